@@ -0,0 +1,129 @@
+use std::ffi::OsStr;
+
+use clap::builder::{PossibleValue, TypedValueParser, ValueParserFactory};
+use clap::error::ErrorKind;
+use clap::{Arg, Command, Error};
+
+use crate::Language;
+
+/// Parser of [`Language`] arguments for clap.
+///
+/// It accepts ISO 639-1, 639-3 and 639-2b codes. All languages are listed as possible values, with
+/// their English name as help text if the `english_names` feature is enabled, which also provides
+/// the candidates for shell completions. Available with the `clap` feature.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use isolang::Language;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     /// Language of the input
+///     #[arg(long)]
+///     lang: Language,
+/// }
+///
+/// let args = Args::try_parse_from(["translate", "--lang", "de"]).unwrap();
+/// assert_eq!(args.lang, Language::Deu);
+/// assert!(Args::try_parse_from(["translate", "--lang", "xx"]).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LanguageValueParser;
+
+impl TypedValueParser for LanguageValueParser {
+    type Value = Language;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Language, Error> {
+        let value = value
+            .to_str()
+            .ok_or_else(|| Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd))?;
+        Language::from_639_3(value)
+            .or_else(|| Language::from_639_1(value))
+            .or_else(|| Language::from_639_2b(value))
+            .ok_or_else(|| {
+                let arg = arg.map_or("...".to_owned(), |arg| arg.to_string());
+                Error::raw(
+                    ErrorKind::InvalidValue,
+                    format!(
+                        "invalid value '{value}' for '{arg}': not an ISO 639-1, 639-3 or 639-2b code\n"
+                    ),
+                )
+                .with_cmd(cmd)
+            })
+    }
+
+    fn possible_values(
+        &self,
+    ) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(crate::all_languages().map(possible_value)))
+    }
+}
+
+/// Describe a language as possible value, with its other codes as hidden aliases.
+fn possible_value(language: Language) -> PossibleValue {
+    let value = PossibleValue::new(language.to_639_3())
+        .aliases(language.to_639_1())
+        .aliases(
+            Some(language.to_639_2b())
+                .filter(|&code| code != language.to_639_3()),
+        );
+    #[cfg(feature = "english_names")]
+    let value = value.help(language.to_name());
+    value
+}
+
+impl ValueParserFactory for Language {
+    type Parser = LanguageValueParser;
+
+    fn value_parser() -> LanguageValueParser {
+        LanguageValueParser
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command() -> Command {
+        Command::new("translate").arg(
+            Arg::new("lang").long("lang").value_parser(LanguageValueParser),
+        )
+    }
+
+    fn parse(code: &str) -> Result<Language, Error> {
+        let matches =
+            command().try_get_matches_from(["translate", "--lang", code])?;
+        Ok(*matches.get_one::<Language>("lang").unwrap())
+    }
+
+    #[test]
+    fn test_parse() {
+        for code in ["de", "deu", "ger"] {
+            assert_eq!(parse(code).unwrap(), Language::Deu);
+        }
+        let error = parse("xx").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidValue);
+        assert!(error
+            .to_string()
+            .contains("invalid value 'xx' for '--lang <lang>'"));
+    }
+
+    #[test]
+    fn test_possible_values() {
+        let values: Vec<_> =
+            LanguageValueParser.possible_values().unwrap().collect();
+        assert_eq!(values.len(), crate::languages().count());
+        let german = values.iter().find(|v| v.get_name() == "deu").unwrap();
+        assert!(german.matches("de", false));
+        assert!(german.matches("ger", false));
+        #[cfg(feature = "english_names")]
+        assert_eq!(german.get_help().unwrap().to_string(), "German");
+    }
+}
@@ -0,0 +1,327 @@
+#[cfg(feature = "alloc")]
+use alloc::borrow::ToOwned;
+#[cfg(feature = "alloc")]
+use core::str::FromStr;
+use core::{
+    fmt::{self, Debug, Display, Formatter},
+    str,
+};
+#[cfg(all(feature = "english_names", feature = "std"))]
+use std::borrow::Cow;
+#[cfg(feature = "std")]
+use std::sync::{Arc, RwLock};
+
+use crate::Language;
+#[cfg(feature = "alloc")]
+use crate::ParseLanguageError;
+
+/// Names registered for private-use codes
+#[cfg(feature = "std")]
+static PRIVATE_USE_NAMES: RwLock<Vec<(PrivateUse, Arc<str>)>> =
+    RwLock::new(Vec::new());
+
+/// A code of the range `qaa`–`qtz`, reserved for local use by ISO 639-2 and 639-3.
+///
+/// These codes are not assigned to any language, so they are free to be used for in-house
+/// dialects or languages missing from ISO 639. With the `std` feature, a name can be registered
+/// for each code.
+///
+/// # Examples
+///
+/// ```
+/// use isolang::PrivateUse;
+///
+/// let code = PrivateUse::from_639_3("qab").unwrap();
+/// assert_eq!(code.to_639_3(), "qab");
+/// assert!(PrivateUse::from_639_3("qua").is_none());
+/// ```
+#[derive(Clone, Copy, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub struct PrivateUse([u8; 3]);
+
+impl PrivateUse {
+    /// Create a private-use code from a three-letter code in the range `qaa`–`qtz`.
+    ///
+    /// Returns `None` for all other codes, including upper case ones.
+    pub const fn from_639_3(code: &str) -> Option<Self> {
+        match code.as_bytes() {
+            [b'q', second @ b'a'..=b't', third @ b'a'..=b'z'] => {
+                Some(PrivateUse([b'q', *second, *third]))
+            }
+            _ => None,
+        }
+    }
+
+    /// Get the three-letter code.
+    pub const fn to_639_3(&self) -> &str {
+        // SAFETY: The code has been validated to consist of ASCII letters.
+        unsafe { str::from_utf8_unchecked(&self.0) }
+    }
+
+    /// Register a name for this code, replacing any previously registered one.
+    ///
+    /// Names are registered globally, usually once at program start, and may be loaded at
+    /// runtime, e.g. from a configuration file. Available with the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolang::PrivateUse;
+    ///
+    /// let code = PrivateUse::from_639_3("qaa").unwrap();
+    /// let name = String::from("Lower Franconian (Kleve)");
+    /// code.register(name);
+    /// assert_eq!(code.name().as_deref(), Some("Lower Franconian (Kleve)"));
+    /// assert_eq!(PrivateUse::from_name("Lower Franconian (Kleve)"), Some(code));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn register(self, name: impl Into<Arc<str>>) {
+        let name = name.into();
+        let mut names = PRIVATE_USE_NAMES
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        match names.iter_mut().find(|(code, _)| *code == self) {
+            Some(entry) => entry.1 = name,
+            None => names.push((self, name)),
+        }
+    }
+
+    /// Get the name registered for this code, if any. Available with the `std` feature.
+    ///
+    /// The name is shared with the registry, so it stays valid when another name is registered.
+    #[cfg(feature = "std")]
+    pub fn name(&self) -> Option<Arc<str>> {
+        let names = PRIVATE_USE_NAMES
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        names
+            .iter()
+            .find(|(code, _)| code == self)
+            .map(|(_, name)| Arc::clone(name))
+    }
+
+    /// Get the code for which the given name has been registered. Available with the `std`
+    /// feature.
+    #[cfg(feature = "std")]
+    pub fn from_name(name: &str) -> Option<Self> {
+        let names = PRIVATE_USE_NAMES
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        names
+            .iter()
+            .find(|(_, registered)| **registered == *name)
+            .map(|(code, _)| *code)
+    }
+}
+
+impl Debug for PrivateUse {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(self.to_639_3())
+    }
+}
+
+/// Formats the registered name, or the code if there is none.
+impl Display for PrivateUse {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(&name),
+            None => f.write_str(self.to_639_3()),
+        }
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(self.to_639_3())
+    }
+}
+
+/// Either a language of ISO 639 or a private-use code.
+///
+/// This allows to use one type for the languages of ISO 639 and for in-house languages, which
+/// are given private-use codes in the range `qaa`–`qtz`. Parsing accepts everything accepted by
+/// [`Language`](enum.Language.html), private-use codes and, with the `std` feature, names
+/// registered for private-use codes.
+///
+/// # Examples
+///
+/// ```
+/// use isolang::{Language, LanguageCode, PrivateUse};
+///
+/// # #[cfg(feature = "std")] {
+/// PrivateUse::from_639_3("qdu").unwrap().register("Düsseldorf dialect");
+///
+/// let codes: Vec<LanguageCode> =
+///     ["de", "qdu"].iter().map(|s| s.parse().unwrap()).collect();
+/// assert_eq!(codes[0], LanguageCode::Known(Language::Deu));
+/// assert_eq!(codes[1].to_639_3(), "qdu");
+/// assert_eq!(codes[1].to_string(), "Düsseldorf dialect");
+/// assert!("qzz".parse::<LanguageCode>().is_err());
+/// # }
+/// ```
+#[derive(Clone, Copy, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub enum LanguageCode {
+    /// A language of ISO 639
+    Known(Language),
+    /// A private-use code
+    Private(PrivateUse),
+}
+
+impl LanguageCode {
+    /// Get the three-letter ISO 639-3 code.
+    pub const fn to_639_3(&self) -> &str {
+        match self {
+            LanguageCode::Known(language) => language.to_639_3(),
+            LanguageCode::Private(code) => code.to_639_3(),
+        }
+    }
+
+    /// Get the language of ISO 639, if this is not a private-use code.
+    pub const fn language(&self) -> Option<Language> {
+        match self {
+            LanguageCode::Known(language) => Some(*language),
+            LanguageCode::Private(_) => None,
+        }
+    }
+
+    /// Get the English name of the language or the name registered for the private-use code.
+    ///
+    /// Available with the `english_names` and `std` features.
+    #[cfg(all(feature = "english_names", feature = "std"))]
+    pub fn to_name(&self) -> Option<Cow<'static, str>> {
+        match self {
+            LanguageCode::Known(language) => {
+                Some(Cow::Borrowed(language.to_name()))
+            }
+            LanguageCode::Private(code) => {
+                code.name().map(|name| Cow::Owned(name.to_string()))
+            }
+        }
+    }
+
+    /// Parse a code without allocating an error.
+    #[cfg(any(feature = "alloc", feature = "serde", test))]
+    pub(crate) fn parse(s: &str) -> Option<Self> {
+        if let Some(language) = Language::parse(s) {
+            return Some(LanguageCode::Known(language));
+        }
+        if let Some(code) = PrivateUse::from_639_3(s) {
+            return Some(LanguageCode::Private(code));
+        }
+        #[cfg(feature = "std")]
+        if let Some(code) = PrivateUse::from_name(s) {
+            return Some(LanguageCode::Private(code));
+        }
+        None
+    }
+}
+
+impl From<Language> for LanguageCode {
+    fn from(language: Language) -> Self {
+        LanguageCode::Known(language)
+    }
+}
+
+impl From<PrivateUse> for LanguageCode {
+    fn from(code: PrivateUse) -> Self {
+        LanguageCode::Private(code)
+    }
+}
+
+impl Debug for LanguageCode {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(self.to_639_3())
+    }
+}
+
+impl Display for LanguageCode {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            LanguageCode::Known(language) => Display::fmt(language, f),
+            LanguageCode::Private(code) => Display::fmt(code, f),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromStr for LanguageCode {
+    type Err = ParseLanguageError;
+
+    fn from_str(s: &str) -> Result<Self, ParseLanguageError> {
+        LanguageCode::parse(s).ok_or_else(|| ParseLanguageError(s.to_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_private_use_range() {
+        assert!(PrivateUse::from_639_3("qaa").is_some());
+        assert!(PrivateUse::from_639_3("qtz").is_some());
+        assert!(PrivateUse::from_639_3("qua").is_none());
+        assert!(PrivateUse::from_639_3("QAA").is_none());
+        assert!(PrivateUse::from_639_3("qa").is_none());
+        assert!(PrivateUse::from_639_3("qaaa").is_none());
+        // no language of ISO 639-3 uses a private-use code
+        assert!(crate::languages()
+            .all(|lang| PrivateUse::from_639_3(lang.to_639_3()).is_none()));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_registered_names() {
+        let code = PrivateUse::from_639_3("qkl").unwrap();
+        assert_eq!(code.name(), None);
+        assert_eq!(code.to_string(), "qkl");
+        code.register("Veedel (Innenstadt)");
+        let previous = code.name().unwrap();
+        code.register(format!("Veedels{}", "platt"));
+        assert_eq!(&*previous, "Veedel (Innenstadt)");
+        assert_eq!(code.name().as_deref(), Some("Veedelsplatt"));
+        assert_eq!(PrivateUse::from_name("Veedelsplatt"), Some(code));
+        assert_eq!(PrivateUse::from_name("Veedel (Innenstadt)"), None);
+        assert_eq!(LanguageCode::parse("Veedelsplatt"), Some(code.into()));
+        assert_eq!(LanguageCode::from(code).to_string(), "Veedelsplatt");
+        #[cfg(feature = "english_names")]
+        assert_eq!(
+            LanguageCode::from(code).to_name().as_deref(),
+            Some("Veedelsplatt")
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            LanguageCode::parse("deu"),
+            Some(LanguageCode::Known(Language::Deu))
+        );
+        let code = LanguageCode::parse("qab").unwrap();
+        assert_eq!(code.language(), None);
+        assert_eq!(format!("{:?}", code), "qab");
+        assert_eq!(LanguageCode::parse("qzz"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        use ::serde::de::{value, Deserialize, IntoDeserializer};
+
+        let codes = [
+            LanguageCode::Known(Language::Fra),
+            PrivateUse::from_639_3("qsb").unwrap().into(),
+        ];
+        let json = serde_json::to_string(&codes).unwrap();
+        assert_eq!(json, r#"["fra","qsb"]"#);
+        assert_eq!(
+            serde_json::from_str::<[LanguageCode; 2]>(&json).unwrap(),
+            codes
+        );
+        assert!(serde_json::from_str::<LanguageCode>(r#""qzz""#).is_err());
+        assert_eq!(
+            LanguageCode::deserialize(b"qsb".as_slice().into_deserializer())
+                .map_err(|e: value::Error| e.to_string()),
+            Ok(codes[1])
+        );
+    }
+}
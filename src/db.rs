@@ -0,0 +1,367 @@
+//! Language data loaded at runtime
+
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    fs, io,
+    path::Path,
+};
+
+use crate::table::{read_iso_table, MalformedLine};
+use crate::Language;
+
+/// A language of a [`LanguageDb`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LanguageEntry {
+    code_3: String,
+    code_2b: Option<String>,
+    code_1: Option<String>,
+    name_en: String,
+    autonym: Option<String>,
+}
+
+impl LanguageEntry {
+    /// Get the ISO 639-3 code of this language.
+    pub fn to_639_3(&self) -> &str {
+        &self.code_3
+    }
+
+    /// Get the ISO 639-2b code of this language.
+    pub fn to_639_2b(&self) -> &str {
+        self.code_2b.as_deref().unwrap_or(&self.code_3)
+    }
+
+    /// Get the ISO 639-2t code of this language.
+    pub fn to_639_2t(&self) -> &str {
+        self.to_639_3()
+    }
+
+    /// Get the ISO 639-1 code of this language, if it has one.
+    pub fn to_639_1(&self) -> Option<&str> {
+        self.code_1.as_deref()
+    }
+
+    /// Get the English name of this language.
+    pub fn to_name(&self) -> &str {
+        &self.name_en
+    }
+
+    /// Get the autonym of this language, if it is known.
+    pub fn to_autonym(&self) -> Option<&str> {
+        self.autonym.as_deref()
+    }
+
+    /// Get the compiled-in language with the same ISO 639-3 code, if there is one.
+    pub fn language(&self) -> Option<Language> {
+        Language::from_639_3(&self.code_3)
+    }
+
+    /// Check whether this entry equals the compiled-in data of the given language.
+    fn matches(&self, language: Language) -> bool {
+        #[cfg(feature = "english_names")]
+        if self.name_en != language.to_name() {
+            return false;
+        }
+        #[cfg(feature = "local_names")]
+        if self.autonym.as_deref() != language.to_autonym() {
+            return false;
+        }
+        self.to_639_2b() == language.to_639_2b()
+            && self.to_639_1() == language.to_639_1()
+    }
+}
+
+/// Language data loaded at runtime from `iso-639-3.tab` and `iso639-autonyms.tsv`.
+///
+/// The compiled-in tables of [`Language`](../enum.Language.html) can only be updated by a new
+/// release of this crate. A `LanguageDb` reads the same tables at runtime, so that servers can
+/// pick up a new release of ISO 639-3 without recompiling. It offers the lookups of `Language`
+/// and can be compared against the compiled-in tables with [`diff`](#method.diff). It is
+/// available with the `runtime_db` feature.
+///
+/// # Examples
+///
+/// ```
+/// use isolang::db::LanguageDb;
+///
+/// let iso_table = "Id\tPart2b\tPart2t\tPart1\tScope\tLanguage_Type\tRef_Name\tComment\n\
+///                  deu\tger\tdeu\tde\tI\tL\tGerman\t\n";
+/// let autonyms = "tag3\ttag1\tname\tautonym\tsource\ndeu\tde\tGerman\tDeutsch\tcldr\n";
+/// let db = LanguageDb::from_tables(iso_table, autonyms).unwrap();
+///
+/// let german = db.from_639_1("de").unwrap();
+/// assert_eq!(german.to_639_2b(), "ger");
+/// assert_eq!(german.to_autonym(), Some("Deutsch"));
+/// assert_eq!(db.from_name("German"), Some(german));
+/// ```
+#[derive(Clone, Debug)]
+pub struct LanguageDb {
+    entries: Vec<LanguageEntry>,
+    /// Indices of the entries by their 639-3 codes
+    by_639_3: HashMap<String, usize>,
+    /// Indices of the entries by their 639-2b codes
+    by_639_2b: HashMap<String, usize>,
+    /// Indices of the entries by their 639-1 codes
+    by_639_1: HashMap<String, usize>,
+}
+
+impl LanguageDb {
+    /// Parse the contents of the ISO 639-3 table and of the autonyms table.
+    ///
+    /// The autonyms table may be empty, if no autonyms are needed.
+    pub fn from_tables(
+        iso_table: &str,
+        autonyms_table: &str,
+    ) -> Result<Self, LoadError> {
+        let codes = read_iso_table(iso_table, autonyms_table)
+            .map_err(|MalformedLine(line)| LoadError::Malformed { line })?;
+        let entries: Vec<LanguageEntry> = codes
+            .into_iter()
+            .map(|lang| LanguageEntry {
+                code_3: lang.code_3.to_owned(),
+                code_2b: lang.code_2b.map(str::to_owned),
+                code_1: lang.code_1.map(str::to_owned),
+                name_en: lang.name_en.to_owned(),
+                autonym: lang.autonym.map(str::to_owned),
+            })
+            .collect();
+        let index = |code: fn(&LanguageEntry) -> Option<&String>| {
+            entries
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, entry)| Some((code(entry)?.clone(), idx)))
+                .collect()
+        };
+        Ok(LanguageDb {
+            by_639_3: index(|entry| Some(&entry.code_3)),
+            by_639_2b: index(|entry| entry.code_2b.as_ref()),
+            by_639_1: index(|entry| entry.code_1.as_ref()),
+            entries,
+        })
+    }
+
+    /// Read and parse the ISO 639-3 table and, optionally, the autonyms table from files.
+    pub fn load(
+        iso_table: &Path,
+        autonyms_table: Option<&Path>,
+    ) -> Result<Self, LoadError> {
+        let iso_table = fs::read_to_string(iso_table)?;
+        let autonyms_table = match autonyms_table {
+            Some(path) => fs::read_to_string(path)?,
+            None => String::new(),
+        };
+        Self::from_tables(&iso_table, &autonyms_table)
+    }
+
+    /// Get the number of languages.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check whether no languages have been loaded.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterate over all languages in the order of the ISO 639-3 table.
+    pub fn iter(&self) -> impl Iterator<Item = &LanguageEntry> {
+        self.entries.iter()
+    }
+
+    /// Look up a language by its ISO 639-1 code.
+    pub fn from_639_1(&self, code: &str) -> Option<&LanguageEntry> {
+        Self::lookup(&self.by_639_1, code, &self.entries)
+    }
+
+    /// Look up a language by its ISO 639-2b code.
+    pub fn from_639_2b(&self, code: &str) -> Option<&LanguageEntry> {
+        Self::lookup(&self.by_639_2b, code, &self.entries)
+            .or_else(|| self.from_639_3(code))
+    }
+
+    /// Look up a language by its ISO 639-2t code.
+    pub fn from_639_2t(&self, code: &str) -> Option<&LanguageEntry> {
+        self.from_639_3(code)
+    }
+
+    /// Look up a language by its ISO 639-3 code.
+    pub fn from_639_3(&self, code: &str) -> Option<&LanguageEntry> {
+        Self::lookup(&self.by_639_3, code, &self.entries)
+    }
+
+    /// Look up a language by its English name.
+    pub fn from_name(&self, name: &str) -> Option<&LanguageEntry> {
+        self.entries.iter().find(|entry| entry.name_en == name)
+    }
+
+    /// Look up a language by its autonym.
+    pub fn from_autonym(&self, autonym: &str) -> Option<&LanguageEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.autonym.as_deref() == Some(autonym))
+    }
+
+    fn lookup<'a>(
+        index: &HashMap<String, usize>,
+        code: &str,
+        entries: &'a [LanguageEntry],
+    ) -> Option<&'a LanguageEntry> {
+        index.get(code).map(|idx| &entries[*idx])
+    }
+
+    /// Compare the loaded languages against the compiled-in tables.
+    ///
+    /// The English names and autonyms are only compared if the `english_names` and
+    /// `local_names` features are enabled, respectively. Added and modified languages are
+    /// reported in the order of the loaded table, followed by the removed languages.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolang::db::{Change, LanguageDb};
+    /// use isolang::Language;
+    ///
+    /// let iso_table = "Id\tPart2b\tPart2t\tPart1\tScope\tLanguage_Type\tRef_Name\tComment\n\
+    ///                  deu\tger\tdeu\tde\tI\tL\tGerman\t\n\
+    ///                  qqq\t\t\t\tI\tL\tNew language\t\n";
+    /// let db = LanguageDb::from_tables(iso_table, "").unwrap();
+    /// let changes = db.diff();
+    ///
+    /// assert!(changes
+    ///     .iter()
+    ///     .any(|change| matches!(change, Change::Added(entry) if entry.to_639_3() == "qqq")));
+    /// assert!(changes.contains(&Change::Removed(Language::Fra)));
+    /// ```
+    pub fn diff(&self) -> Vec<Change<'_>> {
+        let mut changes = Vec::new();
+        let mut found = crate::LanguageSet::new();
+        for entry in &self.entries {
+            match entry.language() {
+                Some(language) => {
+                    found.insert(language);
+                    if !entry.matches(language) {
+                        changes.push(Change::Modified { language, entry });
+                    }
+                }
+                None => changes.push(Change::Added(entry)),
+            }
+        }
+        changes.extend(
+            (crate::LanguageSet::all() - found).iter().map(Change::Removed),
+        );
+        changes
+    }
+}
+
+/// A difference between a [`LanguageDb`] and the compiled-in tables.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Change<'a> {
+    /// The language is not part of the compiled-in tables.
+    Added(&'a LanguageEntry),
+    /// The compiled-in language is missing from the loaded data.
+    Removed(Language),
+    /// The codes or names of the language differ from the compiled-in ones.
+    Modified { language: Language, entry: &'a LanguageEntry },
+}
+
+/// Error returned when loading a [`LanguageDb`] fails.
+#[derive(Debug)]
+pub enum LoadError {
+    /// A table couldn't be read.
+    Io(io::Error),
+    /// A line of a table lacks columns.
+    Malformed {
+        /// The 1-based number of the malformed line
+        line: usize,
+    },
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Io(e) => {
+                write!(f, "unable to read language table: {}", e)
+            }
+            LoadError::Malformed { line } => {
+                write!(f, "malformed language table in line {}", line)
+            }
+        }
+    }
+}
+
+impl Error for LoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LoadError::Io(e) => Some(e),
+            LoadError::Malformed { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for LoadError {
+    fn from(e: io::Error) -> Self {
+        LoadError::Io(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load_repository_tables() -> LanguageDb {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        LanguageDb::load(
+            &dir.join("iso-639-3.tab"),
+            Some(&dir.join("iso639-autonyms.tsv")),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_repository_tables_match_compiled_in() {
+        let db = load_repository_tables();
+        assert_eq!(db.len(), crate::languages().count());
+        assert_eq!(db.diff(), []);
+        let entry = db.from_639_2b("chi").unwrap();
+        assert_eq!(entry.language(), Some(Language::Zho));
+        assert_eq!(db.from_639_1("zh"), Some(entry));
+        assert_eq!(db.from_639_3("zho"), Some(entry));
+        assert!(db.from_639_3("chi").is_none());
+        assert!(db.from_639_1("zho").is_none());
+    }
+
+    #[test]
+    fn test_diff() {
+        let iso_table = "Id\tPart2b\tPart2t\tPart1\tScope\tLanguage_Type\tRef_Name\tComment\n\
+                         deu\tger\tdeu\tdx\tI\tL\tGerman\t\n\
+                         fra\tfre\tfra\tfr\tI\tL\tFrench\t\n\
+                         qqq\t\t\t\tI\tL\tNew (language)\t\n";
+        let autonyms = "tag3\ttag1\tname\tautonym\tsource\n\
+                        fra\tfr\tFrench\tfrançais\tcldr\n";
+        let db = LanguageDb::from_tables(iso_table, autonyms).unwrap();
+        let changes = db.diff();
+        assert_eq!(
+            changes[..2],
+            [
+                Change::Modified {
+                    language: Language::Deu,
+                    entry: db.from_639_3("deu").unwrap()
+                },
+                Change::Added(db.from_name("New").unwrap())
+            ]
+        );
+        assert_eq!(changes.len(), crate::languages().count());
+        assert!(changes.contains(&Change::Removed(Language::Eng)));
+        assert!(!changes.contains(&Change::Removed(Language::Fra)));
+    }
+
+    #[test]
+    fn test_malformed() {
+        let error = LanguageDb::from_tables("Id\n\nde\n", "").unwrap_err();
+        assert!(matches!(error, LoadError::Malformed { line: 3 }));
+        assert!(
+            LanguageDb::load(Path::new("does-not-exist.tab"), None).is_err()
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql};
+use diesel::mysql::Mysql;
+use diesel::pg::Pg;
+#[cfg(feature = "diesel-sqlite")]
+use diesel::serialize::IsNull;
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::{SmallInt, Text};
+#[cfg(feature = "diesel-sqlite")]
+use diesel::sqlite::Sqlite;
+
+use crate::sql::{language_from_id, language_from_text, LanguageId};
+use crate::Language;
+
+impl<DB> ToSql<Text, DB> for Language
+where
+    DB: Backend,
+    str: ToSql<Text, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        self.to_639_3().to_sql(out)
+    }
+}
+
+impl<DB> FromSql<Text, DB> for Language
+where
+    DB: Backend,
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let text = String::from_sql(bytes)?;
+        Ok(language_from_text(&text)?)
+    }
+}
+
+// Diesel borrows the value for as long as the output, so the stable ID, which is computed on the
+// fly, is written to a reborrowed output, respectively passed as owned value. Both are specific to
+// the backend. Stable IDs are below 26³, hence fit into an `i16`.
+
+impl ToSql<SmallInt, Pg> for LanguageId {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let id = self.0.to_stable_id() as i16;
+        ToSql::<SmallInt, Pg>::to_sql(&id, &mut out.reborrow())
+    }
+}
+
+impl ToSql<SmallInt, Mysql> for LanguageId {
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut Output<'b, '_, Mysql>,
+    ) -> serialize::Result {
+        let id = self.0.to_stable_id() as i16;
+        ToSql::<SmallInt, Mysql>::to_sql(&id, &mut out.reborrow())
+    }
+}
+
+/// Available with the `diesel-sqlite` feature.
+#[cfg(feature = "diesel-sqlite")]
+impl ToSql<SmallInt, Sqlite> for LanguageId {
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut Output<'b, '_, Sqlite>,
+    ) -> serialize::Result {
+        out.set_value(i32::from(self.0.to_stable_id()));
+        Ok(IsNull::No)
+    }
+}
+
+impl<DB> FromSql<SmallInt, DB> for LanguageId
+where
+    DB: Backend,
+    i16: FromSql<SmallInt, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let id = i16::from_sql(bytes)?;
+        Ok(language_from_id(id.into())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use diesel::pg::{PgMetadataLookup, PgTypeMetadata};
+    use diesel::query_builder::bind_collector::RawBytesBindCollector;
+    use diesel::query_builder::BindCollector;
+    #[cfg(feature = "diesel-sqlite")]
+    use diesel::{prelude::*, sql_query, sqlite::SqliteConnection};
+
+    use super::*;
+
+    struct NoLookup;
+
+    impl PgMetadataLookup for NoLookup {
+        fn lookup_type(&mut self, _: &str, _: Option<&str>) -> PgTypeMetadata {
+            unreachable!("only built-in types are bound")
+        }
+    }
+
+    #[test]
+    fn test_binds() {
+        // the collected bytes are only exposed by the `Debug` output
+        let mut pg = RawBytesBindCollector::<Pg>::new();
+        pg.push_bound_value::<SmallInt, _>(
+            &LanguageId(Language::Deu),
+            &mut NoLookup,
+        )
+        .unwrap();
+        assert!(format!("{:?}", pg).contains("binds: [Some([8, 104])]"));
+        let mut mysql = RawBytesBindCollector::<Mysql>::new();
+        mysql
+            .push_bound_value::<SmallInt, _>(
+                &LanguageId(Language::Deu),
+                &mut (),
+            )
+            .unwrap();
+        assert!(format!("{:?}", mysql).contains("binds: [Some([104, 8])]"));
+    }
+
+    #[cfg(feature = "diesel-sqlite")]
+    #[derive(QueryableByName, Debug, PartialEq)]
+    struct Row {
+        #[diesel(sql_type = Text)]
+        language: Language,
+        #[diesel(sql_type = SmallInt)]
+        language_id: LanguageId,
+    }
+
+    #[test]
+    #[cfg(feature = "diesel-sqlite")]
+    fn test_roundtrip() {
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+        sql_query(
+            "CREATE TABLE documents (language CHAR(3), language_id SMALLINT)",
+        )
+        .execute(&mut conn)
+        .unwrap();
+        sql_query("INSERT INTO documents VALUES (?, ?)")
+            .bind::<Text, _>(Language::Deu)
+            .bind::<SmallInt, _>(LanguageId(Language::Fra))
+            .execute(&mut conn)
+            .unwrap();
+        let rows = sql_query("SELECT * FROM documents")
+            .load::<Row>(&mut conn)
+            .unwrap();
+        assert_eq!(
+            rows,
+            [Row {
+                language: Language::Deu,
+                language_id: LanguageId(Language::Fra)
+            }]
+        );
+
+        // the raw values are the ISO 639-3 code and the stable ID
+        sql_query("UPDATE documents SET language = 'xxx'")
+            .execute(&mut conn)
+            .unwrap();
+        let error = sql_query("SELECT * FROM documents")
+            .load::<Row>(&mut conn)
+            .unwrap_err();
+        assert!(error.to_string().contains("'xxx' is not a valid ISO 639-3"));
+    }
+}
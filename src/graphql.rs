@@ -0,0 +1,162 @@
+use alloc::borrow::ToOwned;
+
+use async_graphql::{
+    InputValueError, InputValueResult, Object, Scalar, ScalarType, Value,
+};
+
+use crate::{Language, Scope};
+
+/// A [`Language`] as GraphQL scalar, as alternative to the `Language` enum.
+///
+/// Unlike the enum with its thousands of variants, the `IsoLanguageCode` scalar accepts any ISO
+/// 639-1, 639-3 or 639-2b code as string and is output as ISO 639-3 code. Available with the
+/// `async-graphql` feature.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub struct LanguageScalar(pub Language);
+
+/// An ISO 639-1, 639-3 or 639-2b language code
+#[Scalar(name = "IsoLanguageCode")]
+impl ScalarType for LanguageScalar {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        let Value::String(code) = &value else {
+            return Err(InputValueError::expected_type(value));
+        };
+        Language::from_639_3(code)
+            .or_else(|| Language::from_639_1(code))
+            .or_else(|| Language::from_639_2b(code))
+            .map(LanguageScalar)
+            .ok_or_else(|| {
+                InputValueError::custom(format_args!(
+                    "unknown language code \"{code}\""
+                ))
+            })
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(_))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.0.to_639_3().to_owned())
+    }
+}
+
+impl From<Language> for LanguageScalar {
+    fn from(language: Language) -> Self {
+        LanguageScalar(language)
+    }
+}
+
+impl From<LanguageScalar> for Language {
+    fn from(scalar: LanguageScalar) -> Self {
+        scalar.0
+    }
+}
+
+/// The metadata of a [`Language`] as GraphQL object.
+///
+/// The fields `name` and `autonym` are only part of the schema with the `english_names` and
+/// `local_names` features. Available with the `async-graphql` feature.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub struct LanguageInfo(pub Language);
+
+/// Metadata of a language of ISO 639
+#[Object(name = "LanguageInfo")]
+impl LanguageInfo {
+    /// The ISO 639-1 code, if the language has one
+    async fn iso6391(&self) -> Option<&'static str> {
+        self.0.to_639_1()
+    }
+
+    /// The ISO 639-3 code
+    async fn iso6393(&self) -> &'static str {
+        self.0.to_639_3()
+    }
+
+    /// The English name
+    #[cfg(feature = "english_names")]
+    async fn name(&self) -> &'static str {
+        self.0.to_name()
+    }
+
+    /// The name of the language in the language itself, if known
+    #[cfg(feature = "local_names")]
+    async fn autonym(&self) -> Option<&'static str> {
+        self.0.to_autonym()
+    }
+
+    /// Whether this is an individual language, a macrolanguage or a special code
+    async fn scope(&self) -> Scope {
+        self.0.scope()
+    }
+}
+
+impl From<Language> for LanguageInfo {
+    fn from(language: Language) -> Self {
+        LanguageInfo(language)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_graphql::futures_util::FutureExt;
+    use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema};
+
+    use super::*;
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn language(&self, code: LanguageScalar) -> LanguageInfo {
+            code.0.into()
+        }
+
+        async fn echo(&self, code: LanguageScalar) -> LanguageScalar {
+            code
+        }
+    }
+
+    fn execute(query: &str) -> async_graphql::Response {
+        Schema::new(Query, EmptyMutation, EmptySubscription)
+            .execute(query)
+            .now_or_never()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_scalar() {
+        for code in ["de", "deu", "ger"] {
+            let response = execute(&format!(r#"{{ echo(code: "{code}") }}"#));
+            assert_eq!(response.data.to_string(), r#"{echo: "deu"}"#);
+        }
+        let response = execute(r#"{ echo(code: "xx") }"#);
+        assert!(response.errors[0].message.contains("unknown language code"));
+        assert!(!execute("{ echo(code: 1) }").errors.is_empty());
+        let sdl = Schema::new(Query, EmptyMutation, EmptySubscription).sdl();
+        assert!(sdl.contains("scalar IsoLanguageCode"));
+    }
+
+    #[test]
+    fn test_info() {
+        let response =
+            execute(r#"{ language(code: "zh") { iso6391 iso6393 scope } }"#);
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert_eq!(
+            response.data.to_string(),
+            r#"{language: {iso6391: "zh", iso6393: "zho", scope: MACROLANGUAGE}}"#
+        );
+        let response = execute(r#"{ language(code: "gsw") { iso6391 } }"#);
+        assert_eq!(response.data.to_string(), "{language: {iso6391: null}}");
+    }
+
+    #[test]
+    #[cfg(all(feature = "english_names", feature = "local_names"))]
+    fn test_names() {
+        let response = execute(r#"{ language(code: "de") { name autonym } }"#);
+        assert_eq!(
+            response.data.to_string(),
+            r#"{language: {name: "German", autonym: "Deutsch"}}"#
+        );
+    }
+}
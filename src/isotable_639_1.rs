@@ -0,0 +1,5886 @@
+/// This file is generated and should not be edited directly.
+use super::{LanguageData, Scope};
+
+/// Number of languages, i.e. variants of the `Language` enum
+pub(crate) const LANGUAGE_COUNT: usize = 188;
+
+#[allow(clippy::type_complexity)]
+pub(crate) static OVERVIEW: [LanguageData; LANGUAGE_COUNT] = [
+    LanguageData { code_3: [97, 97, 114], code_1: Some([97, 97]) },
+    LanguageData { code_3: [97, 98, 107], code_1: Some([97, 98]) },
+    LanguageData { code_3: [97, 102, 114], code_1: Some([97, 102]) },
+    LanguageData { code_3: [97, 107, 97], code_1: Some([97, 107]) },
+    LanguageData { code_3: [97, 109, 104], code_1: Some([97, 109]) },
+    LanguageData { code_3: [97, 114, 97], code_1: Some([97, 114]) },
+    LanguageData { code_3: [97, 114, 103], code_1: Some([97, 110]) },
+    LanguageData { code_3: [97, 115, 109], code_1: Some([97, 115]) },
+    LanguageData { code_3: [97, 118, 97], code_1: Some([97, 118]) },
+    LanguageData { code_3: [97, 118, 101], code_1: Some([97, 101]) },
+    LanguageData { code_3: [97, 121, 109], code_1: Some([97, 121]) },
+    LanguageData { code_3: [97, 122, 101], code_1: Some([97, 122]) },
+    LanguageData { code_3: [98, 97, 107], code_1: Some([98, 97]) },
+    LanguageData { code_3: [98, 97, 109], code_1: Some([98, 109]) },
+    LanguageData { code_3: [98, 101, 108], code_1: Some([98, 101]) },
+    LanguageData { code_3: [98, 101, 110], code_1: Some([98, 110]) },
+    LanguageData { code_3: [98, 105, 115], code_1: Some([98, 105]) },
+    LanguageData { code_3: [98, 111, 100], code_1: Some([98, 111]) },
+    LanguageData { code_3: [98, 111, 115], code_1: Some([98, 115]) },
+    LanguageData { code_3: [98, 114, 101], code_1: Some([98, 114]) },
+    LanguageData { code_3: [98, 117, 108], code_1: Some([98, 103]) },
+    LanguageData { code_3: [99, 97, 116], code_1: Some([99, 97]) },
+    LanguageData { code_3: [99, 101, 115], code_1: Some([99, 115]) },
+    LanguageData { code_3: [99, 104, 97], code_1: Some([99, 104]) },
+    LanguageData { code_3: [99, 104, 101], code_1: Some([99, 101]) },
+    LanguageData { code_3: [99, 104, 117], code_1: Some([99, 117]) },
+    LanguageData { code_3: [99, 104, 118], code_1: Some([99, 118]) },
+    LanguageData { code_3: [99, 111, 114], code_1: Some([107, 119]) },
+    LanguageData { code_3: [99, 111, 115], code_1: Some([99, 111]) },
+    LanguageData { code_3: [99, 114, 101], code_1: Some([99, 114]) },
+    LanguageData { code_3: [99, 121, 109], code_1: Some([99, 121]) },
+    LanguageData { code_3: [100, 97, 110], code_1: Some([100, 97]) },
+    LanguageData { code_3: [100, 101, 117], code_1: Some([100, 101]) },
+    LanguageData { code_3: [100, 105, 118], code_1: Some([100, 118]) },
+    LanguageData { code_3: [100, 122, 111], code_1: Some([100, 122]) },
+    LanguageData { code_3: [101, 108, 108], code_1: Some([101, 108]) },
+    LanguageData { code_3: [101, 110, 103], code_1: Some([101, 110]) },
+    LanguageData { code_3: [101, 112, 111], code_1: Some([101, 111]) },
+    LanguageData { code_3: [101, 115, 116], code_1: Some([101, 116]) },
+    LanguageData { code_3: [101, 117, 115], code_1: Some([101, 117]) },
+    LanguageData { code_3: [101, 119, 101], code_1: Some([101, 101]) },
+    LanguageData { code_3: [102, 97, 111], code_1: Some([102, 111]) },
+    LanguageData { code_3: [102, 97, 115], code_1: Some([102, 97]) },
+    LanguageData { code_3: [102, 105, 106], code_1: Some([102, 106]) },
+    LanguageData { code_3: [102, 105, 110], code_1: Some([102, 105]) },
+    LanguageData { code_3: [102, 114, 97], code_1: Some([102, 114]) },
+    LanguageData { code_3: [102, 114, 121], code_1: Some([102, 121]) },
+    LanguageData { code_3: [102, 117, 108], code_1: Some([102, 102]) },
+    LanguageData { code_3: [103, 108, 97], code_1: Some([103, 100]) },
+    LanguageData { code_3: [103, 108, 101], code_1: Some([103, 97]) },
+    LanguageData { code_3: [103, 108, 103], code_1: Some([103, 108]) },
+    LanguageData { code_3: [103, 108, 118], code_1: Some([103, 118]) },
+    LanguageData { code_3: [103, 114, 110], code_1: Some([103, 110]) },
+    LanguageData { code_3: [103, 117, 106], code_1: Some([103, 117]) },
+    LanguageData { code_3: [104, 97, 116], code_1: Some([104, 116]) },
+    LanguageData { code_3: [104, 97, 117], code_1: Some([104, 97]) },
+    LanguageData { code_3: [104, 98, 115], code_1: Some([115, 104]) },
+    LanguageData { code_3: [104, 101, 98], code_1: Some([104, 101]) },
+    LanguageData { code_3: [104, 101, 114], code_1: Some([104, 122]) },
+    LanguageData { code_3: [104, 105, 110], code_1: Some([104, 105]) },
+    LanguageData { code_3: [104, 109, 111], code_1: Some([104, 111]) },
+    LanguageData { code_3: [104, 114, 118], code_1: Some([104, 114]) },
+    LanguageData { code_3: [104, 117, 110], code_1: Some([104, 117]) },
+    LanguageData { code_3: [104, 121, 101], code_1: Some([104, 121]) },
+    LanguageData { code_3: [105, 98, 111], code_1: Some([105, 103]) },
+    LanguageData { code_3: [105, 100, 111], code_1: Some([105, 111]) },
+    LanguageData { code_3: [105, 105, 105], code_1: Some([105, 105]) },
+    LanguageData { code_3: [105, 107, 117], code_1: Some([105, 117]) },
+    LanguageData { code_3: [105, 108, 101], code_1: Some([105, 101]) },
+    LanguageData { code_3: [105, 110, 97], code_1: Some([105, 97]) },
+    LanguageData { code_3: [105, 110, 100], code_1: Some([105, 100]) },
+    LanguageData { code_3: [105, 112, 107], code_1: Some([105, 107]) },
+    LanguageData { code_3: [105, 115, 108], code_1: Some([105, 115]) },
+    LanguageData { code_3: [105, 116, 97], code_1: Some([105, 116]) },
+    LanguageData { code_3: [106, 97, 118], code_1: Some([106, 118]) },
+    LanguageData { code_3: [106, 112, 110], code_1: Some([106, 97]) },
+    LanguageData { code_3: [107, 97, 108], code_1: Some([107, 108]) },
+    LanguageData { code_3: [107, 97, 110], code_1: Some([107, 110]) },
+    LanguageData { code_3: [107, 97, 115], code_1: Some([107, 115]) },
+    LanguageData { code_3: [107, 97, 116], code_1: Some([107, 97]) },
+    LanguageData { code_3: [107, 97, 117], code_1: Some([107, 114]) },
+    LanguageData { code_3: [107, 97, 122], code_1: Some([107, 107]) },
+    LanguageData { code_3: [107, 104, 109], code_1: Some([107, 109]) },
+    LanguageData { code_3: [107, 105, 107], code_1: Some([107, 105]) },
+    LanguageData { code_3: [107, 105, 110], code_1: Some([114, 119]) },
+    LanguageData { code_3: [107, 105, 114], code_1: Some([107, 121]) },
+    LanguageData { code_3: [107, 111, 109], code_1: Some([107, 118]) },
+    LanguageData { code_3: [107, 111, 110], code_1: Some([107, 103]) },
+    LanguageData { code_3: [107, 111, 114], code_1: Some([107, 111]) },
+    LanguageData { code_3: [107, 117, 97], code_1: Some([107, 106]) },
+    LanguageData { code_3: [107, 117, 114], code_1: Some([107, 117]) },
+    LanguageData { code_3: [108, 97, 111], code_1: Some([108, 111]) },
+    LanguageData { code_3: [108, 97, 116], code_1: Some([108, 97]) },
+    LanguageData { code_3: [108, 97, 118], code_1: Some([108, 118]) },
+    LanguageData { code_3: [108, 105, 109], code_1: Some([108, 105]) },
+    LanguageData { code_3: [108, 105, 110], code_1: Some([108, 110]) },
+    LanguageData { code_3: [108, 105, 116], code_1: Some([108, 116]) },
+    LanguageData { code_3: [108, 116, 122], code_1: Some([108, 98]) },
+    LanguageData { code_3: [108, 117, 98], code_1: Some([108, 117]) },
+    LanguageData { code_3: [108, 117, 103], code_1: Some([108, 103]) },
+    LanguageData { code_3: [109, 97, 104], code_1: Some([109, 104]) },
+    LanguageData { code_3: [109, 97, 108], code_1: Some([109, 108]) },
+    LanguageData { code_3: [109, 97, 114], code_1: Some([109, 114]) },
+    LanguageData { code_3: [109, 105, 115], code_1: None },
+    LanguageData { code_3: [109, 107, 100], code_1: Some([109, 107]) },
+    LanguageData { code_3: [109, 108, 103], code_1: Some([109, 103]) },
+    LanguageData { code_3: [109, 108, 116], code_1: Some([109, 116]) },
+    LanguageData { code_3: [109, 111, 110], code_1: Some([109, 110]) },
+    LanguageData { code_3: [109, 114, 105], code_1: Some([109, 105]) },
+    LanguageData { code_3: [109, 115, 97], code_1: Some([109, 115]) },
+    LanguageData { code_3: [109, 117, 108], code_1: None },
+    LanguageData { code_3: [109, 121, 97], code_1: Some([109, 121]) },
+    LanguageData { code_3: [110, 97, 117], code_1: Some([110, 97]) },
+    LanguageData { code_3: [110, 97, 118], code_1: Some([110, 118]) },
+    LanguageData { code_3: [110, 98, 108], code_1: Some([110, 114]) },
+    LanguageData { code_3: [110, 100, 101], code_1: Some([110, 100]) },
+    LanguageData { code_3: [110, 100, 111], code_1: Some([110, 103]) },
+    LanguageData { code_3: [110, 101, 112], code_1: Some([110, 101]) },
+    LanguageData { code_3: [110, 108, 100], code_1: Some([110, 108]) },
+    LanguageData { code_3: [110, 110, 111], code_1: Some([110, 110]) },
+    LanguageData { code_3: [110, 111, 98], code_1: Some([110, 98]) },
+    LanguageData { code_3: [110, 111, 114], code_1: Some([110, 111]) },
+    LanguageData { code_3: [110, 121, 97], code_1: Some([110, 121]) },
+    LanguageData { code_3: [111, 99, 105], code_1: Some([111, 99]) },
+    LanguageData { code_3: [111, 106, 105], code_1: Some([111, 106]) },
+    LanguageData { code_3: [111, 114, 105], code_1: Some([111, 114]) },
+    LanguageData { code_3: [111, 114, 109], code_1: Some([111, 109]) },
+    LanguageData { code_3: [111, 115, 115], code_1: Some([111, 115]) },
+    LanguageData { code_3: [112, 97, 110], code_1: Some([112, 97]) },
+    LanguageData { code_3: [112, 108, 105], code_1: Some([112, 105]) },
+    LanguageData { code_3: [112, 111, 108], code_1: Some([112, 108]) },
+    LanguageData { code_3: [112, 111, 114], code_1: Some([112, 116]) },
+    LanguageData { code_3: [112, 117, 115], code_1: Some([112, 115]) },
+    LanguageData { code_3: [113, 117, 101], code_1: Some([113, 117]) },
+    LanguageData { code_3: [114, 111, 104], code_1: Some([114, 109]) },
+    LanguageData { code_3: [114, 111, 110], code_1: Some([114, 111]) },
+    LanguageData { code_3: [114, 117, 110], code_1: Some([114, 110]) },
+    LanguageData { code_3: [114, 117, 115], code_1: Some([114, 117]) },
+    LanguageData { code_3: [115, 97, 103], code_1: Some([115, 103]) },
+    LanguageData { code_3: [115, 97, 110], code_1: Some([115, 97]) },
+    LanguageData { code_3: [115, 105, 110], code_1: Some([115, 105]) },
+    LanguageData { code_3: [115, 108, 107], code_1: Some([115, 107]) },
+    LanguageData { code_3: [115, 108, 118], code_1: Some([115, 108]) },
+    LanguageData { code_3: [115, 109, 101], code_1: Some([115, 101]) },
+    LanguageData { code_3: [115, 109, 111], code_1: Some([115, 109]) },
+    LanguageData { code_3: [115, 110, 97], code_1: Some([115, 110]) },
+    LanguageData { code_3: [115, 110, 100], code_1: Some([115, 100]) },
+    LanguageData { code_3: [115, 111, 109], code_1: Some([115, 111]) },
+    LanguageData { code_3: [115, 111, 116], code_1: Some([115, 116]) },
+    LanguageData { code_3: [115, 112, 97], code_1: Some([101, 115]) },
+    LanguageData { code_3: [115, 113, 105], code_1: Some([115, 113]) },
+    LanguageData { code_3: [115, 114, 100], code_1: Some([115, 99]) },
+    LanguageData { code_3: [115, 114, 112], code_1: Some([115, 114]) },
+    LanguageData { code_3: [115, 115, 119], code_1: Some([115, 115]) },
+    LanguageData { code_3: [115, 117, 110], code_1: Some([115, 117]) },
+    LanguageData { code_3: [115, 119, 97], code_1: Some([115, 119]) },
+    LanguageData { code_3: [115, 119, 101], code_1: Some([115, 118]) },
+    LanguageData { code_3: [116, 97, 104], code_1: Some([116, 121]) },
+    LanguageData { code_3: [116, 97, 109], code_1: Some([116, 97]) },
+    LanguageData { code_3: [116, 97, 116], code_1: Some([116, 116]) },
+    LanguageData { code_3: [116, 101, 108], code_1: Some([116, 101]) },
+    LanguageData { code_3: [116, 103, 107], code_1: Some([116, 103]) },
+    LanguageData { code_3: [116, 103, 108], code_1: Some([116, 108]) },
+    LanguageData { code_3: [116, 104, 97], code_1: Some([116, 104]) },
+    LanguageData { code_3: [116, 105, 114], code_1: Some([116, 105]) },
+    LanguageData { code_3: [116, 111, 110], code_1: Some([116, 111]) },
+    LanguageData { code_3: [116, 115, 110], code_1: Some([116, 110]) },
+    LanguageData { code_3: [116, 115, 111], code_1: Some([116, 115]) },
+    LanguageData { code_3: [116, 117, 107], code_1: Some([116, 107]) },
+    LanguageData { code_3: [116, 117, 114], code_1: Some([116, 114]) },
+    LanguageData { code_3: [116, 119, 105], code_1: Some([116, 119]) },
+    LanguageData { code_3: [117, 105, 103], code_1: Some([117, 103]) },
+    LanguageData { code_3: [117, 107, 114], code_1: Some([117, 107]) },
+    LanguageData { code_3: [117, 110, 100], code_1: None },
+    LanguageData { code_3: [117, 114, 100], code_1: Some([117, 114]) },
+    LanguageData { code_3: [117, 122, 98], code_1: Some([117, 122]) },
+    LanguageData { code_3: [118, 101, 110], code_1: Some([118, 101]) },
+    LanguageData { code_3: [118, 105, 101], code_1: Some([118, 105]) },
+    LanguageData { code_3: [118, 111, 108], code_1: Some([118, 111]) },
+    LanguageData { code_3: [119, 108, 110], code_1: Some([119, 97]) },
+    LanguageData { code_3: [119, 111, 108], code_1: Some([119, 111]) },
+    LanguageData { code_3: [120, 104, 111], code_1: Some([120, 104]) },
+    LanguageData { code_3: [121, 105, 100], code_1: Some([121, 105]) },
+    LanguageData { code_3: [121, 111, 114], code_1: Some([121, 111]) },
+    LanguageData { code_3: [122, 104, 97], code_1: Some([122, 97]) },
+    LanguageData { code_3: [122, 104, 111], code_1: Some([122, 104]) },
+    LanguageData { code_3: [122, 117, 108], code_1: Some([122, 117]) },
+    LanguageData { code_3: [122, 120, 120], code_1: None },
+];
+
+#[cfg(feature = "english_names")]
+pub(crate) static NAMES_EN: &str = concat!(
+    "Afar",
+    "Abkhazian",
+    "Afrikaans",
+    "Akan",
+    "Amharic",
+    "Arabic",
+    "Aragonese",
+    "Assamese",
+    "Avaric",
+    "Avestan",
+    "Aymara",
+    "Azerbaijani",
+    "Bashkir",
+    "Bambara",
+    "Belarusian",
+    "Bengali",
+    "Bislama",
+    "Tibetan",
+    "Bosnian",
+    "Breton",
+    "Bulgarian",
+    "Catalan",
+    "Czech",
+    "Chamorro",
+    "Chechen",
+    "Church Slavic",
+    "Chuvash",
+    "Cornish",
+    "Corsican",
+    "Cree",
+    "Welsh",
+    "Danish",
+    "German",
+    "Dhivehi",
+    "Dzongkha",
+    "Modern Greek",
+    "English",
+    "Esperanto",
+    "Estonian",
+    "Basque",
+    "Ewe",
+    "Faroese",
+    "Persian",
+    "Fijian",
+    "Finnish",
+    "French",
+    "Western Frisian",
+    "Fulah",
+    "Scottish Gaelic",
+    "Irish",
+    "Galician",
+    "Manx",
+    "Guarani",
+    "Gujarati",
+    "Haitian",
+    "Hausa",
+    "Serbo-Croatian",
+    "Hebrew",
+    "Herero",
+    "Hindi",
+    "Hiri Motu",
+    "Croatian",
+    "Hungarian",
+    "Armenian",
+    "Igbo",
+    "Ido",
+    "Sichuan Yi",
+    "Inuktitut",
+    "Interlingue",
+    "Interlingua",
+    "Indonesian",
+    "Inupiaq",
+    "Icelandic",
+    "Italian",
+    "Javanese",
+    "Japanese",
+    "Kalaallisut",
+    "Kannada",
+    "Kashmiri",
+    "Georgian",
+    "Kanuri",
+    "Kazakh",
+    "Khmer",
+    "Kikuyu",
+    "Kinyarwanda",
+    "Kirghiz",
+    "Komi",
+    "Kongo",
+    "Korean",
+    "Kuanyama",
+    "Kurdish",
+    "Lao",
+    "Latin",
+    "Latvian",
+    "Limburgan",
+    "Lingala",
+    "Lithuanian",
+    "Luxembourgish",
+    "Luba-Katanga",
+    "Ganda",
+    "Marshallese",
+    "Malayalam",
+    "Marathi",
+    "Uncoded languages",
+    "Macedonian",
+    "Malagasy",
+    "Maltese",
+    "Mongolian",
+    "Maori",
+    "Malay",
+    "Multiple languages",
+    "Burmese",
+    "Nauru",
+    "Navajo",
+    "South Ndebele",
+    "North Ndebele",
+    "Ndonga",
+    "Nepali",
+    "Dutch",
+    "Norwegian Nynorsk",
+    "Norwegian Bokmål",
+    "Norwegian",
+    "Chichewa",
+    "Occitan",
+    "Ojibwa",
+    "Oriya",
+    "Oromo",
+    "Iron Ossetic",
+    "Panjabi",
+    "Pali",
+    "Polish",
+    "Portuguese",
+    "Pushto",
+    "Quechua",
+    "Romansh",
+    "Romanian",
+    "Rundi",
+    "Russian",
+    "Sango",
+    "Sanskrit",
+    "Sinhala",
+    "Slovak",
+    "Slovenian",
+    "Northern Sami",
+    "Samoan",
+    "Shona",
+    "Sindhi",
+    "Somali",
+    "Southern Sotho",
+    "Spanish",
+    "Albanian",
+    "Sardinian",
+    "Serbian",
+    "Swati",
+    "Sundanese",
+    "Swahili",
+    "Swedish",
+    "Tahitian",
+    "Tamil",
+    "Tatar",
+    "Telugu",
+    "Tajik",
+    "Tagalog",
+    "Thai",
+    "Tigrinya",
+    "Tonga",
+    "Tswana",
+    "Tsonga",
+    "Turkmen",
+    "Turkish",
+    "Twi",
+    "Uighur",
+    "Ukrainian",
+    "Undetermined",
+    "Urdu",
+    "Uzbek",
+    "Venda",
+    "Vietnamese",
+    "Volapük",
+    "Walloon",
+    "Wolof",
+    "Xhosa",
+    "Yiddish",
+    "Yoruba",
+    "Zhuang",
+    "Chinese",
+    "Zulu",
+    "No linguistic content",
+);
+
+#[cfg(feature = "english_names")]
+pub(crate) static NAMES_EN_OFFSETS: [u32; LANGUAGE_COUNT + 1] = [
+    0, 4, 13, 22, 26, 33, 39, 48, 56, 62, 69, 75, 86, 93, 100, 110, 117, 124,
+    131, 138, 144, 153, 160, 165, 173, 180, 193, 200, 207, 215, 219, 224, 230,
+    236, 243, 251, 263, 270, 279, 287, 293, 296, 303, 310, 316, 323, 329, 344,
+    349, 364, 369, 377, 381, 388, 396, 403, 408, 422, 428, 434, 439, 448, 456,
+    465, 473, 477, 480, 490, 499, 510, 521, 531, 538, 547, 554, 562, 570, 581,
+    588, 596, 604, 610, 616, 621, 627, 638, 645, 649, 654, 660, 668, 675, 678,
+    683, 690, 699, 706, 716, 729, 741, 746, 757, 766, 773, 790, 800, 808, 815,
+    824, 829, 834, 852, 859, 864, 870, 883, 896, 902, 908, 913, 930, 947, 956,
+    964, 971, 977, 982, 987, 999, 1006, 1010, 1016, 1026, 1032, 1039, 1046,
+    1054, 1059, 1066, 1071, 1079, 1086, 1092, 1101, 1114, 1120, 1125, 1131,
+    1137, 1151, 1158, 1166, 1175, 1182, 1187, 1196, 1203, 1210, 1218, 1223,
+    1228, 1234, 1239, 1246, 1250, 1258, 1263, 1269, 1275, 1282, 1289, 1292,
+    1298, 1307, 1319, 1323, 1328, 1333, 1343, 1351, 1358, 1363, 1368, 1375,
+    1381, 1387, 1394, 1398, 1419,
+];
+
+#[cfg(feature = "local_names")]
+pub(crate) static AUTONYMS: &str = concat!(
+    "Qafar",
+    "аҧсуа бызшәа\u{200e} (Aṗsua byzšwa), аҧсшәа\u{200e} (Aṗsšwa)",
+    "Afrikaans",
+    "Akan",
+    "አማርኛ",
+    "العربية",
+    "Aragonés",
+    "অসমীয\u{9bc}\u{9be}",
+    "авар мацӏ\u{200e} (Awar mac\u{323}), магӏарул мацӏ\u{200e} (Maʿarul mac\u{323})",
+    "azərbaycan",
+    "башҡорт теле\u{200e} (Başķort tele), башҡортса\u{200e} (Başķortsa)",
+    "bamanakan",
+    "беларуская",
+    "ব\u{9be}ংল\u{9be}",
+    "Bislama",
+    "བ\u{f7c}ད་ས\u{f90}ད་",
+    "bosanski",
+    "brezhoneg",
+    "български",
+    "català",
+    "čeština",
+    "Chamorru",
+    "нохчийн",
+    "церковнослове\u{301}нскїй",
+    "Чӑвашла",
+    "kernewek",
+    "Corsu",
+    "Cymraeg",
+    "dansk",
+    "Deutsch",
+    "ދ\u{7a8}ވ\u{7ac}ހ\u{7a8}ބ\u{7a6}ސ\u{7b0}",
+    "ར\u{fab}\u{f7c}ང་ཁ",
+    "Ελληνικά",
+    "English",
+    "esperanto",
+    "eesti",
+    "euskara",
+    "Eʋegbe",
+    "føroyskt",
+    "فارسی",
+    "suomi",
+    "français",
+    "Frysk",
+    "Pulaar",
+    "Gàidhlig",
+    "Gaeilge",
+    "galego",
+    "Gaelg",
+    "ગ\u{ac1}જરાતી",
+    "Ayisyen, Kreyòl",
+    "Hausa",
+    "עברית",
+    "हिन\u{94d}दी",
+    "hrvatski",
+    "magyar",
+    "հայերեն",
+    "Igbo",
+    "ꆈꌠꉙ",
+    "ᐃᓄᒃᑎᑐᑦ",
+    "interlingua",
+    "Indonesia",
+    "íslenska",
+    "italiano",
+    "Basa Jawa",
+    "日本語",
+    "kalaallisut",
+    "ಕನ\u{ccd}ನಡ",
+    "کٲش\u{64f}ر",
+    "ქართული",
+    "қазақ тілі",
+    "ខ\u{17d2}មែរ",
+    "Gikuyu",
+    "Kinyarwanda",
+    "кыргызча",
+    "한국어",
+    "Oshikwanyama",
+    "kurdî",
+    "ລາວ",
+    "latviešu",
+    "Limbourgeois, Limburgs",
+    "lingála",
+    "lietuvių",
+    "Lëtzebuergesch",
+    "Tshiluba",
+    "Luganda",
+    "മലയ\u{d3e}ളം",
+    "मराठी",
+    "македонски",
+    "Malagasy",
+    "Malti",
+    "монгол",
+    "te reo Maori",
+    "Melayu",
+    "မြန\u{103a}မာ",
+    "Diné",
+    "isiNdebele",
+    "isiNdebele",
+    "न\u{947}पाली",
+    "Nederlands",
+    "nynorsk",
+    "norsk bokmål",
+    "Norsk",
+    "Chichewa, chiCheŵa\u{200e} (Chichewa), chiNyanja",
+    "occitan",
+    "ଓଡ\u{b3c}\u{b3f}ଆ",
+    "Oromoo",
+    "ирон",
+    "ਪ\u{a70}ਜਾਬੀ",
+    "polski",
+    "português",
+    "پښتو",
+    "Runasimi",
+    "rumantsch",
+    "română",
+    "Ikirundi",
+    "русский",
+    "Sängö",
+    "स\u{902}स\u{94d}क\u{943}त भाषा",
+    "ස\u{dd2}ංහල",
+    "slovenčina",
+    "slovenščina",
+    "davvisámegiella",
+    "Gagana Samoa",
+    "chiShona",
+    "سنڌي",
+    "Soomaali",
+    "Sesotho",
+    "español",
+    "shqip",
+    "српски",
+    "siSwati",
+    "Sunda",
+    "Kiswahili",
+    "svenska",
+    "Reo Tahiti",
+    "தமிழ\u{bcd}",
+    "татар",
+    "త\u{c46}లుగు",
+    "тоҷикӣ",
+    "Tagalog",
+    "ไทย",
+    "ትግርኛ",
+    "lea fakatonga",
+    "Setswana",
+    "Xitsonga",
+    "Türkmen dili",
+    "Türkçe",
+    "ئۇيغۇرچە",
+    "українська",
+    "und",
+    "اردو",
+    "o‘zbek",
+    "Tshivenḓa",
+    "Tiếng Việt",
+    "Volapük",
+    "Wolof",
+    "isiXhosa",
+    "יי\u{5b4}דיש",
+    "Èdè Yorùbá",
+    "中文",
+    "isiZulu",
+);
+
+#[cfg(feature = "local_names")]
+pub(crate) static AUTONYMS_OFFSETS: [u16; LANGUAGE_COUNT + 1] = [
+    0, 5, 78, 87, 91, 103, 117, 126, 147, 227, 227, 227, 238, 318, 327, 347,
+    362, 369, 393, 401, 410, 428, 435, 444, 452, 466, 504, 518, 526, 531, 531,
+    538, 543, 550, 570, 588, 604, 611, 620, 625, 632, 639, 648, 658, 658, 663,
+    672, 677, 683, 692, 699, 705, 710, 710, 731, 747, 752, 752, 762, 762, 780,
+    780, 788, 794, 808, 812, 812, 821, 839, 839, 850, 859, 859, 868, 876, 885,
+    894, 905, 920, 930, 951, 951, 970, 985, 991, 1002, 1018, 1018, 1018, 1027,
+    1039, 1045, 1054, 1054, 1063, 1085, 1093, 1102, 1117, 1125, 1132, 1132,
+    1150, 1165, 1165, 1185, 1193, 1198, 1210, 1222, 1228, 1228, 1246, 1246,
+    1251, 1261, 1271, 1271, 1289, 1299, 1306, 1319, 1324, 1368, 1375, 1375,
+    1390, 1396, 1404, 1422, 1422, 1428, 1438, 1446, 1454, 1463, 1471, 1479,
+    1493, 1500, 1534, 1549, 1560, 1573, 1589, 1601, 1609, 1617, 1625, 1632,
+    1640, 1645, 1645, 1657, 1664, 1669, 1678, 1685, 1695, 1710, 1720, 1738,
+    1750, 1757, 1766, 1778, 1791, 1799, 1807, 1820, 1828, 1828, 1844, 1864,
+    1867, 1875, 1883, 1894, 1908, 1916, 1916, 1921, 1929, 1941, 1955, 1955,
+    1961, 1968, 1968,
+];
+
+#[derive(Clone, Copy, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u16)]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "diesel", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow), diesel(sql_type = diesel::sql_types::Text))]
+pub enum Language {
+    /// Afar
+    Aar = 0,
+    /// Abkhazian
+    Abk = 1,
+    /// Afrikaans
+    Afr = 2,
+    /// Akan
+    Aka = 3,
+    /// Amharic
+    Amh = 4,
+    /// Arabic
+    Ara = 5,
+    /// Aragonese
+    Arg = 6,
+    /// Assamese
+    Asm = 7,
+    /// Avaric
+    Ava = 8,
+    /// Avestan
+    Ave = 9,
+    /// Aymara
+    Aym = 10,
+    /// Azerbaijani
+    Aze = 11,
+    /// Bashkir
+    Bak = 12,
+    /// Bambara
+    Bam = 13,
+    /// Belarusian
+    Bel = 14,
+    /// Bengali
+    Ben = 15,
+    /// Bislama
+    Bis = 16,
+    /// Tibetan
+    Bod = 17,
+    /// Bosnian
+    Bos = 18,
+    /// Breton
+    Bre = 19,
+    /// Bulgarian
+    Bul = 20,
+    /// Catalan
+    Cat = 21,
+    /// Czech
+    Ces = 22,
+    /// Chamorro
+    Cha = 23,
+    /// Chechen
+    Che = 24,
+    /// Church Slavic
+    Chu = 25,
+    /// Chuvash
+    Chv = 26,
+    /// Cornish
+    Cor = 27,
+    /// Corsican
+    Cos = 28,
+    /// Cree
+    Cre = 29,
+    /// Welsh
+    Cym = 30,
+    /// Danish
+    Dan = 31,
+    /// German
+    Deu = 32,
+    /// Dhivehi
+    Div = 33,
+    /// Dzongkha
+    Dzo = 34,
+    /// Modern Greek
+    Ell = 35,
+    /// English
+    Eng = 36,
+    /// Esperanto
+    Epo = 37,
+    /// Estonian
+    Est = 38,
+    /// Basque
+    Eus = 39,
+    /// Ewe
+    Ewe = 40,
+    /// Faroese
+    Fao = 41,
+    /// Persian
+    Fas = 42,
+    /// Fijian
+    Fij = 43,
+    /// Finnish
+    Fin = 44,
+    /// French
+    Fra = 45,
+    /// Western Frisian
+    Fry = 46,
+    /// Fulah
+    Ful = 47,
+    /// Scottish Gaelic
+    Gla = 48,
+    /// Irish
+    Gle = 49,
+    /// Galician
+    Glg = 50,
+    /// Manx
+    Glv = 51,
+    /// Guarani
+    Grn = 52,
+    /// Gujarati
+    Guj = 53,
+    /// Haitian
+    Hat = 54,
+    /// Hausa
+    Hau = 55,
+    /// Serbo-Croatian
+    Hbs = 56,
+    /// Hebrew
+    Heb = 57,
+    /// Herero
+    Her = 58,
+    /// Hindi
+    Hin = 59,
+    /// Hiri Motu
+    Hmo = 60,
+    /// Croatian
+    Hrv = 61,
+    /// Hungarian
+    Hun = 62,
+    /// Armenian
+    Hye = 63,
+    /// Igbo
+    Ibo = 64,
+    /// Ido
+    Ido = 65,
+    /// Sichuan Yi
+    Iii = 66,
+    /// Inuktitut
+    Iku = 67,
+    /// Interlingue
+    Ile = 68,
+    /// Interlingua
+    Ina = 69,
+    /// Indonesian
+    Ind = 70,
+    /// Inupiaq
+    Ipk = 71,
+    /// Icelandic
+    Isl = 72,
+    /// Italian
+    Ita = 73,
+    /// Javanese
+    Jav = 74,
+    /// Japanese
+    Jpn = 75,
+    /// Kalaallisut
+    Kal = 76,
+    /// Kannada
+    Kan = 77,
+    /// Kashmiri
+    Kas = 78,
+    /// Georgian
+    Kat = 79,
+    /// Kanuri
+    Kau = 80,
+    /// Kazakh
+    Kaz = 81,
+    /// Khmer
+    Khm = 82,
+    /// Kikuyu
+    Kik = 83,
+    /// Kinyarwanda
+    Kin = 84,
+    /// Kirghiz
+    Kir = 85,
+    /// Komi
+    Kom = 86,
+    /// Kongo
+    Kon = 87,
+    /// Korean
+    Kor = 88,
+    /// Kuanyama
+    Kua = 89,
+    /// Kurdish
+    Kur = 90,
+    /// Lao
+    Lao = 91,
+    /// Latin
+    Lat = 92,
+    /// Latvian
+    Lav = 93,
+    /// Limburgan
+    Lim = 94,
+    /// Lingala
+    Lin = 95,
+    /// Lithuanian
+    Lit = 96,
+    /// Luxembourgish
+    Ltz = 97,
+    /// Luba-Katanga
+    Lub = 98,
+    /// Ganda
+    Lug = 99,
+    /// Marshallese
+    Mah = 100,
+    /// Malayalam
+    Mal = 101,
+    /// Marathi
+    Mar = 102,
+    /// Uncoded languages
+    Mis = 103,
+    /// Macedonian
+    Mkd = 104,
+    /// Malagasy
+    Mlg = 105,
+    /// Maltese
+    Mlt = 106,
+    /// Mongolian
+    Mon = 107,
+    /// Maori
+    Mri = 108,
+    /// Malay
+    Msa = 109,
+    /// Multiple languages
+    Mul = 110,
+    /// Burmese
+    Mya = 111,
+    /// Nauru
+    Nau = 112,
+    /// Navajo
+    Nav = 113,
+    /// South Ndebele
+    Nbl = 114,
+    /// North Ndebele
+    Nde = 115,
+    /// Ndonga
+    Ndo = 116,
+    /// Nepali
+    Nep = 117,
+    /// Dutch
+    Nld = 118,
+    /// Norwegian Nynorsk
+    Nno = 119,
+    /// Norwegian Bokmål
+    Nob = 120,
+    /// Norwegian
+    Nor = 121,
+    /// Chichewa
+    Nya = 122,
+    /// Occitan
+    Oci = 123,
+    /// Ojibwa
+    Oji = 124,
+    /// Oriya
+    Ori = 125,
+    /// Oromo
+    Orm = 126,
+    /// Iron Ossetic
+    Oss = 127,
+    /// Panjabi
+    Pan = 128,
+    /// Pali
+    Pli = 129,
+    /// Polish
+    Pol = 130,
+    /// Portuguese
+    Por = 131,
+    /// Pushto
+    Pus = 132,
+    /// Quechua
+    Que = 133,
+    /// Romansh
+    Roh = 134,
+    /// Romanian
+    Ron = 135,
+    /// Rundi
+    Run = 136,
+    /// Russian
+    Rus = 137,
+    /// Sango
+    Sag = 138,
+    /// Sanskrit
+    San = 139,
+    /// Sinhala
+    Sin = 140,
+    /// Slovak
+    Slk = 141,
+    /// Slovenian
+    Slv = 142,
+    /// Northern Sami
+    Sme = 143,
+    /// Samoan
+    Smo = 144,
+    /// Shona
+    Sna = 145,
+    /// Sindhi
+    Snd = 146,
+    /// Somali
+    Som = 147,
+    /// Southern Sotho
+    Sot = 148,
+    /// Spanish
+    Spa = 149,
+    /// Albanian
+    Sqi = 150,
+    /// Sardinian
+    Srd = 151,
+    /// Serbian
+    Srp = 152,
+    /// Swati
+    Ssw = 153,
+    /// Sundanese
+    Sun = 154,
+    /// Swahili
+    Swa = 155,
+    /// Swedish
+    Swe = 156,
+    /// Tahitian
+    Tah = 157,
+    /// Tamil
+    Tam = 158,
+    /// Tatar
+    Tat = 159,
+    /// Telugu
+    Tel = 160,
+    /// Tajik
+    Tgk = 161,
+    /// Tagalog
+    Tgl = 162,
+    /// Thai
+    Tha = 163,
+    /// Tigrinya
+    Tir = 164,
+    /// Tonga
+    Ton = 165,
+    /// Tswana
+    Tsn = 166,
+    /// Tsonga
+    Tso = 167,
+    /// Turkmen
+    Tuk = 168,
+    /// Turkish
+    Tur = 169,
+    /// Twi
+    Twi = 170,
+    /// Uighur
+    Uig = 171,
+    /// Ukrainian
+    Ukr = 172,
+    /// Undetermined
+    Und = 173,
+    /// Urdu
+    Urd = 174,
+    /// Uzbek
+    Uzb = 175,
+    /// Venda
+    Ven = 176,
+    /// Vietnamese
+    Vie = 177,
+    /// Volapük
+    Vol = 178,
+    /// Walloon
+    Wln = 179,
+    /// Wolof
+    Wol = 180,
+    /// Xhosa
+    Xho = 181,
+    /// Yiddish
+    Yid = 182,
+    /// Yoruba
+    Yor = 183,
+    /// Zhuang
+    Zha = 184,
+    /// Chinese
+    Zho = 185,
+    /// Zulu
+    Zul = 186,
+    /// No linguistic content
+    Zxx = 187,
+}
+
+pub(crate) const TWO_TO_THREE: phf::Map<&str, u16> = ::phf::Map {
+    key: 16287231350648472473,
+    disps: &[
+        (0, 28),
+        (0, 0),
+        (1, 2),
+        (0, 0),
+        (0, 7),
+        (0, 85),
+        (0, 1),
+        (0, 77),
+        (0, 17),
+        (0, 23),
+        (0, 70),
+        (2, 57),
+        (1, 16),
+        (0, 6),
+        (0, 2),
+        (0, 77),
+        (0, 24),
+        (3, 183),
+        (3, 120),
+        (1, 2),
+        (1, 112),
+        (0, 18),
+        (1, 39),
+        (12, 70),
+        (0, 2),
+        (0, 2),
+        (84, 144),
+        (0, 143),
+        (0, 60),
+        (4, 108),
+        (1, 87),
+        (2, 0),
+        (5, 7),
+        (26, 41),
+        (0, 105),
+        (0, 32),
+        (3, 79),
+    ],
+    entries: &[
+        ("uz", Language::Uzb as u16),
+        ("eu", Language::Eus as u16),
+        ("nv", Language::Nav as u16),
+        ("ms", Language::Msa as u16),
+        ("se", Language::Sme as u16),
+        ("zu", Language::Zul as u16),
+        ("za", Language::Zha as u16),
+        ("ne", Language::Nep as u16),
+        ("lu", Language::Lub as u16),
+        ("an", Language::Arg as u16),
+        ("ln", Language::Lin as u16),
+        ("eo", Language::Epo as u16),
+        ("bs", Language::Bos as u16),
+        ("az", Language::Aze as u16),
+        ("lt", Language::Lit as u16),
+        ("mi", Language::Mri as u16),
+        ("om", Language::Orm as u16),
+        ("rw", Language::Kin as u16),
+        ("ki", Language::Kik as u16),
+        ("kv", Language::Kom as u16),
+        ("mr", Language::Mar as u16),
+        ("ro", Language::Ron as u16),
+        ("ay", Language::Aym as u16),
+        ("ee", Language::Ewe as u16),
+        ("oc", Language::Oci as u16),
+        ("mt", Language::Mlt as u16),
+        ("tk", Language::Tuk as u16),
+        ("co", Language::Cos as u16),
+        ("ga", Language::Gle as u16),
+        ("ba", Language::Bak as u16),
+        ("so", Language::Som as u16),
+        ("hz", Language::Her as u16),
+        ("st", Language::Sot as u16),
+        ("pt", Language::Por as u16),
+        ("oj", Language::Oji as u16),
+        ("no", Language::Nor as u16),
+        ("vo", Language::Vol as u16),
+        ("mn", Language::Mon as u16),
+        ("ho", Language::Hmo as u16),
+        ("be", Language::Bel as u16),
+        ("dz", Language::Dzo as u16),
+        ("ta", Language::Tam as u16),
+        ("kr", Language::Kau as u16),
+        ("sa", Language::San as u16),
+        ("to", Language::Ton as u16),
+        ("br", Language::Bre as u16),
+        ("cy", Language::Cym as u16),
+        ("hi", Language::Hin as u16),
+        ("la", Language::Lat as u16),
+        ("ca", Language::Cat as u16),
+        ("zh", Language::Zho as u16),
+        ("tt", Language::Tat as u16),
+        ("tr", Language::Tur as u16),
+        ("kn", Language::Kan as u16),
+        ("sn", Language::Sna as u16),
+        ("is", Language::Isl as u16),
+        ("hy", Language::Hye as u16),
+        ("ti", Language::Tir as u16),
+        ("wa", Language::Wln as u16),
+        ("nn", Language::Nno as u16),
+        ("it", Language::Ita as u16),
+        ("si", Language::Sin as u16),
+        ("bm", Language::Bam as u16),
+        ("ts", Language::Tso as u16),
+        ("dv", Language::Div as u16),
+        ("tn", Language::Tsn as u16),
+        ("lb", Language::Ltz as u16),
+        ("te", Language::Tel as u16),
+        ("el", Language::Ell as u16),
+        ("xh", Language::Xho as u16),
+        ("sm", Language::Smo as u16),
+        ("kk", Language::Kaz as u16),
+        ("ii", Language::Iii as u16),
+        ("pi", Language::Pli as u16),
+        ("na", Language::Nau as u16),
+        ("kj", Language::Kua as u16),
+        ("io", Language::Ido as u16),
+        ("en", Language::Eng as u16),
+        ("as", Language::Asm as u16),
+        ("sd", Language::Snd as u16),
+        ("da", Language::Dan as u16),
+        ("qu", Language::Que as u16),
+        ("nr", Language::Nbl as u16),
+        ("gd", Language::Gla as u16),
+        ("km", Language::Khm as u16),
+        ("ig", Language::Ibo as u16),
+        ("bo", Language::Bod as u16),
+        ("av", Language::Ava as u16),
+        ("ch", Language::Cha as u16),
+        ("bi", Language::Bis as u16),
+        ("sw", Language::Swa as u16),
+        ("ng", Language::Ndo as u16),
+        ("ha", Language::Hau as u16),
+        ("ar", Language::Ara as u16),
+        ("ku", Language::Kur as u16),
+        ("ss", Language::Ssw as u16),
+        ("sh", Language::Hbs as u16),
+        ("ko", Language::Kor as u16),
+        ("ia", Language::Ina as u16),
+        ("iu", Language::Iku as u16),
+        ("yi", Language::Yid as u16),
+        ("sr", Language::Srp as u16),
+        ("hr", Language::Hrv as u16),
+        ("fa", Language::Fas as u16),
+        ("lv", Language::Lav as u16),
+        ("ug", Language::Uig as u16),
+        ("gl", Language::Glg as u16),
+        ("ve", Language::Ven as u16),
+        ("ks", Language::Kas as u16),
+        ("tl", Language::Tgl as u16),
+        ("lg", Language::Lug as u16),
+        ("ka", Language::Kat as u16),
+        ("sg", Language::Sag as u16),
+        ("mg", Language::Mlg as u16),
+        ("fo", Language::Fao as u16),
+        ("et", Language::Est as u16),
+        ("fy", Language::Fry as u16),
+        ("kg", Language::Kon as u16),
+        ("gv", Language::Glv as u16),
+        ("gn", Language::Grn as u16),
+        ("wo", Language::Wol as u16),
+        ("tw", Language::Twi as u16),
+        ("aa", Language::Aar as u16),
+        ("rn", Language::Run as u16),
+        ("bn", Language::Ben as u16),
+        ("ps", Language::Pus as u16),
+        ("ky", Language::Kir as u16),
+        ("th", Language::Tha as u16),
+        ("de", Language::Deu as u16),
+        ("pl", Language::Pol as u16),
+        ("bg", Language::Bul as u16),
+        ("am", Language::Amh as u16),
+        ("ae", Language::Ave as u16),
+        ("rm", Language::Roh as u16),
+        ("sk", Language::Slk as u16),
+        ("he", Language::Heb as u16),
+        ("my", Language::Mya as u16),
+        ("sq", Language::Sqi as u16),
+        ("ik", Language::Ipk as u16),
+        ("kw", Language::Cor as u16),
+        ("cv", Language::Chv as u16),
+        ("ie", Language::Ile as u16),
+        ("ml", Language::Mal as u16),
+        ("uk", Language::Ukr as u16),
+        ("ja", Language::Jpn as u16),
+        ("kl", Language::Kal as u16),
+        ("nb", Language::Nob as u16),
+        ("ht", Language::Hat as u16),
+        ("mk", Language::Mkd as u16),
+        ("jv", Language::Jav as u16),
+        ("fi", Language::Fin as u16),
+        ("ff", Language::Ful as u16),
+        ("lo", Language::Lao as u16),
+        ("vi", Language::Vie as u16),
+        ("su", Language::Sun as u16),
+        ("es", Language::Spa as u16),
+        ("fr", Language::Fra as u16),
+        ("nd", Language::Nde as u16),
+        ("sl", Language::Slv as u16),
+        ("yo", Language::Yor as u16),
+        ("ak", Language::Aka as u16),
+        ("ny", Language::Nya as u16),
+        ("id", Language::Ind as u16),
+        ("gu", Language::Guj as u16),
+        ("or", Language::Ori as u16),
+        ("ru", Language::Rus as u16),
+        ("hu", Language::Hun as u16),
+        ("pa", Language::Pan as u16),
+        ("ur", Language::Urd as u16),
+        ("li", Language::Lim as u16),
+        ("af", Language::Afr as u16),
+        ("sv", Language::Swe as u16),
+        ("sc", Language::Srd as u16),
+        ("cu", Language::Chu as u16),
+        ("fj", Language::Fij as u16),
+        ("ce", Language::Che as u16),
+        ("ab", Language::Abk as u16),
+        ("tg", Language::Tgk as u16),
+        ("nl", Language::Nld as u16),
+        ("cs", Language::Ces as u16),
+        ("os", Language::Oss as u16),
+        ("ty", Language::Tah as u16),
+        ("mh", Language::Mah as u16),
+        ("cr", Language::Cre as u16),
+    ],
+};
+
+pub(crate) const THREE_TO_THREE: phf::Map<&str, u16> = ::phf::Map {
+    key: 16287231350648472473,
+    disps: &[
+        (0, 3),
+        (0, 2),
+        (0, 19),
+        (1, 73),
+        (0, 9),
+        (2, 39),
+        (0, 74),
+        (0, 183),
+        (0, 25),
+        (0, 141),
+        (0, 32),
+        (0, 0),
+        (5, 49),
+        (4, 150),
+        (0, 9),
+        (1, 0),
+        (0, 59),
+        (0, 162),
+        (29, 82),
+        (2, 36),
+        (0, 16),
+        (2, 6),
+        (0, 42),
+        (6, 68),
+        (0, 25),
+        (7, 116),
+        (14, 160),
+        (0, 121),
+        (7, 100),
+        (0, 96),
+        (0, 64),
+        (0, 31),
+        (28, 153),
+        (0, 30),
+        (0, 1),
+        (6, 118),
+        (2, 1),
+        (0, 3),
+    ],
+    entries: &[
+        ("ina", Language::Ina as u16),
+        ("mlt", Language::Mlt as u16),
+        ("oss", Language::Oss as u16),
+        ("ven", Language::Ven as u16),
+        ("tuk", Language::Tuk as u16),
+        ("hmo", Language::Hmo as u16),
+        ("kom", Language::Kom as u16),
+        ("mul", Language::Mul as u16),
+        ("srp", Language::Srp as u16),
+        ("chv", Language::Chv as u16),
+        ("ton", Language::Ton as u16),
+        ("bre", Language::Bre as u16),
+        ("wln", Language::Wln as u16),
+        ("hin", Language::Hin as u16),
+        ("sin", Language::Sin as u16),
+        ("hrv", Language::Hrv as u16),
+        ("ben", Language::Ben as u16),
+        ("kik", Language::Kik as u16),
+        ("nep", Language::Nep as u16),
+        ("ssw", Language::Ssw as u16),
+        ("ful", Language::Ful as u16),
+        ("mon", Language::Mon as u16),
+        ("jav", Language::Jav as u16),
+        ("iku", Language::Iku as u16),
+        ("bel", Language::Bel as u16),
+        ("som", Language::Som as u16),
+        ("mya", Language::Mya as u16),
+        ("kon", Language::Kon as u16),
+        ("aym", Language::Aym as u16),
+        ("msa", Language::Msa as u16),
+        ("kas", Language::Kas as u16),
+        ("que", Language::Que as u16),
+        ("bul", Language::Bul as u16),
+        ("tur", Language::Tur as u16),
+        ("vol", Language::Vol as u16),
+        ("uzb", Language::Uzb as u16),
+        ("ron", Language::Ron as u16),
+        ("ibo", Language::Ibo as u16),
+        ("div", Language::Div as u16),
+        ("glg", Language::Glg as u16),
+        ("nob", Language::Nob as u16),
+        ("zul", Language::Zul as u16),
+        ("spa", Language::Spa as u16),
+        ("xho", Language::Xho as u16),
+        ("sun", Language::Sun as u16),
+        ("sna", Language::Sna as u16),
+        ("swa", Language::Swa as u16),
+        ("nno", Language::Nno as u16),
+        ("eng", Language::Eng as u16),
+        ("uig", Language::Uig as u16),
+        ("yid", Language::Yid as u16),
+        ("lat", Language::Lat as u16),
+        ("fao", Language::Fao as u16),
+        ("por", Language::Por as u16),
+        ("hau", Language::Hau as u16),
+        ("snd", Language::Snd as u16),
+        ("glv", Language::Glv as u16),
+        ("ita", Language::Ita as u16),
+        ("fra", Language::Fra as u16),
+        ("ido", Language::Ido as u16),
+        ("ipk", Language::Ipk as u16),
+        ("sme", Language::Sme as u16),
+        ("ewe", Language::Ewe as u16),
+        ("gla", Language::Gla as u16),
+        ("nld", Language::Nld as u16),
+        ("srd", Language::Srd as u16),
+        ("fin", Language::Fin as u16),
+        ("roh", Language::Roh as u16),
+        ("ava", Language::Ava as u16),
+        ("ori", Language::Ori as u16),
+        ("kur", Language::Kur as u16),
+        ("jpn", Language::Jpn as u16),
+        ("abk", Language::Abk as u16),
+        ("vie", Language::Vie as u16),
+        ("her", Language::Her as u16),
+        ("mlg", Language::Mlg as u16),
+        ("und", Language::Und as u16),
+        ("aar", Language::Aar as u16),
+        ("pli", Language::Pli as u16),
+        ("hun", Language::Hun as u16),
+        ("nor", Language::Nor as u16),
+        ("fas", Language::Fas as u16),
+        ("kor", Language::Kor as u16),
+        ("hat", Language::Hat as u16),
+        ("kin", Language::Kin as u16),
+        ("ell", Language::Ell as u16),
+        ("cym", Language::Cym as u16),
+        ("cha", Language::Cha as u16),
+        ("tah", Language::Tah as u16),
+        ("dzo", Language::Dzo as u16),
+        ("kau", Language::Kau as u16),
+        ("lim", Language::Lim as u16),
+        ("ndo", Language::Ndo as u16),
+        ("khm", Language::Khm as u16),
+        ("nya", Language::Nya as u16),
+        ("est", Language::Est as u16),
+        ("smo", Language::Smo as u16),
+        ("hbs", Language::Hbs as u16),
+        ("mis", Language::Mis as u16),
+        ("ave", Language::Ave as u16),
+        ("tgk", Language::Tgk as u16),
+        ("pan", Language::Pan as u16),
+        ("kan", Language::Kan as u16),
+        ("kaz", Language::Kaz as u16),
+        ("gle", Language::Gle as u16),
+        ("deu", Language::Deu as u16),
+        ("nbl", Language::Nbl as u16),
+        ("amh", Language::Amh as u16),
+        ("fij", Language::Fij as u16),
+        ("mkd", Language::Mkd as u16),
+        ("pol", Language::Pol as u16),
+        ("aka", Language::Aka as u16),
+        ("mah", Language::Mah as u16),
+        ("bod", Language::Bod as u16),
+        ("lug", Language::Lug as u16),
+        ("isl", Language::Isl as u16),
+        ("oji", Language::Oji as u16),
+        ("nde", Language::Nde as u16),
+        ("ind", Language::Ind as u16),
+        ("eus", Language::Eus as u16),
+        ("tsn", Language::Tsn as u16),
+        ("slk", Language::Slk as u16),
+        ("tgl", Language::Tgl as u16),
+        ("tam", Language::Tam as u16),
+        ("mri", Language::Mri as u16),
+        ("iii", Language::Iii as u16),
+        ("zho", Language::Zho as u16),
+        ("bis", Language::Bis as u16),
+        ("cos", Language::Cos as u16),
+        ("nav", Language::Nav as u16),
+        ("wol", Language::Wol as u16),
+        ("kir", Language::Kir as u16),
+        ("mal", Language::Mal as u16),
+        ("nau", Language::Nau as u16),
+        ("ara", Language::Ara as u16),
+        ("lub", Language::Lub as u16),
+        ("aze", Language::Aze as u16),
+        ("zha", Language::Zha as u16),
+        ("tso", Language::Tso as u16),
+        ("swe", Language::Swe as u16),
+        ("pus", Language::Pus as u16),
+        ("tel", Language::Tel as u16),
+        ("chu", Language::Chu as u16),
+        ("hye", Language::Hye as u16),
+        ("slv", Language::Slv as u16),
+        ("lao", Language::Lao as u16),
+        ("ukr", Language::Ukr as u16),
+        ("bam", Language::Bam as u16),
+        ("che", Language::Che as u16),
+        ("orm", Language::Orm as u16),
+        ("sqi", Language::Sqi as u16),
+        ("kua", Language::Kua as u16),
+        ("dan", Language::Dan as u16),
+        ("tat", Language::Tat as u16),
+        ("asm", Language::Asm as u16),
+        ("tir", Language::Tir as u16),
+        ("cor", Language::Cor as u16),
+        ("ces", Language::Ces as u16),
+        ("afr", Language::Afr as u16),
+        ("sag", Language::Sag as u16),
+        ("cat", Language::Cat as u16),
+        ("heb", Language::Heb as u16),
+        ("tha", Language::Tha as u16),
+        ("kat", Language::Kat as u16),
+        ("bos", Language::Bos as u16),
+        ("lav", Language::Lav as u16),
+        ("epo", Language::Epo as u16),
+        ("sot", Language::Sot as u16),
+        ("rus", Language::Rus as u16),
+        ("lit", Language::Lit as u16),
+        ("lin", Language::Lin as u16),
+        ("guj", Language::Guj as u16),
+        ("yor", Language::Yor as u16),
+        ("bak", Language::Bak as u16),
+        ("run", Language::Run as u16),
+        ("grn", Language::Grn as u16),
+        ("ltz", Language::Ltz as u16),
+        ("cre", Language::Cre as u16),
+        ("twi", Language::Twi as u16),
+        ("ile", Language::Ile as u16),
+        ("oci", Language::Oci as u16),
+        ("kal", Language::Kal as u16),
+        ("fry", Language::Fry as u16),
+        ("zxx", Language::Zxx as u16),
+        ("mar", Language::Mar as u16),
+        ("arg", Language::Arg as u16),
+        ("urd", Language::Urd as u16),
+        ("san", Language::San as u16),
+    ],
+};
+pub(crate) const fn language_from_639_1(code: &[u8; 2]) -> Option<Language> {
+    match code {
+        b"aa" => Some(Language::Aar),
+        b"ab" => Some(Language::Abk),
+        b"af" => Some(Language::Afr),
+        b"ak" => Some(Language::Aka),
+        b"am" => Some(Language::Amh),
+        b"ar" => Some(Language::Ara),
+        b"an" => Some(Language::Arg),
+        b"as" => Some(Language::Asm),
+        b"av" => Some(Language::Ava),
+        b"ae" => Some(Language::Ave),
+        b"ay" => Some(Language::Aym),
+        b"az" => Some(Language::Aze),
+        b"ba" => Some(Language::Bak),
+        b"bm" => Some(Language::Bam),
+        b"be" => Some(Language::Bel),
+        b"bn" => Some(Language::Ben),
+        b"bi" => Some(Language::Bis),
+        b"bo" => Some(Language::Bod),
+        b"bs" => Some(Language::Bos),
+        b"br" => Some(Language::Bre),
+        b"bg" => Some(Language::Bul),
+        b"ca" => Some(Language::Cat),
+        b"cs" => Some(Language::Ces),
+        b"ch" => Some(Language::Cha),
+        b"ce" => Some(Language::Che),
+        b"cu" => Some(Language::Chu),
+        b"cv" => Some(Language::Chv),
+        b"kw" => Some(Language::Cor),
+        b"co" => Some(Language::Cos),
+        b"cr" => Some(Language::Cre),
+        b"cy" => Some(Language::Cym),
+        b"da" => Some(Language::Dan),
+        b"de" => Some(Language::Deu),
+        b"dv" => Some(Language::Div),
+        b"dz" => Some(Language::Dzo),
+        b"el" => Some(Language::Ell),
+        b"en" => Some(Language::Eng),
+        b"eo" => Some(Language::Epo),
+        b"et" => Some(Language::Est),
+        b"eu" => Some(Language::Eus),
+        b"ee" => Some(Language::Ewe),
+        b"fo" => Some(Language::Fao),
+        b"fa" => Some(Language::Fas),
+        b"fj" => Some(Language::Fij),
+        b"fi" => Some(Language::Fin),
+        b"fr" => Some(Language::Fra),
+        b"fy" => Some(Language::Fry),
+        b"ff" => Some(Language::Ful),
+        b"gd" => Some(Language::Gla),
+        b"ga" => Some(Language::Gle),
+        b"gl" => Some(Language::Glg),
+        b"gv" => Some(Language::Glv),
+        b"gn" => Some(Language::Grn),
+        b"gu" => Some(Language::Guj),
+        b"ht" => Some(Language::Hat),
+        b"ha" => Some(Language::Hau),
+        b"sh" => Some(Language::Hbs),
+        b"he" => Some(Language::Heb),
+        b"hz" => Some(Language::Her),
+        b"hi" => Some(Language::Hin),
+        b"ho" => Some(Language::Hmo),
+        b"hr" => Some(Language::Hrv),
+        b"hu" => Some(Language::Hun),
+        b"hy" => Some(Language::Hye),
+        b"ig" => Some(Language::Ibo),
+        b"io" => Some(Language::Ido),
+        b"ii" => Some(Language::Iii),
+        b"iu" => Some(Language::Iku),
+        b"ie" => Some(Language::Ile),
+        b"ia" => Some(Language::Ina),
+        b"id" => Some(Language::Ind),
+        b"ik" => Some(Language::Ipk),
+        b"is" => Some(Language::Isl),
+        b"it" => Some(Language::Ita),
+        b"jv" => Some(Language::Jav),
+        b"ja" => Some(Language::Jpn),
+        b"kl" => Some(Language::Kal),
+        b"kn" => Some(Language::Kan),
+        b"ks" => Some(Language::Kas),
+        b"ka" => Some(Language::Kat),
+        b"kr" => Some(Language::Kau),
+        b"kk" => Some(Language::Kaz),
+        b"km" => Some(Language::Khm),
+        b"ki" => Some(Language::Kik),
+        b"rw" => Some(Language::Kin),
+        b"ky" => Some(Language::Kir),
+        b"kv" => Some(Language::Kom),
+        b"kg" => Some(Language::Kon),
+        b"ko" => Some(Language::Kor),
+        b"kj" => Some(Language::Kua),
+        b"ku" => Some(Language::Kur),
+        b"lo" => Some(Language::Lao),
+        b"la" => Some(Language::Lat),
+        b"lv" => Some(Language::Lav),
+        b"li" => Some(Language::Lim),
+        b"ln" => Some(Language::Lin),
+        b"lt" => Some(Language::Lit),
+        b"lb" => Some(Language::Ltz),
+        b"lu" => Some(Language::Lub),
+        b"lg" => Some(Language::Lug),
+        b"mh" => Some(Language::Mah),
+        b"ml" => Some(Language::Mal),
+        b"mr" => Some(Language::Mar),
+        b"mk" => Some(Language::Mkd),
+        b"mg" => Some(Language::Mlg),
+        b"mt" => Some(Language::Mlt),
+        b"mn" => Some(Language::Mon),
+        b"mi" => Some(Language::Mri),
+        b"ms" => Some(Language::Msa),
+        b"my" => Some(Language::Mya),
+        b"na" => Some(Language::Nau),
+        b"nv" => Some(Language::Nav),
+        b"nr" => Some(Language::Nbl),
+        b"nd" => Some(Language::Nde),
+        b"ng" => Some(Language::Ndo),
+        b"ne" => Some(Language::Nep),
+        b"nl" => Some(Language::Nld),
+        b"nn" => Some(Language::Nno),
+        b"nb" => Some(Language::Nob),
+        b"no" => Some(Language::Nor),
+        b"ny" => Some(Language::Nya),
+        b"oc" => Some(Language::Oci),
+        b"oj" => Some(Language::Oji),
+        b"or" => Some(Language::Ori),
+        b"om" => Some(Language::Orm),
+        b"os" => Some(Language::Oss),
+        b"pa" => Some(Language::Pan),
+        b"pi" => Some(Language::Pli),
+        b"pl" => Some(Language::Pol),
+        b"pt" => Some(Language::Por),
+        b"ps" => Some(Language::Pus),
+        b"qu" => Some(Language::Que),
+        b"rm" => Some(Language::Roh),
+        b"ro" => Some(Language::Ron),
+        b"rn" => Some(Language::Run),
+        b"ru" => Some(Language::Rus),
+        b"sg" => Some(Language::Sag),
+        b"sa" => Some(Language::San),
+        b"si" => Some(Language::Sin),
+        b"sk" => Some(Language::Slk),
+        b"sl" => Some(Language::Slv),
+        b"se" => Some(Language::Sme),
+        b"sm" => Some(Language::Smo),
+        b"sn" => Some(Language::Sna),
+        b"sd" => Some(Language::Snd),
+        b"so" => Some(Language::Som),
+        b"st" => Some(Language::Sot),
+        b"es" => Some(Language::Spa),
+        b"sq" => Some(Language::Sqi),
+        b"sc" => Some(Language::Srd),
+        b"sr" => Some(Language::Srp),
+        b"ss" => Some(Language::Ssw),
+        b"su" => Some(Language::Sun),
+        b"sw" => Some(Language::Swa),
+        b"sv" => Some(Language::Swe),
+        b"ty" => Some(Language::Tah),
+        b"ta" => Some(Language::Tam),
+        b"tt" => Some(Language::Tat),
+        b"te" => Some(Language::Tel),
+        b"tg" => Some(Language::Tgk),
+        b"tl" => Some(Language::Tgl),
+        b"th" => Some(Language::Tha),
+        b"ti" => Some(Language::Tir),
+        b"to" => Some(Language::Ton),
+        b"tn" => Some(Language::Tsn),
+        b"ts" => Some(Language::Tso),
+        b"tk" => Some(Language::Tuk),
+        b"tr" => Some(Language::Tur),
+        b"tw" => Some(Language::Twi),
+        b"ug" => Some(Language::Uig),
+        b"uk" => Some(Language::Ukr),
+        b"ur" => Some(Language::Urd),
+        b"uz" => Some(Language::Uzb),
+        b"ve" => Some(Language::Ven),
+        b"vi" => Some(Language::Vie),
+        b"vo" => Some(Language::Vol),
+        b"wa" => Some(Language::Wln),
+        b"wo" => Some(Language::Wol),
+        b"xh" => Some(Language::Xho),
+        b"yi" => Some(Language::Yid),
+        b"yo" => Some(Language::Yor),
+        b"za" => Some(Language::Zha),
+        b"zh" => Some(Language::Zho),
+        b"zu" => Some(Language::Zul),
+        _ => None,
+    }
+}
+pub(crate) const fn language_from_639_3(code: &[u8; 3]) -> Option<Language> {
+    match code {
+        b"aar" => Some(Language::Aar),
+        b"abk" => Some(Language::Abk),
+        b"afr" => Some(Language::Afr),
+        b"aka" => Some(Language::Aka),
+        b"amh" => Some(Language::Amh),
+        b"ara" => Some(Language::Ara),
+        b"arg" => Some(Language::Arg),
+        b"asm" => Some(Language::Asm),
+        b"ava" => Some(Language::Ava),
+        b"ave" => Some(Language::Ave),
+        b"aym" => Some(Language::Aym),
+        b"aze" => Some(Language::Aze),
+        b"bak" => Some(Language::Bak),
+        b"bam" => Some(Language::Bam),
+        b"bel" => Some(Language::Bel),
+        b"ben" => Some(Language::Ben),
+        b"bis" => Some(Language::Bis),
+        b"bod" => Some(Language::Bod),
+        b"bos" => Some(Language::Bos),
+        b"bre" => Some(Language::Bre),
+        b"bul" => Some(Language::Bul),
+        b"cat" => Some(Language::Cat),
+        b"ces" => Some(Language::Ces),
+        b"cha" => Some(Language::Cha),
+        b"che" => Some(Language::Che),
+        b"chu" => Some(Language::Chu),
+        b"chv" => Some(Language::Chv),
+        b"cor" => Some(Language::Cor),
+        b"cos" => Some(Language::Cos),
+        b"cre" => Some(Language::Cre),
+        b"cym" => Some(Language::Cym),
+        b"dan" => Some(Language::Dan),
+        b"deu" => Some(Language::Deu),
+        b"div" => Some(Language::Div),
+        b"dzo" => Some(Language::Dzo),
+        b"ell" => Some(Language::Ell),
+        b"eng" => Some(Language::Eng),
+        b"epo" => Some(Language::Epo),
+        b"est" => Some(Language::Est),
+        b"eus" => Some(Language::Eus),
+        b"ewe" => Some(Language::Ewe),
+        b"fao" => Some(Language::Fao),
+        b"fas" => Some(Language::Fas),
+        b"fij" => Some(Language::Fij),
+        b"fin" => Some(Language::Fin),
+        b"fra" => Some(Language::Fra),
+        b"fry" => Some(Language::Fry),
+        b"ful" => Some(Language::Ful),
+        b"gla" => Some(Language::Gla),
+        b"gle" => Some(Language::Gle),
+        b"glg" => Some(Language::Glg),
+        b"glv" => Some(Language::Glv),
+        b"grn" => Some(Language::Grn),
+        b"guj" => Some(Language::Guj),
+        b"hat" => Some(Language::Hat),
+        b"hau" => Some(Language::Hau),
+        b"hbs" => Some(Language::Hbs),
+        b"heb" => Some(Language::Heb),
+        b"her" => Some(Language::Her),
+        b"hin" => Some(Language::Hin),
+        b"hmo" => Some(Language::Hmo),
+        b"hrv" => Some(Language::Hrv),
+        b"hun" => Some(Language::Hun),
+        b"hye" => Some(Language::Hye),
+        b"ibo" => Some(Language::Ibo),
+        b"ido" => Some(Language::Ido),
+        b"iii" => Some(Language::Iii),
+        b"iku" => Some(Language::Iku),
+        b"ile" => Some(Language::Ile),
+        b"ina" => Some(Language::Ina),
+        b"ind" => Some(Language::Ind),
+        b"ipk" => Some(Language::Ipk),
+        b"isl" => Some(Language::Isl),
+        b"ita" => Some(Language::Ita),
+        b"jav" => Some(Language::Jav),
+        b"jpn" => Some(Language::Jpn),
+        b"kal" => Some(Language::Kal),
+        b"kan" => Some(Language::Kan),
+        b"kas" => Some(Language::Kas),
+        b"kat" => Some(Language::Kat),
+        b"kau" => Some(Language::Kau),
+        b"kaz" => Some(Language::Kaz),
+        b"khm" => Some(Language::Khm),
+        b"kik" => Some(Language::Kik),
+        b"kin" => Some(Language::Kin),
+        b"kir" => Some(Language::Kir),
+        b"kom" => Some(Language::Kom),
+        b"kon" => Some(Language::Kon),
+        b"kor" => Some(Language::Kor),
+        b"kua" => Some(Language::Kua),
+        b"kur" => Some(Language::Kur),
+        b"lao" => Some(Language::Lao),
+        b"lat" => Some(Language::Lat),
+        b"lav" => Some(Language::Lav),
+        b"lim" => Some(Language::Lim),
+        b"lin" => Some(Language::Lin),
+        b"lit" => Some(Language::Lit),
+        b"ltz" => Some(Language::Ltz),
+        b"lub" => Some(Language::Lub),
+        b"lug" => Some(Language::Lug),
+        b"mah" => Some(Language::Mah),
+        b"mal" => Some(Language::Mal),
+        b"mar" => Some(Language::Mar),
+        b"mis" => Some(Language::Mis),
+        b"mkd" => Some(Language::Mkd),
+        b"mlg" => Some(Language::Mlg),
+        b"mlt" => Some(Language::Mlt),
+        b"mon" => Some(Language::Mon),
+        b"mri" => Some(Language::Mri),
+        b"msa" => Some(Language::Msa),
+        b"mul" => Some(Language::Mul),
+        b"mya" => Some(Language::Mya),
+        b"nau" => Some(Language::Nau),
+        b"nav" => Some(Language::Nav),
+        b"nbl" => Some(Language::Nbl),
+        b"nde" => Some(Language::Nde),
+        b"ndo" => Some(Language::Ndo),
+        b"nep" => Some(Language::Nep),
+        b"nld" => Some(Language::Nld),
+        b"nno" => Some(Language::Nno),
+        b"nob" => Some(Language::Nob),
+        b"nor" => Some(Language::Nor),
+        b"nya" => Some(Language::Nya),
+        b"oci" => Some(Language::Oci),
+        b"oji" => Some(Language::Oji),
+        b"ori" => Some(Language::Ori),
+        b"orm" => Some(Language::Orm),
+        b"oss" => Some(Language::Oss),
+        b"pan" => Some(Language::Pan),
+        b"pli" => Some(Language::Pli),
+        b"pol" => Some(Language::Pol),
+        b"por" => Some(Language::Por),
+        b"pus" => Some(Language::Pus),
+        b"que" => Some(Language::Que),
+        b"roh" => Some(Language::Roh),
+        b"ron" => Some(Language::Ron),
+        b"run" => Some(Language::Run),
+        b"rus" => Some(Language::Rus),
+        b"sag" => Some(Language::Sag),
+        b"san" => Some(Language::San),
+        b"sin" => Some(Language::Sin),
+        b"slk" => Some(Language::Slk),
+        b"slv" => Some(Language::Slv),
+        b"sme" => Some(Language::Sme),
+        b"smo" => Some(Language::Smo),
+        b"sna" => Some(Language::Sna),
+        b"snd" => Some(Language::Snd),
+        b"som" => Some(Language::Som),
+        b"sot" => Some(Language::Sot),
+        b"spa" => Some(Language::Spa),
+        b"sqi" => Some(Language::Sqi),
+        b"srd" => Some(Language::Srd),
+        b"srp" => Some(Language::Srp),
+        b"ssw" => Some(Language::Ssw),
+        b"sun" => Some(Language::Sun),
+        b"swa" => Some(Language::Swa),
+        b"swe" => Some(Language::Swe),
+        b"tah" => Some(Language::Tah),
+        b"tam" => Some(Language::Tam),
+        b"tat" => Some(Language::Tat),
+        b"tel" => Some(Language::Tel),
+        b"tgk" => Some(Language::Tgk),
+        b"tgl" => Some(Language::Tgl),
+        b"tha" => Some(Language::Tha),
+        b"tir" => Some(Language::Tir),
+        b"ton" => Some(Language::Ton),
+        b"tsn" => Some(Language::Tsn),
+        b"tso" => Some(Language::Tso),
+        b"tuk" => Some(Language::Tuk),
+        b"tur" => Some(Language::Tur),
+        b"twi" => Some(Language::Twi),
+        b"uig" => Some(Language::Uig),
+        b"ukr" => Some(Language::Ukr),
+        b"und" => Some(Language::Und),
+        b"urd" => Some(Language::Urd),
+        b"uzb" => Some(Language::Uzb),
+        b"ven" => Some(Language::Ven),
+        b"vie" => Some(Language::Vie),
+        b"vol" => Some(Language::Vol),
+        b"wln" => Some(Language::Wln),
+        b"wol" => Some(Language::Wol),
+        b"xho" => Some(Language::Xho),
+        b"yid" => Some(Language::Yid),
+        b"yor" => Some(Language::Yor),
+        b"zha" => Some(Language::Zha),
+        b"zho" => Some(Language::Zho),
+        b"zul" => Some(Language::Zul),
+        b"zxx" => Some(Language::Zxx),
+        _ => None,
+    }
+}
+pub(crate) const fn iso_639_3_to_2b(code: &[u8; 3]) -> Option<&'static str> {
+    match code {
+        b"bod" => Some("tib"),
+        b"ces" => Some("cze"),
+        b"cym" => Some("wel"),
+        b"deu" => Some("ger"),
+        b"ell" => Some("gre"),
+        b"eus" => Some("baq"),
+        b"fas" => Some("per"),
+        b"fra" => Some("fre"),
+        b"hye" => Some("arm"),
+        b"isl" => Some("ice"),
+        b"kat" => Some("geo"),
+        b"mkd" => Some("mac"),
+        b"mri" => Some("mao"),
+        b"msa" => Some("may"),
+        b"mya" => Some("bur"),
+        b"nld" => Some("dut"),
+        b"ron" => Some("rum"),
+        b"slk" => Some("slo"),
+        b"sqi" => Some("alb"),
+        b"zho" => Some("chi"),
+        _ => None,
+    }
+}
+pub(crate) const fn language_from_639_2b(code: &[u8; 3]) -> Option<Language> {
+    match code {
+        b"tib" => Some(Language::Bod),
+        b"cze" => Some(Language::Ces),
+        b"wel" => Some(Language::Cym),
+        b"ger" => Some(Language::Deu),
+        b"gre" => Some(Language::Ell),
+        b"baq" => Some(Language::Eus),
+        b"per" => Some(Language::Fas),
+        b"fre" => Some(Language::Fra),
+        b"arm" => Some(Language::Hye),
+        b"ice" => Some(Language::Isl),
+        b"geo" => Some(Language::Kat),
+        b"mac" => Some(Language::Mkd),
+        b"mao" => Some(Language::Mri),
+        b"may" => Some(Language::Msa),
+        b"bur" => Some(Language::Mya),
+        b"dut" => Some(Language::Nld),
+        b"rum" => Some(Language::Ron),
+        b"slo" => Some(Language::Slk),
+        b"alb" => Some(Language::Sqi),
+        b"chi" => Some(Language::Zho),
+        _ => None,
+    }
+}
+pub(crate) const fn scope(lang: Language) -> Scope {
+    match lang {
+        Language::Aka => Scope::Macrolanguage,
+        Language::Ara => Scope::Macrolanguage,
+        Language::Aym => Scope::Macrolanguage,
+        Language::Aze => Scope::Macrolanguage,
+        Language::Cre => Scope::Macrolanguage,
+        Language::Est => Scope::Macrolanguage,
+        Language::Fas => Scope::Macrolanguage,
+        Language::Ful => Scope::Macrolanguage,
+        Language::Grn => Scope::Macrolanguage,
+        Language::Hbs => Scope::Macrolanguage,
+        Language::Iku => Scope::Macrolanguage,
+        Language::Ipk => Scope::Macrolanguage,
+        Language::Kau => Scope::Macrolanguage,
+        Language::Kom => Scope::Macrolanguage,
+        Language::Kon => Scope::Macrolanguage,
+        Language::Kur => Scope::Macrolanguage,
+        Language::Lav => Scope::Macrolanguage,
+        Language::Mis => Scope::Special,
+        Language::Mlg => Scope::Macrolanguage,
+        Language::Mon => Scope::Macrolanguage,
+        Language::Msa => Scope::Macrolanguage,
+        Language::Mul => Scope::Special,
+        Language::Nep => Scope::Macrolanguage,
+        Language::Nor => Scope::Macrolanguage,
+        Language::Oji => Scope::Macrolanguage,
+        Language::Ori => Scope::Macrolanguage,
+        Language::Orm => Scope::Macrolanguage,
+        Language::Pus => Scope::Macrolanguage,
+        Language::Que => Scope::Macrolanguage,
+        Language::San => Scope::Macrolanguage,
+        Language::Sqi => Scope::Macrolanguage,
+        Language::Srd => Scope::Macrolanguage,
+        Language::Swa => Scope::Macrolanguage,
+        Language::Und => Scope::Special,
+        Language::Uzb => Scope::Macrolanguage,
+        Language::Yid => Scope::Macrolanguage,
+        Language::Zha => Scope::Macrolanguage,
+        Language::Zho => Scope::Macrolanguage,
+        Language::Zxx => Scope::Special,
+        _ => Scope::Individual,
+    }
+}
+
+#[cfg(all(feature = "english_names", feature = "serde"))]
+pub(crate) const fn has_shared_name(lang: Language) -> bool {
+    let _ = lang;
+    false
+}
+
+pub(crate) fn macrolanguage(lang: Language) -> Option<Language> {
+    match lang {
+        Language::Twi => Some(Language::Aka),
+        _ => None,
+    }
+}
+
+pub(crate) const PARENT_LOCALES: phf::Map<&str, &str> = ::phf::Map {
+    key: 15995050791870030928,
+    disps: &[
+        (0, 19),
+        (0, 126),
+        (14, 78),
+        (1, 93),
+        (0, 9),
+        (0, 57),
+        (0, 7),
+        (5, 55),
+        (1, 17),
+        (0, 50),
+        (0, 140),
+        (1, 36),
+        (1, 79),
+        (0, 0),
+        (0, 132),
+        (0, 0),
+        (0, 51),
+        (0, 3),
+        (0, 5),
+        (0, 0),
+        (6, 119),
+        (0, 41),
+        (2, 138),
+        (27, 150),
+        (14, 81),
+        (0, 19),
+        (0, 13),
+        (1, 149),
+        (72, 45),
+        (4, 11),
+        (0, 93),
+    ],
+    entries: &[
+        ("no-NO", "no"),
+        ("pt-TL", "pt-PT"),
+        ("en-NG", "en-001"),
+        ("en-SC", "en-001"),
+        ("en-GS", "en-001"),
+        ("pt-CH", "pt-PT"),
+        ("en-AG", "en-001"),
+        ("es-CL", "es-419"),
+        ("en-ZW", "en-001"),
+        ("pt-CV", "pt-PT"),
+        ("es-VE", "es-419"),
+        ("en-ES", "en-150"),
+        ("en-TK", "en-001"),
+        ("en-LT", "en-150"),
+        ("pt-AO", "pt-PT"),
+        ("en-SL", "en-001"),
+        ("en-AI", "en-001"),
+        ("pt-LU", "pt-PT"),
+        ("en-LR", "en-001"),
+        ("en-DE", "en-150"),
+        ("en-SG", "en-001"),
+        ("en-GH", "en-001"),
+        ("en-MU", "en-001"),
+        ("en-BM", "en-001"),
+        ("hi-Latn", "en-IN"),
+        ("en-VG", "en-001"),
+        ("pt-MO", "pt-PT"),
+        ("en-GM", "en-001"),
+        ("en-GD", "en-001"),
+        ("en-GG", "en-001"),
+        ("en-MY", "en-001"),
+        ("en-IO", "en-001"),
+        ("en-PG", "en-001"),
+        ("es-MX", "es-419"),
+        ("en-MT", "en-001"),
+        ("en-HK", "en-001"),
+        ("und-Hans", "und-Hani"),
+        ("en-NL", "en-150"),
+        ("en-SB", "en-001"),
+        ("en-SH", "en-001"),
+        ("en-UG", "en-001"),
+        ("en-ZA", "en-001"),
+        ("es-CU", "es-419"),
+        ("en-BE", "en-150"),
+        ("en-GI", "en-001"),
+        ("en-CC", "en-001"),
+        ("en-ZM", "en-001"),
+        ("en-KY", "en-001"),
+        ("en-MV", "en-001"),
+        ("en-PT", "en-150"),
+        ("en-NR", "en-001"),
+        ("en-NZ", "en-001"),
+        ("en-BS", "en-001"),
+        ("pt-MZ", "pt-PT"),
+        ("en-CY", "en-001"),
+        ("en-IE", "en-001"),
+        ("es-JP", "es-419"),
+        ("es-CO", "es-419"),
+        ("en-PK", "en-001"),
+        ("en-SK", "en-150"),
+        ("en-SZ", "en-001"),
+        ("es-BR", "es-419"),
+        ("es-NI", "es-419"),
+        ("pt-GW", "pt-PT"),
+        ("en-IM", "en-001"),
+        ("en-IL", "en-001"),
+        ("en-KI", "en-001"),
+        ("en-NA", "en-001"),
+        ("nb", "no"),
+        ("es-PE", "es-419"),
+        ("en-PW", "en-001"),
+        ("en-LS", "en-001"),
+        ("en-ER", "en-001"),
+        ("en-IN", "en-001"),
+        ("en-CZ", "en-150"),
+        ("en-LV", "en-150"),
+        ("en-DK", "en-150"),
+        ("en-EE", "en-150"),
+        ("en-KE", "en-001"),
+        ("en-MO", "en-001"),
+        ("en-PL", "en-150"),
+        ("en-SX", "en-001"),
+        ("en-FR", "en-150"),
+        ("en-FK", "en-001"),
+        ("en-JM", "en-001"),
+        ("en-FI", "en-150"),
+        ("es-GT", "es-419"),
+        ("en-150", "en-001"),
+        ("ht", "fr-HT"),
+        ("en-CH", "en-150"),
+        ("es-BZ", "es-419"),
+        ("und-Hant", "und-Hani"),
+        ("en-HU", "en-150"),
+        ("en-CX", "en-001"),
+        ("es-US", "es-419"),
+        ("en-AU", "en-001"),
+        ("en-MS", "en-001"),
+        ("es-PR", "es-419"),
+        ("en-JE", "en-001"),
+        ("en-NO", "en-150"),
+        ("en-BW", "en-001"),
+        ("en-SE", "en-150"),
+        ("en-TC", "en-001"),
+        ("en-GB", "en-001"),
+        ("en-BZ", "en-001"),
+        ("en-DM", "en-001"),
+        ("en-TT", "en-001"),
+        ("en-WS", "en-001"),
+        ("es-DO", "es-419"),
+        ("en-NU", "en-001"),
+        ("en-SD", "en-001"),
+        ("es-PY", "es-419"),
+        ("es-CR", "es-419"),
+        ("en-UA", "en-150"),
+        ("en-RW", "en-001"),
+        ("es-AR", "es-419"),
+        ("en-MG", "en-001"),
+        ("en-GE", "en-150"),
+        ("en-TO", "en-001"),
+        ("en-KN", "en-001"),
+        ("en-ID", "en-001"),
+        ("en-TV", "en-001"),
+        ("pt-GQ", "pt-PT"),
+        ("en-VU", "en-001"),
+        ("en-MW", "en-001"),
+        ("en-SS", "en-001"),
+        ("en-VC", "en-001"),
+        ("en-IT", "en-150"),
+        ("es-PA", "es-419"),
+        ("pt-ST", "pt-PT"),
+        ("en-NF", "en-001"),
+        ("en-DG", "en-001"),
+        ("en-CK", "en-001"),
+        ("en-AT", "en-150"),
+        ("en-GY", "en-001"),
+        ("en-LC", "en-001"),
+        ("en-TZ", "en-001"),
+        ("en-FJ", "en-001"),
+        ("pt-FR", "pt-PT"),
+        ("nn", "no"),
+        ("en-CM", "en-001"),
+        ("es-UY", "es-419"),
+        ("es-BO", "es-419"),
+        ("en-BB", "en-001"),
+        ("en-SI", "en-150"),
+        ("es-SV", "es-419"),
+        ("en-FM", "en-001"),
+        ("es-HN", "es-419"),
+        ("es-EC", "es-419"),
+        ("en-RO", "en-150"),
+        ("en-PN", "en-001"),
+        ("zh-Hant-MO", "zh-Hant-HK"),
+    ],
+};
+
+pub(crate) static LANGUAGE_MATCHES: &[(Language, Language, u16, bool)] = &[
+    (Language::Nob, Language::Nor, 1, false),
+    (Language::Hrv, Language::Bos, 4, false),
+    (Language::Hbs, Language::Bos, 4, false),
+    (Language::Srp, Language::Bos, 4, false),
+    (Language::Hbs, Language::Hrv, 4, false),
+    (Language::Srp, Language::Hrv, 4, false),
+    (Language::Hbs, Language::Srp, 4, false),
+    (Language::Ltz, Language::Deu, 4, true),
+    (Language::Dan, Language::Nor, 8, false),
+    (Language::Dan, Language::Nob, 8, false),
+    (Language::Nno, Language::Nob, 10, false),
+    (Language::Nno, Language::Nor, 10, false),
+    (Language::Msa, Language::Ind, 15, true),
+    (Language::Abk, Language::Rus, 30, true),
+    (Language::Afr, Language::Nld, 20, true),
+    (Language::Aka, Language::Eng, 30, true),
+    (Language::Amh, Language::Eng, 30, true),
+    (Language::Aym, Language::Spa, 20, true),
+    (Language::Aze, Language::Rus, 30, true),
+    (Language::Bel, Language::Rus, 20, true),
+    (Language::Ben, Language::Eng, 30, true),
+    (Language::Bod, Language::Zho, 20, true),
+    (Language::Bre, Language::Fra, 20, true),
+    (Language::Cat, Language::Spa, 20, true),
+    (Language::Cos, Language::Fra, 20, true),
+    (Language::Cym, Language::Eng, 20, true),
+    (Language::Ewe, Language::Eng, 30, true),
+    (Language::Epo, Language::Eng, 30, true),
+    (Language::Est, Language::Fin, 30, true),
+    (Language::Eus, Language::Spa, 20, true),
+    (Language::Fao, Language::Dan, 20, true),
+    (Language::Fry, Language::Nld, 20, true),
+    (Language::Gle, Language::Eng, 20, true),
+    (Language::Gla, Language::Eng, 20, true),
+    (Language::Glg, Language::Spa, 20, true),
+    (Language::Grn, Language::Spa, 20, true),
+    (Language::Guj, Language::Hin, 30, true),
+    (Language::Hau, Language::Eng, 30, true),
+    (Language::Hat, Language::Fra, 25, true),
+    (Language::Hye, Language::Rus, 30, true),
+    (Language::Ina, Language::Eng, 30, true),
+    (Language::Ibo, Language::Eng, 30, true),
+    (Language::Isl, Language::Eng, 20, true),
+    (Language::Jav, Language::Ind, 20, true),
+    (Language::Kat, Language::Eng, 30, true),
+    (Language::Kon, Language::Fra, 30, true),
+    (Language::Kaz, Language::Rus, 30, true),
+    (Language::Khm, Language::Eng, 30, true),
+    (Language::Kan, Language::Eng, 30, true),
+    (Language::Kur, Language::Tur, 30, true),
+    (Language::Kir, Language::Rus, 30, true),
+    (Language::Lat, Language::Ita, 20, true),
+    (Language::Lug, Language::Eng, 30, true),
+    (Language::Lin, Language::Fra, 30, true),
+    (Language::Lao, Language::Eng, 30, true),
+    (Language::Mlg, Language::Fra, 30, true),
+    (Language::Mri, Language::Eng, 20, true),
+    (Language::Mkd, Language::Bul, 30, true),
+    (Language::Mal, Language::Eng, 30, true),
+    (Language::Mon, Language::Rus, 30, true),
+    (Language::Mar, Language::Hin, 30, true),
+    (Language::Mlt, Language::Eng, 30, true),
+    (Language::Mya, Language::Eng, 30, true),
+    (Language::Nep, Language::Eng, 30, true),
+    (Language::Nya, Language::Eng, 30, true),
+    (Language::Oci, Language::Fra, 20, true),
+    (Language::Orm, Language::Eng, 30, true),
+    (Language::Ori, Language::Eng, 30, true),
+    (Language::Pan, Language::Eng, 30, true),
+    (Language::Pus, Language::Eng, 30, true),
+    (Language::Que, Language::Spa, 30, true),
+    (Language::Roh, Language::Deu, 20, true),
+    (Language::Run, Language::Eng, 30, true),
+    (Language::Kin, Language::Fra, 30, true),
+    (Language::San, Language::Hin, 30, true),
+    (Language::Snd, Language::Eng, 30, true),
+    (Language::Sin, Language::Eng, 30, true),
+    (Language::Sna, Language::Eng, 30, true),
+    (Language::Som, Language::Eng, 30, true),
+    (Language::Sqi, Language::Eng, 30, true),
+    (Language::Sot, Language::Eng, 30, true),
+    (Language::Sun, Language::Ind, 20, true),
+    (Language::Swa, Language::Eng, 30, true),
+    (Language::Tam, Language::Eng, 30, true),
+    (Language::Tel, Language::Eng, 30, true),
+    (Language::Tgk, Language::Rus, 30, true),
+    (Language::Tir, Language::Eng, 30, true),
+    (Language::Tuk, Language::Rus, 30, true),
+    (Language::Tsn, Language::Eng, 30, true),
+    (Language::Ton, Language::Eng, 30, true),
+    (Language::Tat, Language::Rus, 30, true),
+    (Language::Uig, Language::Zho, 20, true),
+    (Language::Urd, Language::Eng, 30, true),
+    (Language::Uzb, Language::Rus, 30, true),
+    (Language::Wol, Language::Fra, 30, true),
+    (Language::Xho, Language::Eng, 30, true),
+    (Language::Yid, Language::Eng, 30, true),
+    (Language::Yor, Language::Eng, 30, true),
+    (Language::Zha, Language::Zho, 20, true),
+    (Language::Zul, Language::Eng, 30, true),
+];
+
+#[cfg(feature = "display_names_de")]
+static DISPLAY_NAMES_DEU_LANGUAGES: [u16; 187] = [
+    Language::Aar as u16,
+    Language::Abk as u16,
+    Language::Afr as u16,
+    Language::Aka as u16,
+    Language::Amh as u16,
+    Language::Ara as u16,
+    Language::Arg as u16,
+    Language::Asm as u16,
+    Language::Ava as u16,
+    Language::Ave as u16,
+    Language::Aym as u16,
+    Language::Aze as u16,
+    Language::Bak as u16,
+    Language::Bam as u16,
+    Language::Bel as u16,
+    Language::Ben as u16,
+    Language::Bis as u16,
+    Language::Bod as u16,
+    Language::Bos as u16,
+    Language::Bre as u16,
+    Language::Bul as u16,
+    Language::Cat as u16,
+    Language::Ces as u16,
+    Language::Cha as u16,
+    Language::Che as u16,
+    Language::Chu as u16,
+    Language::Chv as u16,
+    Language::Cor as u16,
+    Language::Cos as u16,
+    Language::Cre as u16,
+    Language::Cym as u16,
+    Language::Dan as u16,
+    Language::Deu as u16,
+    Language::Div as u16,
+    Language::Dzo as u16,
+    Language::Ell as u16,
+    Language::Eng as u16,
+    Language::Epo as u16,
+    Language::Est as u16,
+    Language::Eus as u16,
+    Language::Ewe as u16,
+    Language::Fao as u16,
+    Language::Fas as u16,
+    Language::Fij as u16,
+    Language::Fin as u16,
+    Language::Fra as u16,
+    Language::Fry as u16,
+    Language::Ful as u16,
+    Language::Gla as u16,
+    Language::Gle as u16,
+    Language::Glg as u16,
+    Language::Glv as u16,
+    Language::Grn as u16,
+    Language::Guj as u16,
+    Language::Hat as u16,
+    Language::Hau as u16,
+    Language::Hbs as u16,
+    Language::Heb as u16,
+    Language::Her as u16,
+    Language::Hin as u16,
+    Language::Hmo as u16,
+    Language::Hrv as u16,
+    Language::Hun as u16,
+    Language::Hye as u16,
+    Language::Ibo as u16,
+    Language::Ido as u16,
+    Language::Iii as u16,
+    Language::Iku as u16,
+    Language::Ile as u16,
+    Language::Ina as u16,
+    Language::Ind as u16,
+    Language::Ipk as u16,
+    Language::Isl as u16,
+    Language::Ita as u16,
+    Language::Jav as u16,
+    Language::Jpn as u16,
+    Language::Kal as u16,
+    Language::Kan as u16,
+    Language::Kas as u16,
+    Language::Kat as u16,
+    Language::Kau as u16,
+    Language::Kaz as u16,
+    Language::Khm as u16,
+    Language::Kik as u16,
+    Language::Kin as u16,
+    Language::Kir as u16,
+    Language::Kom as u16,
+    Language::Kon as u16,
+    Language::Kor as u16,
+    Language::Kua as u16,
+    Language::Kur as u16,
+    Language::Lao as u16,
+    Language::Lat as u16,
+    Language::Lav as u16,
+    Language::Lim as u16,
+    Language::Lin as u16,
+    Language::Lit as u16,
+    Language::Ltz as u16,
+    Language::Lub as u16,
+    Language::Lug as u16,
+    Language::Mah as u16,
+    Language::Mal as u16,
+    Language::Mar as u16,
+    Language::Mkd as u16,
+    Language::Mlg as u16,
+    Language::Mlt as u16,
+    Language::Mon as u16,
+    Language::Mri as u16,
+    Language::Msa as u16,
+    Language::Mul as u16,
+    Language::Mya as u16,
+    Language::Nau as u16,
+    Language::Nav as u16,
+    Language::Nbl as u16,
+    Language::Nde as u16,
+    Language::Ndo as u16,
+    Language::Nep as u16,
+    Language::Nld as u16,
+    Language::Nno as u16,
+    Language::Nob as u16,
+    Language::Nor as u16,
+    Language::Nya as u16,
+    Language::Oci as u16,
+    Language::Oji as u16,
+    Language::Ori as u16,
+    Language::Orm as u16,
+    Language::Oss as u16,
+    Language::Pan as u16,
+    Language::Pli as u16,
+    Language::Pol as u16,
+    Language::Por as u16,
+    Language::Pus as u16,
+    Language::Que as u16,
+    Language::Roh as u16,
+    Language::Ron as u16,
+    Language::Run as u16,
+    Language::Rus as u16,
+    Language::Sag as u16,
+    Language::San as u16,
+    Language::Sin as u16,
+    Language::Slk as u16,
+    Language::Slv as u16,
+    Language::Sme as u16,
+    Language::Smo as u16,
+    Language::Sna as u16,
+    Language::Snd as u16,
+    Language::Som as u16,
+    Language::Sot as u16,
+    Language::Spa as u16,
+    Language::Sqi as u16,
+    Language::Srd as u16,
+    Language::Srp as u16,
+    Language::Ssw as u16,
+    Language::Sun as u16,
+    Language::Swa as u16,
+    Language::Swe as u16,
+    Language::Tah as u16,
+    Language::Tam as u16,
+    Language::Tat as u16,
+    Language::Tel as u16,
+    Language::Tgk as u16,
+    Language::Tgl as u16,
+    Language::Tha as u16,
+    Language::Tir as u16,
+    Language::Ton as u16,
+    Language::Tsn as u16,
+    Language::Tso as u16,
+    Language::Tuk as u16,
+    Language::Tur as u16,
+    Language::Twi as u16,
+    Language::Uig as u16,
+    Language::Ukr as u16,
+    Language::Und as u16,
+    Language::Urd as u16,
+    Language::Uzb as u16,
+    Language::Ven as u16,
+    Language::Vie as u16,
+    Language::Vol as u16,
+    Language::Wln as u16,
+    Language::Wol as u16,
+    Language::Xho as u16,
+    Language::Yid as u16,
+    Language::Yor as u16,
+    Language::Zha as u16,
+    Language::Zho as u16,
+    Language::Zul as u16,
+    Language::Zxx as u16,
+];
+
+#[cfg(feature = "display_names_de")]
+pub(crate) static DISPLAY_NAMES_DEU: &str = concat!(
+    "Afar",
+    "Abchasisch",
+    "Afrikaans",
+    "Akan",
+    "Amharisch",
+    "Arabisch",
+    "Aragonesisch",
+    "Assamesisch",
+    "Awarisch",
+    "Avestisch",
+    "Aymara",
+    "Aserbaidschanisch",
+    "Baschkirisch",
+    "Bambara",
+    "Belarussisch",
+    "Bengalisch",
+    "Bislama",
+    "Tibetisch",
+    "Bosnisch",
+    "Bretonisch",
+    "Bulgarisch",
+    "Katalanisch",
+    "Tschechisch",
+    "Chamorro",
+    "Tschetschenisch",
+    "Kirchenslawisch",
+    "Tschuwaschisch",
+    "Kornisch",
+    "Korsisch",
+    "Cree",
+    "Walisisch",
+    "Dänisch",
+    "Deutsch",
+    "Dhivehi",
+    "Dzongkha",
+    "Griechisch",
+    "Englisch",
+    "Esperanto",
+    "Estnisch",
+    "Baskisch",
+    "Ewe",
+    "Färöisch",
+    "Persisch",
+    "Fidschi",
+    "Finnisch",
+    "Französisch",
+    "Westfriesisch",
+    "Ful",
+    "Gälisch (Schottland)",
+    "Irisch",
+    "Galicisch",
+    "Manx",
+    "Guaraní",
+    "Gujarati",
+    "Haiti-Kreolisch",
+    "Haussa",
+    "Serbo-Kroatisch",
+    "Hebräisch",
+    "Herero",
+    "Hindi",
+    "Hiri-Motu",
+    "Kroatisch",
+    "Ungarisch",
+    "Armenisch",
+    "Igbo",
+    "Ido",
+    "Yi",
+    "Inuktitut",
+    "Interlingue",
+    "Interlingua",
+    "Indonesisch",
+    "Inupiak",
+    "Isländisch",
+    "Italienisch",
+    "Javanisch",
+    "Japanisch",
+    "Grönländisch",
+    "Kannada",
+    "Kaschmiri",
+    "Georgisch",
+    "Kanuri",
+    "Kasachisch",
+    "Khmer",
+    "Kikuyu",
+    "Kinyarwanda",
+    "Kirgisisch",
+    "Komi",
+    "Kongolesisch",
+    "Koreanisch",
+    "Kwanyama",
+    "Kurdisch",
+    "Laotisch",
+    "Latein",
+    "Lettisch",
+    "Limburgisch",
+    "Lingala",
+    "Litauisch",
+    "Luxemburgisch",
+    "Luba-Katanga",
+    "Ganda",
+    "Marschallesisch",
+    "Malayalam",
+    "Marathi",
+    "Mazedonisch",
+    "Malagasy",
+    "Maltesisch",
+    "Mongolisch",
+    "Māori",
+    "Malaiisch",
+    "Mehrsprachig",
+    "Birmanisch",
+    "Nauruisch",
+    "Navajo",
+    "Süd-Ndebele",
+    "Nord-Ndebele",
+    "Ndonga",
+    "Nepalesisch",
+    "Niederländisch",
+    "Norwegisch (Nynorsk)",
+    "Norwegisch (Bokmål)",
+    "Norwegisch",
+    "Nyanja",
+    "Okzitanisch",
+    "Ojibwa",
+    "Oriya",
+    "Oromo",
+    "Ossetisch",
+    "Punjabi",
+    "Pali",
+    "Polnisch",
+    "Portugiesisch",
+    "Paschtu",
+    "Quechua",
+    "Rätoromanisch",
+    "Rumänisch",
+    "Rundi",
+    "Russisch",
+    "Sango",
+    "Sanskrit",
+    "Singhalesisch",
+    "Slowakisch",
+    "Slowenisch",
+    "Nordsamisch",
+    "Samoanisch",
+    "Shona",
+    "Sindhi",
+    "Somali",
+    "Süd-Sotho",
+    "Spanisch",
+    "Albanisch",
+    "Sardisch",
+    "Serbisch",
+    "Swazi",
+    "Sundanesisch",
+    "Suaheli",
+    "Schwedisch",
+    "Tahitisch",
+    "Tamil",
+    "Tatarisch",
+    "Telugu",
+    "Tadschikisch",
+    "Tagalog",
+    "Thailändisch",
+    "Tigrinya",
+    "Tongaisch",
+    "Tswana",
+    "Tsonga",
+    "Turkmenisch",
+    "Türkisch",
+    "Twi",
+    "Uigurisch",
+    "Ukrainisch",
+    "Unbekannte Sprache",
+    "Urdu",
+    "Usbekisch",
+    "Venda",
+    "Vietnamesisch",
+    "Volapük",
+    "Wallonisch",
+    "Wolof",
+    "Xhosa",
+    "Jiddisch",
+    "Yoruba",
+    "Zhuang",
+    "Chinesisch",
+    "Zulu",
+    "Keine Sprachinhalte",
+);
+
+#[cfg(feature = "display_names_de")]
+pub(crate) static DISPLAY_NAMES_DEU_OFFSETS: [u16; 188] = [
+    0, 4, 14, 23, 27, 36, 44, 56, 67, 75, 84, 90, 107, 119, 126, 138, 148, 155,
+    164, 172, 182, 192, 203, 214, 222, 237, 252, 266, 274, 282, 286, 295, 303,
+    310, 317, 325, 335, 343, 352, 360, 368, 371, 381, 389, 396, 404, 416, 429,
+    432, 453, 459, 468, 472, 480, 488, 503, 509, 524, 534, 540, 545, 554, 563,
+    572, 581, 585, 588, 590, 599, 610, 621, 632, 639, 650, 661, 670, 679, 693,
+    700, 709, 718, 724, 734, 739, 745, 756, 766, 770, 782, 792, 800, 808, 816,
+    822, 830, 841, 848, 857, 870, 882, 887, 902, 911, 918, 929, 937, 947, 957,
+    963, 972, 984, 994, 1003, 1009, 1021, 1033, 1039, 1050, 1065, 1085, 1105,
+    1115, 1121, 1132, 1138, 1143, 1148, 1157, 1164, 1168, 1176, 1189, 1196,
+    1203, 1217, 1227, 1232, 1240, 1245, 1253, 1266, 1276, 1286, 1297, 1307,
+    1312, 1318, 1324, 1334, 1342, 1351, 1359, 1367, 1372, 1384, 1391, 1401,
+    1410, 1415, 1424, 1430, 1442, 1449, 1462, 1470, 1479, 1485, 1491, 1502,
+    1511, 1514, 1523, 1533, 1551, 1555, 1564, 1569, 1582, 1590, 1600, 1605,
+    1610, 1618, 1624, 1630, 1640, 1644, 1663,
+];
+
+#[cfg(feature = "display_names_en")]
+static DISPLAY_NAMES_ENG_LANGUAGES: [u16; 187] = [
+    Language::Aar as u16,
+    Language::Abk as u16,
+    Language::Afr as u16,
+    Language::Aka as u16,
+    Language::Amh as u16,
+    Language::Ara as u16,
+    Language::Arg as u16,
+    Language::Asm as u16,
+    Language::Ava as u16,
+    Language::Ave as u16,
+    Language::Aym as u16,
+    Language::Aze as u16,
+    Language::Bak as u16,
+    Language::Bam as u16,
+    Language::Bel as u16,
+    Language::Ben as u16,
+    Language::Bis as u16,
+    Language::Bod as u16,
+    Language::Bos as u16,
+    Language::Bre as u16,
+    Language::Bul as u16,
+    Language::Cat as u16,
+    Language::Ces as u16,
+    Language::Cha as u16,
+    Language::Che as u16,
+    Language::Chu as u16,
+    Language::Chv as u16,
+    Language::Cor as u16,
+    Language::Cos as u16,
+    Language::Cre as u16,
+    Language::Cym as u16,
+    Language::Dan as u16,
+    Language::Deu as u16,
+    Language::Div as u16,
+    Language::Dzo as u16,
+    Language::Ell as u16,
+    Language::Eng as u16,
+    Language::Epo as u16,
+    Language::Est as u16,
+    Language::Eus as u16,
+    Language::Ewe as u16,
+    Language::Fao as u16,
+    Language::Fas as u16,
+    Language::Fij as u16,
+    Language::Fin as u16,
+    Language::Fra as u16,
+    Language::Fry as u16,
+    Language::Ful as u16,
+    Language::Gla as u16,
+    Language::Gle as u16,
+    Language::Glg as u16,
+    Language::Glv as u16,
+    Language::Grn as u16,
+    Language::Guj as u16,
+    Language::Hat as u16,
+    Language::Hau as u16,
+    Language::Hbs as u16,
+    Language::Heb as u16,
+    Language::Her as u16,
+    Language::Hin as u16,
+    Language::Hmo as u16,
+    Language::Hrv as u16,
+    Language::Hun as u16,
+    Language::Hye as u16,
+    Language::Ibo as u16,
+    Language::Ido as u16,
+    Language::Iii as u16,
+    Language::Iku as u16,
+    Language::Ile as u16,
+    Language::Ina as u16,
+    Language::Ind as u16,
+    Language::Ipk as u16,
+    Language::Isl as u16,
+    Language::Ita as u16,
+    Language::Jav as u16,
+    Language::Jpn as u16,
+    Language::Kal as u16,
+    Language::Kan as u16,
+    Language::Kas as u16,
+    Language::Kat as u16,
+    Language::Kau as u16,
+    Language::Kaz as u16,
+    Language::Khm as u16,
+    Language::Kik as u16,
+    Language::Kin as u16,
+    Language::Kir as u16,
+    Language::Kom as u16,
+    Language::Kon as u16,
+    Language::Kor as u16,
+    Language::Kua as u16,
+    Language::Kur as u16,
+    Language::Lao as u16,
+    Language::Lat as u16,
+    Language::Lav as u16,
+    Language::Lim as u16,
+    Language::Lin as u16,
+    Language::Lit as u16,
+    Language::Ltz as u16,
+    Language::Lub as u16,
+    Language::Lug as u16,
+    Language::Mah as u16,
+    Language::Mal as u16,
+    Language::Mar as u16,
+    Language::Mkd as u16,
+    Language::Mlg as u16,
+    Language::Mlt as u16,
+    Language::Mon as u16,
+    Language::Mri as u16,
+    Language::Msa as u16,
+    Language::Mul as u16,
+    Language::Mya as u16,
+    Language::Nau as u16,
+    Language::Nav as u16,
+    Language::Nbl as u16,
+    Language::Nde as u16,
+    Language::Ndo as u16,
+    Language::Nep as u16,
+    Language::Nld as u16,
+    Language::Nno as u16,
+    Language::Nob as u16,
+    Language::Nor as u16,
+    Language::Nya as u16,
+    Language::Oci as u16,
+    Language::Oji as u16,
+    Language::Ori as u16,
+    Language::Orm as u16,
+    Language::Oss as u16,
+    Language::Pan as u16,
+    Language::Pli as u16,
+    Language::Pol as u16,
+    Language::Por as u16,
+    Language::Pus as u16,
+    Language::Que as u16,
+    Language::Roh as u16,
+    Language::Ron as u16,
+    Language::Run as u16,
+    Language::Rus as u16,
+    Language::Sag as u16,
+    Language::San as u16,
+    Language::Sin as u16,
+    Language::Slk as u16,
+    Language::Slv as u16,
+    Language::Sme as u16,
+    Language::Smo as u16,
+    Language::Sna as u16,
+    Language::Snd as u16,
+    Language::Som as u16,
+    Language::Sot as u16,
+    Language::Spa as u16,
+    Language::Sqi as u16,
+    Language::Srd as u16,
+    Language::Srp as u16,
+    Language::Ssw as u16,
+    Language::Sun as u16,
+    Language::Swa as u16,
+    Language::Swe as u16,
+    Language::Tah as u16,
+    Language::Tam as u16,
+    Language::Tat as u16,
+    Language::Tel as u16,
+    Language::Tgk as u16,
+    Language::Tgl as u16,
+    Language::Tha as u16,
+    Language::Tir as u16,
+    Language::Ton as u16,
+    Language::Tsn as u16,
+    Language::Tso as u16,
+    Language::Tuk as u16,
+    Language::Tur as u16,
+    Language::Twi as u16,
+    Language::Uig as u16,
+    Language::Ukr as u16,
+    Language::Und as u16,
+    Language::Urd as u16,
+    Language::Uzb as u16,
+    Language::Ven as u16,
+    Language::Vie as u16,
+    Language::Vol as u16,
+    Language::Wln as u16,
+    Language::Wol as u16,
+    Language::Xho as u16,
+    Language::Yid as u16,
+    Language::Yor as u16,
+    Language::Zha as u16,
+    Language::Zho as u16,
+    Language::Zul as u16,
+    Language::Zxx as u16,
+];
+
+#[cfg(feature = "display_names_en")]
+pub(crate) static DISPLAY_NAMES_ENG: &str = concat!(
+    "Afar",
+    "Abkhazian",
+    "Afrikaans",
+    "Akan",
+    "Amharic",
+    "Arabic",
+    "Aragonese",
+    "Assamese",
+    "Avaric",
+    "Avestan",
+    "Aymara",
+    "Azerbaijani",
+    "Bashkir",
+    "Bambara",
+    "Belarusian",
+    "Bangla",
+    "Bislama",
+    "Tibetan",
+    "Bosnian",
+    "Breton",
+    "Bulgarian",
+    "Catalan",
+    "Czech",
+    "Chamorro",
+    "Chechen",
+    "Church Slavic",
+    "Chuvash",
+    "Cornish",
+    "Corsican",
+    "Cree",
+    "Welsh",
+    "Danish",
+    "German",
+    "Divehi",
+    "Dzongkha",
+    "Greek",
+    "English",
+    "Esperanto",
+    "Estonian",
+    "Basque",
+    "Ewe",
+    "Faroese",
+    "Persian",
+    "Fijian",
+    "Finnish",
+    "French",
+    "Western Frisian",
+    "Fula",
+    "Scottish Gaelic",
+    "Irish",
+    "Galician",
+    "Manx",
+    "Guarani",
+    "Gujarati",
+    "Haitian Creole",
+    "Hausa",
+    "Serbo-Croatian",
+    "Hebrew",
+    "Herero",
+    "Hindi",
+    "Hiri Motu",
+    "Croatian",
+    "Hungarian",
+    "Armenian",
+    "Igbo",
+    "Ido",
+    "Sichuan Yi",
+    "Inuktitut",
+    "Interlingue",
+    "Interlingua",
+    "Indonesian",
+    "Inupiaq",
+    "Icelandic",
+    "Italian",
+    "Javanese",
+    "Japanese",
+    "Kalaallisut",
+    "Kannada",
+    "Kashmiri",
+    "Georgian",
+    "Kanuri",
+    "Kazakh",
+    "Khmer",
+    "Kikuyu",
+    "Kinyarwanda",
+    "Kyrgyz",
+    "Komi",
+    "Kongo",
+    "Korean",
+    "Kuanyama",
+    "Kurdish",
+    "Lao",
+    "Latin",
+    "Latvian",
+    "Limburgish",
+    "Lingala",
+    "Lithuanian",
+    "Luxembourgish",
+    "Luba-Katanga",
+    "Ganda",
+    "Marshallese",
+    "Malayalam",
+    "Marathi",
+    "Macedonian",
+    "Malagasy",
+    "Maltese",
+    "Mongolian",
+    "Māori",
+    "Malay",
+    "Multiple languages",
+    "Burmese",
+    "Nauru",
+    "Navajo",
+    "South Ndebele",
+    "North Ndebele",
+    "Ndonga",
+    "Nepali",
+    "Dutch",
+    "Norwegian Nynorsk",
+    "Norwegian Bokmål",
+    "Norwegian",
+    "Nyanja",
+    "Occitan",
+    "Ojibwa",
+    "Odia",
+    "Oromo",
+    "Ossetic",
+    "Punjabi",
+    "Pali",
+    "Polish",
+    "Portuguese",
+    "Pashto",
+    "Quechua",
+    "Romansh",
+    "Romanian",
+    "Rundi",
+    "Russian",
+    "Sango",
+    "Sanskrit",
+    "Sinhala",
+    "Slovak",
+    "Slovenian",
+    "Northern Sami",
+    "Samoan",
+    "Shona",
+    "Sindhi",
+    "Somali",
+    "Southern Sotho",
+    "Spanish",
+    "Albanian",
+    "Sardinian",
+    "Serbian",
+    "Swati",
+    "Sundanese",
+    "Swahili",
+    "Swedish",
+    "Tahitian",
+    "Tamil",
+    "Tatar",
+    "Telugu",
+    "Tajik",
+    "Tagalog",
+    "Thai",
+    "Tigrinya",
+    "Tongan",
+    "Tswana",
+    "Tsonga",
+    "Turkmen",
+    "Turkish",
+    "Twi",
+    "Uyghur",
+    "Ukrainian",
+    "Unknown language",
+    "Urdu",
+    "Uzbek",
+    "Venda",
+    "Vietnamese",
+    "Volapük",
+    "Walloon",
+    "Wolof",
+    "Xhosa",
+    "Yiddish",
+    "Yoruba",
+    "Zhuang",
+    "Chinese",
+    "Zulu",
+    "No linguistic content",
+);
+
+#[cfg(feature = "display_names_en")]
+pub(crate) static DISPLAY_NAMES_ENG_OFFSETS: [u16; 188] = [
+    0, 4, 13, 22, 26, 33, 39, 48, 56, 62, 69, 75, 86, 93, 100, 110, 116, 123,
+    130, 137, 143, 152, 159, 164, 172, 179, 192, 199, 206, 214, 218, 223, 229,
+    235, 241, 249, 254, 261, 270, 278, 284, 287, 294, 301, 307, 314, 320, 335,
+    339, 354, 359, 367, 371, 378, 386, 400, 405, 419, 425, 431, 436, 445, 453,
+    462, 470, 474, 477, 487, 496, 507, 518, 528, 535, 544, 551, 559, 567, 578,
+    585, 593, 601, 607, 613, 618, 624, 635, 641, 645, 650, 656, 664, 671, 674,
+    679, 686, 696, 703, 713, 726, 738, 743, 754, 763, 770, 780, 788, 795, 804,
+    810, 815, 833, 840, 845, 851, 864, 877, 883, 889, 894, 911, 928, 937, 943,
+    950, 956, 960, 965, 972, 979, 983, 989, 999, 1005, 1012, 1019, 1027, 1032,
+    1039, 1044, 1052, 1059, 1065, 1074, 1087, 1093, 1098, 1104, 1110, 1124,
+    1131, 1139, 1148, 1155, 1160, 1169, 1176, 1183, 1191, 1196, 1201, 1207,
+    1212, 1219, 1223, 1231, 1237, 1243, 1249, 1256, 1263, 1266, 1272, 1281,
+    1297, 1301, 1306, 1311, 1321, 1329, 1336, 1341, 1346, 1353, 1359, 1365,
+    1372, 1376, 1397,
+];
+
+#[cfg(feature = "display_names_es")]
+static DISPLAY_NAMES_SPA_LANGUAGES: [u16; 187] = [
+    Language::Aar as u16,
+    Language::Abk as u16,
+    Language::Afr as u16,
+    Language::Aka as u16,
+    Language::Amh as u16,
+    Language::Ara as u16,
+    Language::Arg as u16,
+    Language::Asm as u16,
+    Language::Ava as u16,
+    Language::Ave as u16,
+    Language::Aym as u16,
+    Language::Aze as u16,
+    Language::Bak as u16,
+    Language::Bam as u16,
+    Language::Bel as u16,
+    Language::Ben as u16,
+    Language::Bis as u16,
+    Language::Bod as u16,
+    Language::Bos as u16,
+    Language::Bre as u16,
+    Language::Bul as u16,
+    Language::Cat as u16,
+    Language::Ces as u16,
+    Language::Cha as u16,
+    Language::Che as u16,
+    Language::Chu as u16,
+    Language::Chv as u16,
+    Language::Cor as u16,
+    Language::Cos as u16,
+    Language::Cre as u16,
+    Language::Cym as u16,
+    Language::Dan as u16,
+    Language::Deu as u16,
+    Language::Div as u16,
+    Language::Dzo as u16,
+    Language::Ell as u16,
+    Language::Eng as u16,
+    Language::Epo as u16,
+    Language::Est as u16,
+    Language::Eus as u16,
+    Language::Ewe as u16,
+    Language::Fao as u16,
+    Language::Fas as u16,
+    Language::Fij as u16,
+    Language::Fin as u16,
+    Language::Fra as u16,
+    Language::Fry as u16,
+    Language::Ful as u16,
+    Language::Gla as u16,
+    Language::Gle as u16,
+    Language::Glg as u16,
+    Language::Glv as u16,
+    Language::Grn as u16,
+    Language::Guj as u16,
+    Language::Hat as u16,
+    Language::Hau as u16,
+    Language::Hbs as u16,
+    Language::Heb as u16,
+    Language::Her as u16,
+    Language::Hin as u16,
+    Language::Hmo as u16,
+    Language::Hrv as u16,
+    Language::Hun as u16,
+    Language::Hye as u16,
+    Language::Ibo as u16,
+    Language::Ido as u16,
+    Language::Iii as u16,
+    Language::Iku as u16,
+    Language::Ile as u16,
+    Language::Ina as u16,
+    Language::Ind as u16,
+    Language::Ipk as u16,
+    Language::Isl as u16,
+    Language::Ita as u16,
+    Language::Jav as u16,
+    Language::Jpn as u16,
+    Language::Kal as u16,
+    Language::Kan as u16,
+    Language::Kas as u16,
+    Language::Kat as u16,
+    Language::Kau as u16,
+    Language::Kaz as u16,
+    Language::Khm as u16,
+    Language::Kik as u16,
+    Language::Kin as u16,
+    Language::Kir as u16,
+    Language::Kom as u16,
+    Language::Kon as u16,
+    Language::Kor as u16,
+    Language::Kua as u16,
+    Language::Kur as u16,
+    Language::Lao as u16,
+    Language::Lat as u16,
+    Language::Lav as u16,
+    Language::Lim as u16,
+    Language::Lin as u16,
+    Language::Lit as u16,
+    Language::Ltz as u16,
+    Language::Lub as u16,
+    Language::Lug as u16,
+    Language::Mah as u16,
+    Language::Mal as u16,
+    Language::Mar as u16,
+    Language::Mkd as u16,
+    Language::Mlg as u16,
+    Language::Mlt as u16,
+    Language::Mon as u16,
+    Language::Mri as u16,
+    Language::Msa as u16,
+    Language::Mul as u16,
+    Language::Mya as u16,
+    Language::Nau as u16,
+    Language::Nav as u16,
+    Language::Nbl as u16,
+    Language::Nde as u16,
+    Language::Ndo as u16,
+    Language::Nep as u16,
+    Language::Nld as u16,
+    Language::Nno as u16,
+    Language::Nob as u16,
+    Language::Nor as u16,
+    Language::Nya as u16,
+    Language::Oci as u16,
+    Language::Oji as u16,
+    Language::Ori as u16,
+    Language::Orm as u16,
+    Language::Oss as u16,
+    Language::Pan as u16,
+    Language::Pli as u16,
+    Language::Pol as u16,
+    Language::Por as u16,
+    Language::Pus as u16,
+    Language::Que as u16,
+    Language::Roh as u16,
+    Language::Ron as u16,
+    Language::Run as u16,
+    Language::Rus as u16,
+    Language::Sag as u16,
+    Language::San as u16,
+    Language::Sin as u16,
+    Language::Slk as u16,
+    Language::Slv as u16,
+    Language::Sme as u16,
+    Language::Smo as u16,
+    Language::Sna as u16,
+    Language::Snd as u16,
+    Language::Som as u16,
+    Language::Sot as u16,
+    Language::Spa as u16,
+    Language::Sqi as u16,
+    Language::Srd as u16,
+    Language::Srp as u16,
+    Language::Ssw as u16,
+    Language::Sun as u16,
+    Language::Swa as u16,
+    Language::Swe as u16,
+    Language::Tah as u16,
+    Language::Tam as u16,
+    Language::Tat as u16,
+    Language::Tel as u16,
+    Language::Tgk as u16,
+    Language::Tgl as u16,
+    Language::Tha as u16,
+    Language::Tir as u16,
+    Language::Ton as u16,
+    Language::Tsn as u16,
+    Language::Tso as u16,
+    Language::Tuk as u16,
+    Language::Tur as u16,
+    Language::Twi as u16,
+    Language::Uig as u16,
+    Language::Ukr as u16,
+    Language::Und as u16,
+    Language::Urd as u16,
+    Language::Uzb as u16,
+    Language::Ven as u16,
+    Language::Vie as u16,
+    Language::Vol as u16,
+    Language::Wln as u16,
+    Language::Wol as u16,
+    Language::Xho as u16,
+    Language::Yid as u16,
+    Language::Yor as u16,
+    Language::Zha as u16,
+    Language::Zho as u16,
+    Language::Zul as u16,
+    Language::Zxx as u16,
+];
+
+#[cfg(feature = "display_names_es")]
+pub(crate) static DISPLAY_NAMES_SPA: &str = concat!(
+    "afar",
+    "abjasio",
+    "afrikáans",
+    "akan",
+    "amárico",
+    "árabe",
+    "aragonés",
+    "asamés",
+    "avar",
+    "avéstico",
+    "aimara",
+    "azerbaiyano",
+    "baskir",
+    "bambara",
+    "bielorruso",
+    "bengalí",
+    "bislama",
+    "tibetano",
+    "bosnio",
+    "bretón",
+    "búlgaro",
+    "catalán",
+    "checo",
+    "chamorro",
+    "checheno",
+    "eslavo eclesiástico",
+    "chuvasio",
+    "córnico",
+    "corso",
+    "cree",
+    "galés",
+    "danés",
+    "alemán",
+    "divehi",
+    "dzongkha",
+    "griego",
+    "inglés",
+    "esperanto",
+    "estonio",
+    "euskera",
+    "ewé",
+    "feroés",
+    "persa",
+    "fiyiano",
+    "finés",
+    "francés",
+    "frisón occidental",
+    "fula",
+    "gaélico escocés",
+    "irlandés",
+    "gallego",
+    "manés",
+    "guaraní",
+    "guyaratí",
+    "criollo haitiano",
+    "hausa",
+    "serbocroata",
+    "hebreo",
+    "herero",
+    "hindi",
+    "hiri motu",
+    "croata",
+    "húngaro",
+    "armenio",
+    "igbo",
+    "ido",
+    "yi de Sichuán",
+    "inuktitut",
+    "interlingue",
+    "interlingua",
+    "indonesio",
+    "inupiaq",
+    "islandés",
+    "italiano",
+    "javanés",
+    "japonés",
+    "groenlandés",
+    "canarés",
+    "cachemir",
+    "georgiano",
+    "kanuri",
+    "kazajo",
+    "jemer",
+    "kikuyu",
+    "kinyarwanda",
+    "kirguís",
+    "komi",
+    "kongo",
+    "coreano",
+    "kuanyama",
+    "kurdo",
+    "lao",
+    "latín",
+    "letón",
+    "limburgués",
+    "lingala",
+    "lituano",
+    "luxemburgués",
+    "luba-katanga",
+    "ganda",
+    "marshalés",
+    "malayálam",
+    "maratí",
+    "macedonio",
+    "malgache",
+    "maltés",
+    "mongol",
+    "maorí",
+    "malayo",
+    "varios idiomas",
+    "birmano",
+    "nauruano",
+    "navajo",
+    "ndebele meridional",
+    "ndebele septentrional",
+    "ndonga",
+    "nepalí",
+    "neerlandés",
+    "noruego nynorsk",
+    "noruego bokmal",
+    "noruego",
+    "nyanja",
+    "occitano",
+    "ojibwa",
+    "oriya",
+    "oromo",
+    "osético",
+    "punyabí",
+    "pali",
+    "polaco",
+    "portugués",
+    "pastún",
+    "quechua",
+    "romanche",
+    "rumano",
+    "kirundi",
+    "ruso",
+    "sango",
+    "sánscrito",
+    "cingalés",
+    "eslovaco",
+    "esloveno",
+    "sami septentrional",
+    "samoano",
+    "shona",
+    "sindi",
+    "somalí",
+    "sotho meridional",
+    "español",
+    "albanés",
+    "sardo",
+    "serbio",
+    "suazi",
+    "sundanés",
+    "suajili",
+    "sueco",
+    "tahitiano",
+    "tamil",
+    "tártaro",
+    "telugu",
+    "tayiko",
+    "tagalo",
+    "tailandés",
+    "tigriña",
+    "tongano",
+    "setsuana",
+    "tsonga",
+    "turcomano",
+    "turco",
+    "twi",
+    "uigur",
+    "ucraniano",
+    "lengua desconocida",
+    "urdu",
+    "uzbeko",
+    "venda",
+    "vietnamita",
+    "volapük",
+    "valón",
+    "wólof",
+    "xhosa",
+    "yidis",
+    "yoruba",
+    "zhuang",
+    "chino",
+    "zulú",
+    "sin contenido lingüístico",
+);
+
+#[cfg(feature = "display_names_es")]
+pub(crate) static DISPLAY_NAMES_SPA_OFFSETS: [u16; 188] = [
+    0, 4, 11, 21, 25, 33, 39, 48, 55, 59, 68, 74, 85, 91, 98, 108, 116, 123,
+    131, 137, 144, 152, 160, 165, 173, 181, 201, 209, 217, 222, 226, 232, 238,
+    245, 251, 259, 265, 272, 281, 288, 295, 299, 306, 311, 318, 324, 332, 350,
+    354, 371, 380, 387, 393, 401, 410, 426, 431, 442, 448, 454, 459, 468, 474,
+    482, 489, 493, 496, 510, 519, 530, 541, 550, 557, 566, 574, 582, 590, 602,
+    610, 618, 627, 633, 639, 644, 650, 661, 669, 673, 678, 685, 693, 698, 701,
+    707, 713, 724, 731, 738, 751, 763, 768, 778, 788, 795, 804, 812, 819, 825,
+    831, 837, 851, 858, 866, 872, 890, 911, 917, 924, 935, 950, 964, 971, 977,
+    985, 991, 996, 1001, 1009, 1017, 1021, 1027, 1037, 1044, 1051, 1059, 1065,
+    1072, 1076, 1081, 1091, 1100, 1108, 1116, 1134, 1141, 1146, 1151, 1158,
+    1174, 1182, 1190, 1195, 1201, 1206, 1215, 1222, 1227, 1236, 1241, 1249,
+    1255, 1261, 1267, 1277, 1285, 1292, 1300, 1306, 1315, 1320, 1323, 1328,
+    1337, 1355, 1359, 1365, 1370, 1380, 1388, 1394, 1400, 1405, 1410, 1416,
+    1422, 1427, 1432, 1459,
+];
+
+#[cfg(feature = "display_names_fr")]
+static DISPLAY_NAMES_FRA_LANGUAGES: [u16; 187] = [
+    Language::Aar as u16,
+    Language::Abk as u16,
+    Language::Afr as u16,
+    Language::Aka as u16,
+    Language::Amh as u16,
+    Language::Ara as u16,
+    Language::Arg as u16,
+    Language::Asm as u16,
+    Language::Ava as u16,
+    Language::Ave as u16,
+    Language::Aym as u16,
+    Language::Aze as u16,
+    Language::Bak as u16,
+    Language::Bam as u16,
+    Language::Bel as u16,
+    Language::Ben as u16,
+    Language::Bis as u16,
+    Language::Bod as u16,
+    Language::Bos as u16,
+    Language::Bre as u16,
+    Language::Bul as u16,
+    Language::Cat as u16,
+    Language::Ces as u16,
+    Language::Cha as u16,
+    Language::Che as u16,
+    Language::Chu as u16,
+    Language::Chv as u16,
+    Language::Cor as u16,
+    Language::Cos as u16,
+    Language::Cre as u16,
+    Language::Cym as u16,
+    Language::Dan as u16,
+    Language::Deu as u16,
+    Language::Div as u16,
+    Language::Dzo as u16,
+    Language::Ell as u16,
+    Language::Eng as u16,
+    Language::Epo as u16,
+    Language::Est as u16,
+    Language::Eus as u16,
+    Language::Ewe as u16,
+    Language::Fao as u16,
+    Language::Fas as u16,
+    Language::Fij as u16,
+    Language::Fin as u16,
+    Language::Fra as u16,
+    Language::Fry as u16,
+    Language::Ful as u16,
+    Language::Gla as u16,
+    Language::Gle as u16,
+    Language::Glg as u16,
+    Language::Glv as u16,
+    Language::Grn as u16,
+    Language::Guj as u16,
+    Language::Hat as u16,
+    Language::Hau as u16,
+    Language::Hbs as u16,
+    Language::Heb as u16,
+    Language::Her as u16,
+    Language::Hin as u16,
+    Language::Hmo as u16,
+    Language::Hrv as u16,
+    Language::Hun as u16,
+    Language::Hye as u16,
+    Language::Ibo as u16,
+    Language::Ido as u16,
+    Language::Iii as u16,
+    Language::Iku as u16,
+    Language::Ile as u16,
+    Language::Ina as u16,
+    Language::Ind as u16,
+    Language::Ipk as u16,
+    Language::Isl as u16,
+    Language::Ita as u16,
+    Language::Jav as u16,
+    Language::Jpn as u16,
+    Language::Kal as u16,
+    Language::Kan as u16,
+    Language::Kas as u16,
+    Language::Kat as u16,
+    Language::Kau as u16,
+    Language::Kaz as u16,
+    Language::Khm as u16,
+    Language::Kik as u16,
+    Language::Kin as u16,
+    Language::Kir as u16,
+    Language::Kom as u16,
+    Language::Kon as u16,
+    Language::Kor as u16,
+    Language::Kua as u16,
+    Language::Kur as u16,
+    Language::Lao as u16,
+    Language::Lat as u16,
+    Language::Lav as u16,
+    Language::Lim as u16,
+    Language::Lin as u16,
+    Language::Lit as u16,
+    Language::Ltz as u16,
+    Language::Lub as u16,
+    Language::Lug as u16,
+    Language::Mah as u16,
+    Language::Mal as u16,
+    Language::Mar as u16,
+    Language::Mkd as u16,
+    Language::Mlg as u16,
+    Language::Mlt as u16,
+    Language::Mon as u16,
+    Language::Mri as u16,
+    Language::Msa as u16,
+    Language::Mul as u16,
+    Language::Mya as u16,
+    Language::Nau as u16,
+    Language::Nav as u16,
+    Language::Nbl as u16,
+    Language::Nde as u16,
+    Language::Ndo as u16,
+    Language::Nep as u16,
+    Language::Nld as u16,
+    Language::Nno as u16,
+    Language::Nob as u16,
+    Language::Nor as u16,
+    Language::Nya as u16,
+    Language::Oci as u16,
+    Language::Oji as u16,
+    Language::Ori as u16,
+    Language::Orm as u16,
+    Language::Oss as u16,
+    Language::Pan as u16,
+    Language::Pli as u16,
+    Language::Pol as u16,
+    Language::Por as u16,
+    Language::Pus as u16,
+    Language::Que as u16,
+    Language::Roh as u16,
+    Language::Ron as u16,
+    Language::Run as u16,
+    Language::Rus as u16,
+    Language::Sag as u16,
+    Language::San as u16,
+    Language::Sin as u16,
+    Language::Slk as u16,
+    Language::Slv as u16,
+    Language::Sme as u16,
+    Language::Smo as u16,
+    Language::Sna as u16,
+    Language::Snd as u16,
+    Language::Som as u16,
+    Language::Sot as u16,
+    Language::Spa as u16,
+    Language::Sqi as u16,
+    Language::Srd as u16,
+    Language::Srp as u16,
+    Language::Ssw as u16,
+    Language::Sun as u16,
+    Language::Swa as u16,
+    Language::Swe as u16,
+    Language::Tah as u16,
+    Language::Tam as u16,
+    Language::Tat as u16,
+    Language::Tel as u16,
+    Language::Tgk as u16,
+    Language::Tgl as u16,
+    Language::Tha as u16,
+    Language::Tir as u16,
+    Language::Ton as u16,
+    Language::Tsn as u16,
+    Language::Tso as u16,
+    Language::Tuk as u16,
+    Language::Tur as u16,
+    Language::Twi as u16,
+    Language::Uig as u16,
+    Language::Ukr as u16,
+    Language::Und as u16,
+    Language::Urd as u16,
+    Language::Uzb as u16,
+    Language::Ven as u16,
+    Language::Vie as u16,
+    Language::Vol as u16,
+    Language::Wln as u16,
+    Language::Wol as u16,
+    Language::Xho as u16,
+    Language::Yid as u16,
+    Language::Yor as u16,
+    Language::Zha as u16,
+    Language::Zho as u16,
+    Language::Zul as u16,
+    Language::Zxx as u16,
+];
+
+#[cfg(feature = "display_names_fr")]
+pub(crate) static DISPLAY_NAMES_FRA: &str = concat!(
+    "afar",
+    "abkhaze",
+    "afrikaans",
+    "akan",
+    "amharique",
+    "arabe",
+    "aragonais",
+    "assamais",
+    "avar",
+    "avestique",
+    "aymara",
+    "azerbaïdjanais",
+    "bachkir",
+    "bambara",
+    "biélorusse",
+    "bengali",
+    "bichelamar",
+    "tibétain",
+    "bosniaque",
+    "breton",
+    "bulgare",
+    "catalan",
+    "tchèque",
+    "chamorro",
+    "tchétchène",
+    "slavon d’église",
+    "tchouvache",
+    "cornique",
+    "corse",
+    "cree",
+    "gallois",
+    "danois",
+    "allemand",
+    "maldivien",
+    "dzongkha",
+    "grec",
+    "anglais",
+    "espéranto",
+    "estonien",
+    "basque",
+    "éwé",
+    "féroïen",
+    "persan",
+    "fidjien",
+    "finnois",
+    "français",
+    "frison occidental",
+    "peul",
+    "gaélique écossais",
+    "irlandais",
+    "galicien",
+    "mannois",
+    "guarani",
+    "goudjarati",
+    "créole haïtien",
+    "haoussa",
+    "serbo-croate",
+    "hébreu",
+    "héréro",
+    "hindi",
+    "hiri motu",
+    "croate",
+    "hongrois",
+    "arménien",
+    "igbo",
+    "ido",
+    "yi du Sichuan",
+    "inuktitut",
+    "interlingue",
+    "interlingua",
+    "indonésien",
+    "inupiaq",
+    "islandais",
+    "italien",
+    "javanais",
+    "japonais",
+    "groenlandais",
+    "kannada",
+    "cachemiri",
+    "géorgien",
+    "kanouri",
+    "kazakh",
+    "khmer",
+    "kikuyu",
+    "kinyarwanda",
+    "kirghize",
+    "komi",
+    "kikongo",
+    "coréen",
+    "kuanyama",
+    "kurde",
+    "lao",
+    "latin",
+    "letton",
+    "limbourgeois",
+    "lingala",
+    "lituanien",
+    "luxembourgeois",
+    "luba-katanga (kiluba)",
+    "ganda",
+    "marshallais",
+    "malayalam",
+    "marathi",
+    "macédonien",
+    "malgache",
+    "maltais",
+    "mongol",
+    "maori",
+    "malais",
+    "multilingue",
+    "birman",
+    "nauruan",
+    "navajo",
+    "ndébélé du Sud",
+    "ndébélé du Nord",
+    "ndonga",
+    "népalais",
+    "néerlandais",
+    "norvégien nynorsk",
+    "norvégien bokmål",
+    "norvégien",
+    "chewa",
+    "occitan",
+    "ojibwa",
+    "odia",
+    "oromo",
+    "ossète",
+    "pendjabi",
+    "pali",
+    "polonais",
+    "portugais",
+    "pachto",
+    "quechua",
+    "romanche",
+    "roumain",
+    "roundi",
+    "russe",
+    "sango",
+    "sanskrit",
+    "cingalais",
+    "slovaque",
+    "slovène",
+    "same du Nord",
+    "samoan",
+    "shona",
+    "sindhi",
+    "somali",
+    "sotho du Sud",
+    "espagnol",
+    "albanais",
+    "sarde",
+    "serbe",
+    "swati",
+    "soundanais",
+    "swahili",
+    "suédois",
+    "tahitien",
+    "tamoul",
+    "tatar",
+    "télougou",
+    "tadjik",
+    "tagalog",
+    "thaï",
+    "tigrigna",
+    "tongien",
+    "tswana",
+    "tsonga",
+    "turkmène",
+    "turc",
+    "twi",
+    "ouïghour",
+    "ukrainien",
+    "langue indéterminée",
+    "ourdou",
+    "ouzbek",
+    "venda",
+    "vietnamien",
+    "volapük",
+    "wallon",
+    "wolof",
+    "xhosa",
+    "yiddish",
+    "yoruba",
+    "zhuang",
+    "chinois",
+    "zoulou",
+    "sans contenu linguistique",
+);
+
+#[cfg(feature = "display_names_fr")]
+pub(crate) static DISPLAY_NAMES_FRA_OFFSETS: [u16; 188] = [
+    0, 4, 11, 20, 24, 33, 38, 47, 55, 59, 68, 74, 89, 96, 103, 114, 121, 131,
+    140, 149, 155, 162, 169, 177, 185, 197, 215, 225, 233, 238, 242, 249, 255,
+    263, 272, 280, 284, 291, 301, 309, 315, 320, 329, 335, 342, 349, 358, 375,
+    379, 398, 407, 415, 422, 429, 439, 455, 462, 474, 481, 489, 494, 503, 509,
+    517, 526, 530, 533, 546, 555, 566, 577, 588, 595, 604, 611, 619, 627, 639,
+    646, 655, 664, 671, 677, 682, 688, 699, 707, 711, 718, 725, 733, 738, 741,
+    746, 752, 764, 771, 780, 794, 815, 820, 831, 840, 847, 858, 866, 873, 879,
+    884, 890, 901, 907, 914, 920, 937, 955, 961, 970, 982, 1000, 1018, 1028,
+    1033, 1040, 1046, 1050, 1055, 1062, 1070, 1074, 1082, 1091, 1097, 1104,
+    1112, 1119, 1125, 1130, 1135, 1143, 1152, 1160, 1168, 1180, 1186, 1191,
+    1197, 1203, 1215, 1223, 1231, 1236, 1241, 1246, 1256, 1263, 1271, 1279,
+    1285, 1290, 1299, 1305, 1312, 1317, 1325, 1332, 1338, 1344, 1353, 1357,
+    1360, 1369, 1378, 1399, 1405, 1411, 1416, 1426, 1434, 1440, 1445, 1450,
+    1457, 1463, 1469, 1476, 1482, 1507,
+];
+
+#[cfg(feature = "display_names_it")]
+static DISPLAY_NAMES_ITA_LANGUAGES: [u16; 187] = [
+    Language::Aar as u16,
+    Language::Abk as u16,
+    Language::Afr as u16,
+    Language::Aka as u16,
+    Language::Amh as u16,
+    Language::Ara as u16,
+    Language::Arg as u16,
+    Language::Asm as u16,
+    Language::Ava as u16,
+    Language::Ave as u16,
+    Language::Aym as u16,
+    Language::Aze as u16,
+    Language::Bak as u16,
+    Language::Bam as u16,
+    Language::Bel as u16,
+    Language::Ben as u16,
+    Language::Bis as u16,
+    Language::Bod as u16,
+    Language::Bos as u16,
+    Language::Bre as u16,
+    Language::Bul as u16,
+    Language::Cat as u16,
+    Language::Ces as u16,
+    Language::Cha as u16,
+    Language::Che as u16,
+    Language::Chu as u16,
+    Language::Chv as u16,
+    Language::Cor as u16,
+    Language::Cos as u16,
+    Language::Cre as u16,
+    Language::Cym as u16,
+    Language::Dan as u16,
+    Language::Deu as u16,
+    Language::Div as u16,
+    Language::Dzo as u16,
+    Language::Ell as u16,
+    Language::Eng as u16,
+    Language::Epo as u16,
+    Language::Est as u16,
+    Language::Eus as u16,
+    Language::Ewe as u16,
+    Language::Fao as u16,
+    Language::Fas as u16,
+    Language::Fij as u16,
+    Language::Fin as u16,
+    Language::Fra as u16,
+    Language::Fry as u16,
+    Language::Ful as u16,
+    Language::Gla as u16,
+    Language::Gle as u16,
+    Language::Glg as u16,
+    Language::Glv as u16,
+    Language::Grn as u16,
+    Language::Guj as u16,
+    Language::Hat as u16,
+    Language::Hau as u16,
+    Language::Hbs as u16,
+    Language::Heb as u16,
+    Language::Her as u16,
+    Language::Hin as u16,
+    Language::Hmo as u16,
+    Language::Hrv as u16,
+    Language::Hun as u16,
+    Language::Hye as u16,
+    Language::Ibo as u16,
+    Language::Ido as u16,
+    Language::Iii as u16,
+    Language::Iku as u16,
+    Language::Ile as u16,
+    Language::Ina as u16,
+    Language::Ind as u16,
+    Language::Ipk as u16,
+    Language::Isl as u16,
+    Language::Ita as u16,
+    Language::Jav as u16,
+    Language::Jpn as u16,
+    Language::Kal as u16,
+    Language::Kan as u16,
+    Language::Kas as u16,
+    Language::Kat as u16,
+    Language::Kau as u16,
+    Language::Kaz as u16,
+    Language::Khm as u16,
+    Language::Kik as u16,
+    Language::Kin as u16,
+    Language::Kir as u16,
+    Language::Kom as u16,
+    Language::Kon as u16,
+    Language::Kor as u16,
+    Language::Kua as u16,
+    Language::Kur as u16,
+    Language::Lao as u16,
+    Language::Lat as u16,
+    Language::Lav as u16,
+    Language::Lim as u16,
+    Language::Lin as u16,
+    Language::Lit as u16,
+    Language::Ltz as u16,
+    Language::Lub as u16,
+    Language::Lug as u16,
+    Language::Mah as u16,
+    Language::Mal as u16,
+    Language::Mar as u16,
+    Language::Mkd as u16,
+    Language::Mlg as u16,
+    Language::Mlt as u16,
+    Language::Mon as u16,
+    Language::Mri as u16,
+    Language::Msa as u16,
+    Language::Mul as u16,
+    Language::Mya as u16,
+    Language::Nau as u16,
+    Language::Nav as u16,
+    Language::Nbl as u16,
+    Language::Nde as u16,
+    Language::Ndo as u16,
+    Language::Nep as u16,
+    Language::Nld as u16,
+    Language::Nno as u16,
+    Language::Nob as u16,
+    Language::Nor as u16,
+    Language::Nya as u16,
+    Language::Oci as u16,
+    Language::Oji as u16,
+    Language::Ori as u16,
+    Language::Orm as u16,
+    Language::Oss as u16,
+    Language::Pan as u16,
+    Language::Pli as u16,
+    Language::Pol as u16,
+    Language::Por as u16,
+    Language::Pus as u16,
+    Language::Que as u16,
+    Language::Roh as u16,
+    Language::Ron as u16,
+    Language::Run as u16,
+    Language::Rus as u16,
+    Language::Sag as u16,
+    Language::San as u16,
+    Language::Sin as u16,
+    Language::Slk as u16,
+    Language::Slv as u16,
+    Language::Sme as u16,
+    Language::Smo as u16,
+    Language::Sna as u16,
+    Language::Snd as u16,
+    Language::Som as u16,
+    Language::Sot as u16,
+    Language::Spa as u16,
+    Language::Sqi as u16,
+    Language::Srd as u16,
+    Language::Srp as u16,
+    Language::Ssw as u16,
+    Language::Sun as u16,
+    Language::Swa as u16,
+    Language::Swe as u16,
+    Language::Tah as u16,
+    Language::Tam as u16,
+    Language::Tat as u16,
+    Language::Tel as u16,
+    Language::Tgk as u16,
+    Language::Tgl as u16,
+    Language::Tha as u16,
+    Language::Tir as u16,
+    Language::Ton as u16,
+    Language::Tsn as u16,
+    Language::Tso as u16,
+    Language::Tuk as u16,
+    Language::Tur as u16,
+    Language::Twi as u16,
+    Language::Uig as u16,
+    Language::Ukr as u16,
+    Language::Und as u16,
+    Language::Urd as u16,
+    Language::Uzb as u16,
+    Language::Ven as u16,
+    Language::Vie as u16,
+    Language::Vol as u16,
+    Language::Wln as u16,
+    Language::Wol as u16,
+    Language::Xho as u16,
+    Language::Yid as u16,
+    Language::Yor as u16,
+    Language::Zha as u16,
+    Language::Zho as u16,
+    Language::Zul as u16,
+    Language::Zxx as u16,
+];
+
+#[cfg(feature = "display_names_it")]
+pub(crate) static DISPLAY_NAMES_ITA: &str = concat!(
+    "afar",
+    "abcaso",
+    "afrikaans",
+    "akan",
+    "amarico",
+    "arabo",
+    "aragonese",
+    "assamese",
+    "avaro",
+    "avestan",
+    "aymara",
+    "azerbaigiano",
+    "baschiro",
+    "bambara",
+    "bielorusso",
+    "bengalese",
+    "bislama",
+    "tibetano",
+    "bosniaco",
+    "bretone",
+    "bulgaro",
+    "catalano",
+    "ceco",
+    "chamorro",
+    "ceceno",
+    "slavo ecclesiastico",
+    "ciuvascio",
+    "cornico",
+    "corso",
+    "cree",
+    "gallese",
+    "danese",
+    "tedesco",
+    "divehi",
+    "dzongkha",
+    "greco",
+    "inglese",
+    "esperanto",
+    "estone",
+    "basco",
+    "ewe",
+    "faroese",
+    "persiano",
+    "figiano",
+    "finlandese",
+    "francese",
+    "frisone occidentale",
+    "fulah",
+    "gaelico scozzese",
+    "irlandese",
+    "galiziano",
+    "mannese",
+    "guaraní",
+    "gujarati",
+    "creolo haitiano",
+    "hausa",
+    "serbo-croato",
+    "ebraico",
+    "herero",
+    "hindi",
+    "hiri motu",
+    "croato",
+    "ungherese",
+    "armeno",
+    "igbo",
+    "ido",
+    "sichuan yi",
+    "inuktitut",
+    "interlingue",
+    "interlingua",
+    "indonesiano",
+    "inupiak",
+    "islandese",
+    "italiano",
+    "giavanese",
+    "giapponese",
+    "groenlandese",
+    "kannada",
+    "kashmiri",
+    "georgiano",
+    "kanuri",
+    "kazako",
+    "khmer",
+    "kikuyu",
+    "kinyarwanda",
+    "kirghiso",
+    "komi",
+    "kongo",
+    "coreano",
+    "kuanyama",
+    "curdo",
+    "lao",
+    "latino",
+    "lettone",
+    "limburghese",
+    "lingala",
+    "lituano",
+    "lussemburghese",
+    "luba-katanga",
+    "ganda",
+    "marshallese",
+    "malayalam",
+    "marathi",
+    "macedone",
+    "malgascio",
+    "maltese",
+    "mongolo",
+    "maori",
+    "malese",
+    "multilingua",
+    "birmano",
+    "nauru",
+    "navajo",
+    "ndebele del sud",
+    "ndebele del nord",
+    "ndonga",
+    "nepalese",
+    "olandese",
+    "norvegese nynorsk",
+    "norvegese bokmål",
+    "norvegese",
+    "nyanja",
+    "occitano",
+    "ojibwa",
+    "odia",
+    "oromo",
+    "ossetico",
+    "punjabi",
+    "pali",
+    "polacco",
+    "portoghese",
+    "pashto",
+    "quechua",
+    "romancio",
+    "rumeno",
+    "rundi",
+    "russo",
+    "sango",
+    "sanscrito",
+    "singalese",
+    "slovacco",
+    "sloveno",
+    "sami del nord",
+    "samoano",
+    "shona",
+    "sindhi",
+    "somalo",
+    "sotho del sud",
+    "spagnolo",
+    "albanese",
+    "sardo",
+    "serbo",
+    "swati",
+    "sundanese",
+    "swahili",
+    "svedese",
+    "taitiano",
+    "tamil",
+    "tataro",
+    "telugu",
+    "tagico",
+    "tagalog",
+    "thailandese",
+    "tigrino",
+    "tongano",
+    "tswana",
+    "tsonga",
+    "turcomanno",
+    "turco",
+    "ci",
+    "uiguro",
+    "ucraino",
+    "lingua imprecisata",
+    "urdu",
+    "uzbeco",
+    "venda",
+    "vietnamita",
+    "volapük",
+    "vallone",
+    "wolof",
+    "xhosa",
+    "yiddish",
+    "yoruba",
+    "zhuang",
+    "cinese",
+    "zulu",
+    "nessun contenuto linguistico",
+);
+
+#[cfg(feature = "display_names_it")]
+pub(crate) static DISPLAY_NAMES_ITA_OFFSETS: [u16; 188] = [
+    0, 4, 10, 19, 23, 30, 35, 44, 52, 57, 64, 70, 82, 90, 97, 107, 116, 123,
+    131, 139, 146, 153, 161, 165, 173, 179, 198, 207, 214, 219, 223, 230, 236,
+    243, 249, 257, 262, 269, 278, 284, 289, 292, 299, 307, 314, 324, 332, 351,
+    356, 372, 381, 390, 397, 405, 413, 428, 433, 445, 452, 458, 463, 472, 478,
+    487, 493, 497, 500, 510, 519, 530, 541, 552, 559, 568, 576, 585, 595, 607,
+    614, 622, 631, 637, 643, 648, 654, 665, 673, 677, 682, 689, 697, 702, 705,
+    711, 718, 729, 736, 743, 757, 769, 774, 785, 794, 801, 809, 818, 825, 832,
+    837, 843, 854, 861, 866, 872, 887, 903, 909, 917, 925, 942, 959, 968, 974,
+    982, 988, 992, 997, 1005, 1012, 1016, 1023, 1033, 1039, 1046, 1054, 1060,
+    1065, 1070, 1075, 1084, 1093, 1101, 1108, 1121, 1128, 1133, 1139, 1145,
+    1158, 1166, 1174, 1179, 1184, 1189, 1198, 1205, 1212, 1220, 1225, 1231,
+    1237, 1243, 1250, 1261, 1268, 1275, 1281, 1287, 1297, 1302, 1304, 1310,
+    1317, 1335, 1339, 1345, 1350, 1360, 1368, 1375, 1380, 1385, 1392, 1398,
+    1404, 1410, 1414, 1442,
+];
+
+#[cfg(feature = "display_names_ja")]
+static DISPLAY_NAMES_JPN_LANGUAGES: [u16; 187] = [
+    Language::Aar as u16,
+    Language::Abk as u16,
+    Language::Afr as u16,
+    Language::Aka as u16,
+    Language::Amh as u16,
+    Language::Ara as u16,
+    Language::Arg as u16,
+    Language::Asm as u16,
+    Language::Ava as u16,
+    Language::Ave as u16,
+    Language::Aym as u16,
+    Language::Aze as u16,
+    Language::Bak as u16,
+    Language::Bam as u16,
+    Language::Bel as u16,
+    Language::Ben as u16,
+    Language::Bis as u16,
+    Language::Bod as u16,
+    Language::Bos as u16,
+    Language::Bre as u16,
+    Language::Bul as u16,
+    Language::Cat as u16,
+    Language::Ces as u16,
+    Language::Cha as u16,
+    Language::Che as u16,
+    Language::Chu as u16,
+    Language::Chv as u16,
+    Language::Cor as u16,
+    Language::Cos as u16,
+    Language::Cre as u16,
+    Language::Cym as u16,
+    Language::Dan as u16,
+    Language::Deu as u16,
+    Language::Div as u16,
+    Language::Dzo as u16,
+    Language::Ell as u16,
+    Language::Eng as u16,
+    Language::Epo as u16,
+    Language::Est as u16,
+    Language::Eus as u16,
+    Language::Ewe as u16,
+    Language::Fao as u16,
+    Language::Fas as u16,
+    Language::Fij as u16,
+    Language::Fin as u16,
+    Language::Fra as u16,
+    Language::Fry as u16,
+    Language::Ful as u16,
+    Language::Gla as u16,
+    Language::Gle as u16,
+    Language::Glg as u16,
+    Language::Glv as u16,
+    Language::Grn as u16,
+    Language::Guj as u16,
+    Language::Hat as u16,
+    Language::Hau as u16,
+    Language::Hbs as u16,
+    Language::Heb as u16,
+    Language::Her as u16,
+    Language::Hin as u16,
+    Language::Hmo as u16,
+    Language::Hrv as u16,
+    Language::Hun as u16,
+    Language::Hye as u16,
+    Language::Ibo as u16,
+    Language::Ido as u16,
+    Language::Iii as u16,
+    Language::Iku as u16,
+    Language::Ile as u16,
+    Language::Ina as u16,
+    Language::Ind as u16,
+    Language::Ipk as u16,
+    Language::Isl as u16,
+    Language::Ita as u16,
+    Language::Jav as u16,
+    Language::Jpn as u16,
+    Language::Kal as u16,
+    Language::Kan as u16,
+    Language::Kas as u16,
+    Language::Kat as u16,
+    Language::Kau as u16,
+    Language::Kaz as u16,
+    Language::Khm as u16,
+    Language::Kik as u16,
+    Language::Kin as u16,
+    Language::Kir as u16,
+    Language::Kom as u16,
+    Language::Kon as u16,
+    Language::Kor as u16,
+    Language::Kua as u16,
+    Language::Kur as u16,
+    Language::Lao as u16,
+    Language::Lat as u16,
+    Language::Lav as u16,
+    Language::Lim as u16,
+    Language::Lin as u16,
+    Language::Lit as u16,
+    Language::Ltz as u16,
+    Language::Lub as u16,
+    Language::Lug as u16,
+    Language::Mah as u16,
+    Language::Mal as u16,
+    Language::Mar as u16,
+    Language::Mkd as u16,
+    Language::Mlg as u16,
+    Language::Mlt as u16,
+    Language::Mon as u16,
+    Language::Mri as u16,
+    Language::Msa as u16,
+    Language::Mul as u16,
+    Language::Mya as u16,
+    Language::Nau as u16,
+    Language::Nav as u16,
+    Language::Nbl as u16,
+    Language::Nde as u16,
+    Language::Ndo as u16,
+    Language::Nep as u16,
+    Language::Nld as u16,
+    Language::Nno as u16,
+    Language::Nob as u16,
+    Language::Nor as u16,
+    Language::Nya as u16,
+    Language::Oci as u16,
+    Language::Oji as u16,
+    Language::Ori as u16,
+    Language::Orm as u16,
+    Language::Oss as u16,
+    Language::Pan as u16,
+    Language::Pli as u16,
+    Language::Pol as u16,
+    Language::Por as u16,
+    Language::Pus as u16,
+    Language::Que as u16,
+    Language::Roh as u16,
+    Language::Ron as u16,
+    Language::Run as u16,
+    Language::Rus as u16,
+    Language::Sag as u16,
+    Language::San as u16,
+    Language::Sin as u16,
+    Language::Slk as u16,
+    Language::Slv as u16,
+    Language::Sme as u16,
+    Language::Smo as u16,
+    Language::Sna as u16,
+    Language::Snd as u16,
+    Language::Som as u16,
+    Language::Sot as u16,
+    Language::Spa as u16,
+    Language::Sqi as u16,
+    Language::Srd as u16,
+    Language::Srp as u16,
+    Language::Ssw as u16,
+    Language::Sun as u16,
+    Language::Swa as u16,
+    Language::Swe as u16,
+    Language::Tah as u16,
+    Language::Tam as u16,
+    Language::Tat as u16,
+    Language::Tel as u16,
+    Language::Tgk as u16,
+    Language::Tgl as u16,
+    Language::Tha as u16,
+    Language::Tir as u16,
+    Language::Ton as u16,
+    Language::Tsn as u16,
+    Language::Tso as u16,
+    Language::Tuk as u16,
+    Language::Tur as u16,
+    Language::Twi as u16,
+    Language::Uig as u16,
+    Language::Ukr as u16,
+    Language::Und as u16,
+    Language::Urd as u16,
+    Language::Uzb as u16,
+    Language::Ven as u16,
+    Language::Vie as u16,
+    Language::Vol as u16,
+    Language::Wln as u16,
+    Language::Wol as u16,
+    Language::Xho as u16,
+    Language::Yid as u16,
+    Language::Yor as u16,
+    Language::Zha as u16,
+    Language::Zho as u16,
+    Language::Zul as u16,
+    Language::Zxx as u16,
+];
+
+#[cfg(feature = "display_names_ja")]
+pub(crate) static DISPLAY_NAMES_JPN: &str = concat!(
+    "アファル語",
+    "アブハズ語",
+    "アフリカーンス語",
+    "アカン語",
+    "アムハラ語",
+    "アラビア語",
+    "アラゴン語",
+    "アッサム語",
+    "アヴァル語",
+    "アヴェスタ語",
+    "アイマラ語",
+    "アゼルバイジャン語",
+    "バシキール語",
+    "バンバラ語",
+    "ベラルーシ語",
+    "ベンガル語",
+    "ビスラマ語",
+    "チベット語",
+    "ボスニア語",
+    "ブルトン語",
+    "ブルガリア語",
+    "カタロニア語",
+    "チェコ語",
+    "チャモロ語",
+    "チェチェン語",
+    "教会スラブ語",
+    "チュヴァシ語",
+    "コーンウォール語",
+    "コルシカ語",
+    "クリー語",
+    "ウェールズ語",
+    "デンマーク語",
+    "ドイツ語",
+    "ディベヒ語",
+    "ゾンカ語",
+    "ギリシャ語",
+    "英語",
+    "エスペラント語",
+    "エストニア語",
+    "バスク語",
+    "エウェ語",
+    "フェロー語",
+    "ペルシア語",
+    "フィジー語",
+    "フィンランド語",
+    "フランス語",
+    "西フリジア語",
+    "フラ語",
+    "スコットランド・ゲール語",
+    "アイルランド語",
+    "ガリシア語",
+    "マン島語",
+    "グアラニー語",
+    "グジャラート語",
+    "ハイチ・クレオール語",
+    "ハウサ語",
+    "セルボ・クロアチア語",
+    "ヘブライ語",
+    "ヘレロ語",
+    "ヒンディー語",
+    "ヒリモツ語",
+    "クロアチア語",
+    "ハンガリー語",
+    "アルメニア語",
+    "イボ語",
+    "イド語",
+    "四川イ語",
+    "イヌクティトット語",
+    "インターリング",
+    "インターリングア",
+    "インドネシア語",
+    "イヌピアック語",
+    "アイスランド語",
+    "イタリア語",
+    "ジャワ語",
+    "日本語",
+    "グリーンランド語",
+    "カンナダ語",
+    "カシミール語",
+    "ジョージア語",
+    "カヌリ語",
+    "カザフ語",
+    "クメール語",
+    "キクユ語",
+    "キニアルワンダ語",
+    "キルギス語",
+    "コミ語",
+    "コンゴ語",
+    "韓国語",
+    "クワニャマ語",
+    "クルド語",
+    "ラオ語",
+    "ラテン語",
+    "ラトビア語",
+    "リンブルフ語",
+    "リンガラ語",
+    "リトアニア語",
+    "ルクセンブルク語",
+    "ルバ・カタンガ語",
+    "ガンダ語",
+    "マーシャル語",
+    "マラヤーラム語",
+    "マラーティー語",
+    "マケドニア語",
+    "マダガスカル語",
+    "マルタ語",
+    "モンゴル語",
+    "マオリ語",
+    "マレー語",
+    "複数言語",
+    "ミャンマー語",
+    "ナウル語",
+    "ナバホ語",
+    "南ンデベレ語",
+    "北ンデベレ語",
+    "ンドンガ語",
+    "ネパール語",
+    "オランダ語",
+    "ノルウェー語(ニーノシュク)",
+    "ノルウェー語(ブークモール)",
+    "ノルウェー語",
+    "ニャンジャ語",
+    "オック語",
+    "オジブウェー語",
+    "オディア語",
+    "オロモ語",
+    "オセット語",
+    "パンジャブ語",
+    "パーリ語",
+    "ポーランド語",
+    "ポルトガル語",
+    "パシュトゥー語",
+    "ケチュア語",
+    "ロマンシュ語",
+    "ルーマニア語",
+    "ルンディ語",
+    "ロシア語",
+    "サンゴ語",
+    "サンスクリット語",
+    "シンハラ語",
+    "スロバキア語",
+    "スロベニア語",
+    "北サーミ語",
+    "サモア語",
+    "ショナ語",
+    "シンド語",
+    "ソマリ語",
+    "南部ソト語",
+    "スペイン語",
+    "アルバニア語",
+    "サルデーニャ語",
+    "セルビア語",
+    "スワジ語",
+    "スンダ語",
+    "スワヒリ語",
+    "スウェーデン語",
+    "タヒチ語",
+    "タミル語",
+    "タタール語",
+    "テルグ語",
+    "タジク語",
+    "タガログ語",
+    "タイ語",
+    "ティグリニア語",
+    "トンガ語",
+    "ツワナ語",
+    "ツォンガ語",
+    "トルクメン語",
+    "トルコ語",
+    "トウィ語",
+    "ウイグル語",
+    "ウクライナ語",
+    "言語不明",
+    "ウルドゥー語",
+    "ウズベク語",
+    "ベンダ語",
+    "ベトナム語",
+    "ヴォラピュク語",
+    "ワロン語",
+    "ウォロフ語",
+    "コサ語",
+    "イディッシュ語",
+    "ヨルバ語",
+    "チワン語",
+    "中国語",
+    "ズールー語",
+    "言語的内容なし",
+);
+
+#[cfg(feature = "display_names_ja")]
+pub(crate) static DISPLAY_NAMES_JPN_OFFSETS: [u16; 188] = [
+    0, 15, 30, 54, 66, 81, 96, 111, 126, 141, 159, 174, 201, 219, 234, 252,
+    267, 282, 297, 312, 327, 345, 363, 375, 390, 408, 426, 444, 468, 483, 495,
+    513, 531, 543, 558, 570, 585, 591, 612, 630, 642, 654, 669, 684, 699, 720,
+    735, 753, 762, 798, 819, 834, 846, 864, 885, 915, 927, 957, 972, 984, 1002,
+    1017, 1035, 1053, 1071, 1080, 1089, 1101, 1128, 1149, 1173, 1194, 1215,
+    1236, 1251, 1263, 1272, 1296, 1311, 1329, 1347, 1359, 1371, 1386, 1398,
+    1422, 1437, 1446, 1458, 1467, 1485, 1497, 1506, 1518, 1533, 1551, 1566,
+    1584, 1608, 1632, 1644, 1662, 1683, 1704, 1722, 1743, 1755, 1770, 1782,
+    1794, 1806, 1824, 1836, 1848, 1866, 1884, 1899, 1914, 1929, 1967, 2005,
+    2023, 2041, 2053, 2074, 2089, 2101, 2116, 2134, 2146, 2164, 2182, 2203,
+    2218, 2236, 2254, 2269, 2281, 2293, 2317, 2332, 2350, 2368, 2383, 2395,
+    2407, 2419, 2431, 2446, 2461, 2479, 2500, 2515, 2527, 2539, 2554, 2575,
+    2587, 2599, 2614, 2626, 2638, 2653, 2662, 2683, 2695, 2707, 2722, 2740,
+    2752, 2764, 2779, 2797, 2809, 2827, 2842, 2854, 2869, 2890, 2902, 2917,
+    2926, 2947, 2959, 2971, 2980, 2995, 3016,
+];
+
+#[cfg(feature = "display_names_pt")]
+static DISPLAY_NAMES_POR_LANGUAGES: [u16; 187] = [
+    Language::Aar as u16,
+    Language::Abk as u16,
+    Language::Afr as u16,
+    Language::Aka as u16,
+    Language::Amh as u16,
+    Language::Ara as u16,
+    Language::Arg as u16,
+    Language::Asm as u16,
+    Language::Ava as u16,
+    Language::Ave as u16,
+    Language::Aym as u16,
+    Language::Aze as u16,
+    Language::Bak as u16,
+    Language::Bam as u16,
+    Language::Bel as u16,
+    Language::Ben as u16,
+    Language::Bis as u16,
+    Language::Bod as u16,
+    Language::Bos as u16,
+    Language::Bre as u16,
+    Language::Bul as u16,
+    Language::Cat as u16,
+    Language::Ces as u16,
+    Language::Cha as u16,
+    Language::Che as u16,
+    Language::Chu as u16,
+    Language::Chv as u16,
+    Language::Cor as u16,
+    Language::Cos as u16,
+    Language::Cre as u16,
+    Language::Cym as u16,
+    Language::Dan as u16,
+    Language::Deu as u16,
+    Language::Div as u16,
+    Language::Dzo as u16,
+    Language::Ell as u16,
+    Language::Eng as u16,
+    Language::Epo as u16,
+    Language::Est as u16,
+    Language::Eus as u16,
+    Language::Ewe as u16,
+    Language::Fao as u16,
+    Language::Fas as u16,
+    Language::Fij as u16,
+    Language::Fin as u16,
+    Language::Fra as u16,
+    Language::Fry as u16,
+    Language::Ful as u16,
+    Language::Gla as u16,
+    Language::Gle as u16,
+    Language::Glg as u16,
+    Language::Glv as u16,
+    Language::Grn as u16,
+    Language::Guj as u16,
+    Language::Hat as u16,
+    Language::Hau as u16,
+    Language::Hbs as u16,
+    Language::Heb as u16,
+    Language::Her as u16,
+    Language::Hin as u16,
+    Language::Hmo as u16,
+    Language::Hrv as u16,
+    Language::Hun as u16,
+    Language::Hye as u16,
+    Language::Ibo as u16,
+    Language::Ido as u16,
+    Language::Iii as u16,
+    Language::Iku as u16,
+    Language::Ile as u16,
+    Language::Ina as u16,
+    Language::Ind as u16,
+    Language::Ipk as u16,
+    Language::Isl as u16,
+    Language::Ita as u16,
+    Language::Jav as u16,
+    Language::Jpn as u16,
+    Language::Kal as u16,
+    Language::Kan as u16,
+    Language::Kas as u16,
+    Language::Kat as u16,
+    Language::Kau as u16,
+    Language::Kaz as u16,
+    Language::Khm as u16,
+    Language::Kik as u16,
+    Language::Kin as u16,
+    Language::Kir as u16,
+    Language::Kom as u16,
+    Language::Kon as u16,
+    Language::Kor as u16,
+    Language::Kua as u16,
+    Language::Kur as u16,
+    Language::Lao as u16,
+    Language::Lat as u16,
+    Language::Lav as u16,
+    Language::Lim as u16,
+    Language::Lin as u16,
+    Language::Lit as u16,
+    Language::Ltz as u16,
+    Language::Lub as u16,
+    Language::Lug as u16,
+    Language::Mah as u16,
+    Language::Mal as u16,
+    Language::Mar as u16,
+    Language::Mkd as u16,
+    Language::Mlg as u16,
+    Language::Mlt as u16,
+    Language::Mon as u16,
+    Language::Mri as u16,
+    Language::Msa as u16,
+    Language::Mul as u16,
+    Language::Mya as u16,
+    Language::Nau as u16,
+    Language::Nav as u16,
+    Language::Nbl as u16,
+    Language::Nde as u16,
+    Language::Ndo as u16,
+    Language::Nep as u16,
+    Language::Nld as u16,
+    Language::Nno as u16,
+    Language::Nob as u16,
+    Language::Nor as u16,
+    Language::Nya as u16,
+    Language::Oci as u16,
+    Language::Oji as u16,
+    Language::Ori as u16,
+    Language::Orm as u16,
+    Language::Oss as u16,
+    Language::Pan as u16,
+    Language::Pli as u16,
+    Language::Pol as u16,
+    Language::Por as u16,
+    Language::Pus as u16,
+    Language::Que as u16,
+    Language::Roh as u16,
+    Language::Ron as u16,
+    Language::Run as u16,
+    Language::Rus as u16,
+    Language::Sag as u16,
+    Language::San as u16,
+    Language::Sin as u16,
+    Language::Slk as u16,
+    Language::Slv as u16,
+    Language::Sme as u16,
+    Language::Smo as u16,
+    Language::Sna as u16,
+    Language::Snd as u16,
+    Language::Som as u16,
+    Language::Sot as u16,
+    Language::Spa as u16,
+    Language::Sqi as u16,
+    Language::Srd as u16,
+    Language::Srp as u16,
+    Language::Ssw as u16,
+    Language::Sun as u16,
+    Language::Swa as u16,
+    Language::Swe as u16,
+    Language::Tah as u16,
+    Language::Tam as u16,
+    Language::Tat as u16,
+    Language::Tel as u16,
+    Language::Tgk as u16,
+    Language::Tgl as u16,
+    Language::Tha as u16,
+    Language::Tir as u16,
+    Language::Ton as u16,
+    Language::Tsn as u16,
+    Language::Tso as u16,
+    Language::Tuk as u16,
+    Language::Tur as u16,
+    Language::Twi as u16,
+    Language::Uig as u16,
+    Language::Ukr as u16,
+    Language::Und as u16,
+    Language::Urd as u16,
+    Language::Uzb as u16,
+    Language::Ven as u16,
+    Language::Vie as u16,
+    Language::Vol as u16,
+    Language::Wln as u16,
+    Language::Wol as u16,
+    Language::Xho as u16,
+    Language::Yid as u16,
+    Language::Yor as u16,
+    Language::Zha as u16,
+    Language::Zho as u16,
+    Language::Zul as u16,
+    Language::Zxx as u16,
+];
+
+#[cfg(feature = "display_names_pt")]
+pub(crate) static DISPLAY_NAMES_POR: &str = concat!(
+    "afar",
+    "abcázio",
+    "africâner",
+    "akan",
+    "amárico",
+    "árabe",
+    "aragonês",
+    "assamês",
+    "avárico",
+    "avéstico",
+    "aimará",
+    "azerbaijano",
+    "bashkir",
+    "bambara",
+    "bielorrusso",
+    "bengali",
+    "bislamá",
+    "tibetano",
+    "bósnio",
+    "bretão",
+    "búlgaro",
+    "catalão",
+    "tcheco",
+    "chamorro",
+    "checheno",
+    "eslavo eclesiástico",
+    "tchuvache",
+    "córnico",
+    "corso",
+    "cree",
+    "galês",
+    "dinamarquês",
+    "alemão",
+    "divehi",
+    "dzonga",
+    "grego",
+    "inglês",
+    "esperanto",
+    "estoniano",
+    "basco",
+    "ewe",
+    "feroês",
+    "persa",
+    "fijiano",
+    "finlandês",
+    "francês",
+    "frísio ocidental",
+    "fula",
+    "gaélico escocês",
+    "irlandês",
+    "galego",
+    "manx",
+    "guarani",
+    "guzerate",
+    "haitiano",
+    "hauçá",
+    "servo-croata",
+    "hebraico",
+    "herero",
+    "híndi",
+    "hiri motu",
+    "croata",
+    "húngaro",
+    "armênio",
+    "igbo",
+    "ido",
+    "sichuan yi",
+    "inuktitut",
+    "interlingue",
+    "interlíngua",
+    "indonésio",
+    "inupiaque",
+    "islandês",
+    "italiano",
+    "javanês",
+    "japonês",
+    "groenlandês",
+    "canarim",
+    "caxemira",
+    "georgiano",
+    "canúri",
+    "cazaque",
+    "khmer",
+    "quicuio",
+    "quiniaruanda",
+    "quirguiz",
+    "komi",
+    "congolês",
+    "coreano",
+    "cuanhama",
+    "curdo",
+    "laosiano",
+    "latim",
+    "letão",
+    "limburguês",
+    "lingala",
+    "lituano",
+    "luxemburguês",
+    "luba-catanga",
+    "luganda",
+    "marshalês",
+    "malaiala",
+    "marati",
+    "macedônio",
+    "malgaxe",
+    "maltês",
+    "mongol",
+    "maori",
+    "malaio",
+    "múltiplos idiomas",
+    "birmanês",
+    "nauruano",
+    "navajo",
+    "ndebele do sul",
+    "ndebele do norte",
+    "dongo",
+    "nepalês",
+    "holandês",
+    "nynorsk norueguês",
+    "bokmål norueguês",
+    "norueguês",
+    "nianja",
+    "occitânico",
+    "ojibwa",
+    "oriá",
+    "oromo",
+    "osseto",
+    "panjabi",
+    "páli",
+    "polonês",
+    "português",
+    "pashto",
+    "quíchua",
+    "romanche",
+    "romeno",
+    "rundi",
+    "russo",
+    "sango",
+    "sânscrito",
+    "cingalês",
+    "eslovaco",
+    "esloveno",
+    "sami setentrional",
+    "samoano",
+    "xona",
+    "sindi",
+    "somali",
+    "soto do sul",
+    "espanhol",
+    "albanês",
+    "sardo",
+    "sérvio",
+    "suázi",
+    "sundanês",
+    "suaíli",
+    "sueco",
+    "taitiano",
+    "tâmil",
+    "tártaro",
+    "télugo",
+    "tadjique",
+    "tagalo",
+    "tailandês",
+    "tigrínia",
+    "tonganês",
+    "tswana",
+    "tsonga",
+    "turcomeno",
+    "turco",
+    "twi",
+    "uigur",
+    "ucraniano",
+    "idioma desconhecido",
+    "urdu",
+    "uzbeque",
+    "venda",
+    "vietnamita",
+    "volapuque",
+    "valão",
+    "uolofe",
+    "xhosa",
+    "iídiche",
+    "iorubá",
+    "zhuang",
+    "chinês",
+    "zulu",
+    "sem conteúdo linguístico",
+);
+
+#[cfg(feature = "display_names_pt")]
+pub(crate) static DISPLAY_NAMES_POR_OFFSETS: [u16; 188] = [
+    0, 4, 12, 22, 26, 34, 40, 49, 57, 65, 74, 81, 92, 99, 106, 117, 124, 132,
+    140, 147, 154, 162, 170, 176, 184, 192, 212, 221, 229, 234, 238, 244, 256,
+    263, 269, 275, 280, 287, 296, 305, 310, 313, 320, 325, 332, 342, 350, 367,
+    371, 388, 397, 403, 407, 414, 422, 430, 437, 449, 457, 463, 469, 478, 484,
+    492, 500, 504, 507, 517, 526, 537, 549, 559, 568, 577, 585, 593, 601, 613,
+    620, 628, 637, 644, 651, 656, 663, 675, 683, 687, 696, 703, 711, 716, 724,
+    729, 735, 746, 753, 760, 773, 785, 792, 802, 810, 816, 826, 833, 840, 846,
+    851, 857, 875, 884, 892, 898, 912, 928, 933, 941, 950, 968, 986, 996, 1002,
+    1013, 1019, 1024, 1029, 1035, 1042, 1047, 1055, 1065, 1071, 1079, 1087,
+    1093, 1098, 1103, 1108, 1118, 1127, 1135, 1143, 1160, 1167, 1171, 1176,
+    1182, 1193, 1201, 1209, 1214, 1221, 1227, 1236, 1243, 1248, 1256, 1262,
+    1270, 1277, 1285, 1291, 1301, 1310, 1319, 1325, 1331, 1340, 1345, 1348,
+    1353, 1362, 1381, 1385, 1392, 1397, 1407, 1416, 1422, 1428, 1433, 1441,
+    1448, 1454, 1461, 1465, 1491,
+];
+
+#[cfg(feature = "display_names_ru")]
+static DISPLAY_NAMES_RUS_LANGUAGES: [u16; 187] = [
+    Language::Aar as u16,
+    Language::Abk as u16,
+    Language::Afr as u16,
+    Language::Aka as u16,
+    Language::Amh as u16,
+    Language::Ara as u16,
+    Language::Arg as u16,
+    Language::Asm as u16,
+    Language::Ava as u16,
+    Language::Ave as u16,
+    Language::Aym as u16,
+    Language::Aze as u16,
+    Language::Bak as u16,
+    Language::Bam as u16,
+    Language::Bel as u16,
+    Language::Ben as u16,
+    Language::Bis as u16,
+    Language::Bod as u16,
+    Language::Bos as u16,
+    Language::Bre as u16,
+    Language::Bul as u16,
+    Language::Cat as u16,
+    Language::Ces as u16,
+    Language::Cha as u16,
+    Language::Che as u16,
+    Language::Chu as u16,
+    Language::Chv as u16,
+    Language::Cor as u16,
+    Language::Cos as u16,
+    Language::Cre as u16,
+    Language::Cym as u16,
+    Language::Dan as u16,
+    Language::Deu as u16,
+    Language::Div as u16,
+    Language::Dzo as u16,
+    Language::Ell as u16,
+    Language::Eng as u16,
+    Language::Epo as u16,
+    Language::Est as u16,
+    Language::Eus as u16,
+    Language::Ewe as u16,
+    Language::Fao as u16,
+    Language::Fas as u16,
+    Language::Fij as u16,
+    Language::Fin as u16,
+    Language::Fra as u16,
+    Language::Fry as u16,
+    Language::Ful as u16,
+    Language::Gla as u16,
+    Language::Gle as u16,
+    Language::Glg as u16,
+    Language::Glv as u16,
+    Language::Grn as u16,
+    Language::Guj as u16,
+    Language::Hat as u16,
+    Language::Hau as u16,
+    Language::Hbs as u16,
+    Language::Heb as u16,
+    Language::Her as u16,
+    Language::Hin as u16,
+    Language::Hmo as u16,
+    Language::Hrv as u16,
+    Language::Hun as u16,
+    Language::Hye as u16,
+    Language::Ibo as u16,
+    Language::Ido as u16,
+    Language::Iii as u16,
+    Language::Iku as u16,
+    Language::Ile as u16,
+    Language::Ina as u16,
+    Language::Ind as u16,
+    Language::Ipk as u16,
+    Language::Isl as u16,
+    Language::Ita as u16,
+    Language::Jav as u16,
+    Language::Jpn as u16,
+    Language::Kal as u16,
+    Language::Kan as u16,
+    Language::Kas as u16,
+    Language::Kat as u16,
+    Language::Kau as u16,
+    Language::Kaz as u16,
+    Language::Khm as u16,
+    Language::Kik as u16,
+    Language::Kin as u16,
+    Language::Kir as u16,
+    Language::Kom as u16,
+    Language::Kon as u16,
+    Language::Kor as u16,
+    Language::Kua as u16,
+    Language::Kur as u16,
+    Language::Lao as u16,
+    Language::Lat as u16,
+    Language::Lav as u16,
+    Language::Lim as u16,
+    Language::Lin as u16,
+    Language::Lit as u16,
+    Language::Ltz as u16,
+    Language::Lub as u16,
+    Language::Lug as u16,
+    Language::Mah as u16,
+    Language::Mal as u16,
+    Language::Mar as u16,
+    Language::Mkd as u16,
+    Language::Mlg as u16,
+    Language::Mlt as u16,
+    Language::Mon as u16,
+    Language::Mri as u16,
+    Language::Msa as u16,
+    Language::Mul as u16,
+    Language::Mya as u16,
+    Language::Nau as u16,
+    Language::Nav as u16,
+    Language::Nbl as u16,
+    Language::Nde as u16,
+    Language::Ndo as u16,
+    Language::Nep as u16,
+    Language::Nld as u16,
+    Language::Nno as u16,
+    Language::Nob as u16,
+    Language::Nor as u16,
+    Language::Nya as u16,
+    Language::Oci as u16,
+    Language::Oji as u16,
+    Language::Ori as u16,
+    Language::Orm as u16,
+    Language::Oss as u16,
+    Language::Pan as u16,
+    Language::Pli as u16,
+    Language::Pol as u16,
+    Language::Por as u16,
+    Language::Pus as u16,
+    Language::Que as u16,
+    Language::Roh as u16,
+    Language::Ron as u16,
+    Language::Run as u16,
+    Language::Rus as u16,
+    Language::Sag as u16,
+    Language::San as u16,
+    Language::Sin as u16,
+    Language::Slk as u16,
+    Language::Slv as u16,
+    Language::Sme as u16,
+    Language::Smo as u16,
+    Language::Sna as u16,
+    Language::Snd as u16,
+    Language::Som as u16,
+    Language::Sot as u16,
+    Language::Spa as u16,
+    Language::Sqi as u16,
+    Language::Srd as u16,
+    Language::Srp as u16,
+    Language::Ssw as u16,
+    Language::Sun as u16,
+    Language::Swa as u16,
+    Language::Swe as u16,
+    Language::Tah as u16,
+    Language::Tam as u16,
+    Language::Tat as u16,
+    Language::Tel as u16,
+    Language::Tgk as u16,
+    Language::Tgl as u16,
+    Language::Tha as u16,
+    Language::Tir as u16,
+    Language::Ton as u16,
+    Language::Tsn as u16,
+    Language::Tso as u16,
+    Language::Tuk as u16,
+    Language::Tur as u16,
+    Language::Twi as u16,
+    Language::Uig as u16,
+    Language::Ukr as u16,
+    Language::Und as u16,
+    Language::Urd as u16,
+    Language::Uzb as u16,
+    Language::Ven as u16,
+    Language::Vie as u16,
+    Language::Vol as u16,
+    Language::Wln as u16,
+    Language::Wol as u16,
+    Language::Xho as u16,
+    Language::Yid as u16,
+    Language::Yor as u16,
+    Language::Zha as u16,
+    Language::Zho as u16,
+    Language::Zul as u16,
+    Language::Zxx as u16,
+];
+
+#[cfg(feature = "display_names_ru")]
+pub(crate) static DISPLAY_NAMES_RUS: &str = concat!(
+    "афарский",
+    "абхазский",
+    "африкаанс",
+    "акан",
+    "амхарский",
+    "арабский",
+    "арагонский",
+    "ассамский",
+    "аварский",
+    "авестийский",
+    "аймара",
+    "азербайджанский",
+    "башкирский",
+    "бамбара",
+    "белорусский",
+    "бенгальский",
+    "бислама",
+    "тибетский",
+    "боснийский",
+    "бретонский",
+    "болгарский",
+    "каталанский",
+    "чешский",
+    "чаморро",
+    "чеченский",
+    "церковнославянский",
+    "чувашский",
+    "корнский",
+    "корсиканский",
+    "кри",
+    "валлийский",
+    "датский",
+    "немецкий",
+    "мальдивский",
+    "дзонг-кэ",
+    "греческий",
+    "английский",
+    "эсперанто",
+    "эстонский",
+    "баскский",
+    "эве",
+    "фарерский",
+    "персидский",
+    "фиджи",
+    "финский",
+    "французский",
+    "западнофризский",
+    "фулах",
+    "гэльский",
+    "ирландский",
+    "галисийский",
+    "мэнский",
+    "гуарани",
+    "гуджарати",
+    "гаитянский",
+    "хауса",
+    "сербскохорватский",
+    "иврит",
+    "гереро",
+    "хинди",
+    "хиримоту",
+    "хорватский",
+    "венгерский",
+    "армянский",
+    "игбо",
+    "идо",
+    "носу",
+    "инуктитут",
+    "интерлингве",
+    "интерлингва",
+    "индонезийский",
+    "инупиак",
+    "исландский",
+    "итальянский",
+    "яванский",
+    "японский",
+    "гренландский",
+    "каннада",
+    "кашмири",
+    "грузинский",
+    "канури",
+    "казахский",
+    "кхмерский",
+    "кикуйю",
+    "киньяруанда",
+    "киргизский",
+    "коми",
+    "конго",
+    "корейский",
+    "кунама",
+    "курдский",
+    "лаосский",
+    "латинский",
+    "латышский",
+    "лимбургский",
+    "лингала",
+    "литовский",
+    "люксембургский",
+    "луба-катанга",
+    "ганда",
+    "маршалльский",
+    "малаялам",
+    "маратхи",
+    "македонский",
+    "малагасийский",
+    "мальтийский",
+    "монгольский",
+    "маори",
+    "малайский",
+    "языки разных семей",
+    "бирманский",
+    "науру",
+    "навахо",
+    "южный ндебеле",
+    "северный ндебеле",
+    "ндонга",
+    "непальский",
+    "нидерландский",
+    "нюнорск",
+    "норвежский букмол",
+    "норвежский",
+    "ньянджа",
+    "окситанский",
+    "оджибва",
+    "ория",
+    "оромо",
+    "осетинский",
+    "панджаби",
+    "пали",
+    "польский",
+    "португальский",
+    "пушту",
+    "кечуа",
+    "романшский",
+    "румынский",
+    "рунди",
+    "русский",
+    "санго",
+    "санскрит",
+    "сингальский",
+    "словацкий",
+    "словенский",
+    "северносаамский",
+    "самоанский",
+    "шона",
+    "синдхи",
+    "сомали",
+    "южный сото",
+    "испанский",
+    "албанский",
+    "сардинский",
+    "сербский",
+    "свази",
+    "сунданский",
+    "суахили",
+    "шведский",
+    "таитянский",
+    "тамильский",
+    "татарский",
+    "телугу",
+    "таджикский",
+    "тагалог",
+    "тайский",
+    "тигринья",
+    "тонганский",
+    "тсвана",
+    "тсонга",
+    "туркменский",
+    "турецкий",
+    "тви",
+    "уйгурский",
+    "украинский",
+    "неизвестный язык",
+    "урду",
+    "узбекский",
+    "венда",
+    "вьетнамский",
+    "волапюк",
+    "валлонский",
+    "волоф",
+    "коса",
+    "идиш",
+    "йоруба",
+    "чжуань",
+    "китайский",
+    "зулу",
+    "нет языкового материала",
+);
+
+#[cfg(feature = "display_names_ru")]
+pub(crate) static DISPLAY_NAMES_RUS_OFFSETS: [u16; 188] = [
+    0, 16, 34, 52, 60, 78, 94, 114, 132, 148, 170, 182, 212, 232, 246, 268,
+    290, 304, 322, 342, 362, 382, 404, 418, 432, 450, 486, 504, 520, 544, 550,
+    570, 584, 600, 622, 637, 655, 675, 693, 711, 727, 733, 751, 771, 781, 795,
+    817, 847, 857, 873, 893, 915, 929, 943, 961, 981, 991, 1025, 1035, 1047,
+    1057, 1073, 1093, 1113, 1131, 1139, 1145, 1153, 1171, 1193, 1215, 1241,
+    1255, 1275, 1297, 1313, 1329, 1353, 1367, 1381, 1401, 1413, 1431, 1449,
+    1461, 1483, 1503, 1511, 1521, 1539, 1551, 1567, 1583, 1601, 1619, 1641,
+    1655, 1673, 1701, 1724, 1734, 1758, 1774, 1788, 1810, 1836, 1858, 1880,
+    1890, 1908, 1942, 1962, 1972, 1984, 2009, 2040, 2052, 2072, 2098, 2112,
+    2145, 2165, 2179, 2201, 2215, 2223, 2233, 2253, 2269, 2277, 2293, 2319,
+    2329, 2339, 2359, 2377, 2387, 2401, 2411, 2427, 2449, 2467, 2487, 2517,
+    2537, 2545, 2557, 2569, 2588, 2606, 2624, 2644, 2660, 2670, 2690, 2704,
+    2720, 2740, 2760, 2778, 2790, 2810, 2824, 2838, 2854, 2874, 2886, 2898,
+    2920, 2936, 2942, 2960, 2980, 3011, 3019, 3037, 3047, 3069, 3083, 3103,
+    3113, 3121, 3129, 3141, 3153, 3171, 3179, 3223,
+];
+
+#[cfg(feature = "display_names_zh")]
+static DISPLAY_NAMES_ZHO_LANGUAGES: [u16; 187] = [
+    Language::Aar as u16,
+    Language::Abk as u16,
+    Language::Afr as u16,
+    Language::Aka as u16,
+    Language::Amh as u16,
+    Language::Ara as u16,
+    Language::Arg as u16,
+    Language::Asm as u16,
+    Language::Ava as u16,
+    Language::Ave as u16,
+    Language::Aym as u16,
+    Language::Aze as u16,
+    Language::Bak as u16,
+    Language::Bam as u16,
+    Language::Bel as u16,
+    Language::Ben as u16,
+    Language::Bis as u16,
+    Language::Bod as u16,
+    Language::Bos as u16,
+    Language::Bre as u16,
+    Language::Bul as u16,
+    Language::Cat as u16,
+    Language::Ces as u16,
+    Language::Cha as u16,
+    Language::Che as u16,
+    Language::Chu as u16,
+    Language::Chv as u16,
+    Language::Cor as u16,
+    Language::Cos as u16,
+    Language::Cre as u16,
+    Language::Cym as u16,
+    Language::Dan as u16,
+    Language::Deu as u16,
+    Language::Div as u16,
+    Language::Dzo as u16,
+    Language::Ell as u16,
+    Language::Eng as u16,
+    Language::Epo as u16,
+    Language::Est as u16,
+    Language::Eus as u16,
+    Language::Ewe as u16,
+    Language::Fao as u16,
+    Language::Fas as u16,
+    Language::Fij as u16,
+    Language::Fin as u16,
+    Language::Fra as u16,
+    Language::Fry as u16,
+    Language::Ful as u16,
+    Language::Gla as u16,
+    Language::Gle as u16,
+    Language::Glg as u16,
+    Language::Glv as u16,
+    Language::Grn as u16,
+    Language::Guj as u16,
+    Language::Hat as u16,
+    Language::Hau as u16,
+    Language::Hbs as u16,
+    Language::Heb as u16,
+    Language::Her as u16,
+    Language::Hin as u16,
+    Language::Hmo as u16,
+    Language::Hrv as u16,
+    Language::Hun as u16,
+    Language::Hye as u16,
+    Language::Ibo as u16,
+    Language::Ido as u16,
+    Language::Iii as u16,
+    Language::Iku as u16,
+    Language::Ile as u16,
+    Language::Ina as u16,
+    Language::Ind as u16,
+    Language::Ipk as u16,
+    Language::Isl as u16,
+    Language::Ita as u16,
+    Language::Jav as u16,
+    Language::Jpn as u16,
+    Language::Kal as u16,
+    Language::Kan as u16,
+    Language::Kas as u16,
+    Language::Kat as u16,
+    Language::Kau as u16,
+    Language::Kaz as u16,
+    Language::Khm as u16,
+    Language::Kik as u16,
+    Language::Kin as u16,
+    Language::Kir as u16,
+    Language::Kom as u16,
+    Language::Kon as u16,
+    Language::Kor as u16,
+    Language::Kua as u16,
+    Language::Kur as u16,
+    Language::Lao as u16,
+    Language::Lat as u16,
+    Language::Lav as u16,
+    Language::Lim as u16,
+    Language::Lin as u16,
+    Language::Lit as u16,
+    Language::Ltz as u16,
+    Language::Lub as u16,
+    Language::Lug as u16,
+    Language::Mah as u16,
+    Language::Mal as u16,
+    Language::Mar as u16,
+    Language::Mkd as u16,
+    Language::Mlg as u16,
+    Language::Mlt as u16,
+    Language::Mon as u16,
+    Language::Mri as u16,
+    Language::Msa as u16,
+    Language::Mul as u16,
+    Language::Mya as u16,
+    Language::Nau as u16,
+    Language::Nav as u16,
+    Language::Nbl as u16,
+    Language::Nde as u16,
+    Language::Ndo as u16,
+    Language::Nep as u16,
+    Language::Nld as u16,
+    Language::Nno as u16,
+    Language::Nob as u16,
+    Language::Nor as u16,
+    Language::Nya as u16,
+    Language::Oci as u16,
+    Language::Oji as u16,
+    Language::Ori as u16,
+    Language::Orm as u16,
+    Language::Oss as u16,
+    Language::Pan as u16,
+    Language::Pli as u16,
+    Language::Pol as u16,
+    Language::Por as u16,
+    Language::Pus as u16,
+    Language::Que as u16,
+    Language::Roh as u16,
+    Language::Ron as u16,
+    Language::Run as u16,
+    Language::Rus as u16,
+    Language::Sag as u16,
+    Language::San as u16,
+    Language::Sin as u16,
+    Language::Slk as u16,
+    Language::Slv as u16,
+    Language::Sme as u16,
+    Language::Smo as u16,
+    Language::Sna as u16,
+    Language::Snd as u16,
+    Language::Som as u16,
+    Language::Sot as u16,
+    Language::Spa as u16,
+    Language::Sqi as u16,
+    Language::Srd as u16,
+    Language::Srp as u16,
+    Language::Ssw as u16,
+    Language::Sun as u16,
+    Language::Swa as u16,
+    Language::Swe as u16,
+    Language::Tah as u16,
+    Language::Tam as u16,
+    Language::Tat as u16,
+    Language::Tel as u16,
+    Language::Tgk as u16,
+    Language::Tgl as u16,
+    Language::Tha as u16,
+    Language::Tir as u16,
+    Language::Ton as u16,
+    Language::Tsn as u16,
+    Language::Tso as u16,
+    Language::Tuk as u16,
+    Language::Tur as u16,
+    Language::Twi as u16,
+    Language::Uig as u16,
+    Language::Ukr as u16,
+    Language::Und as u16,
+    Language::Urd as u16,
+    Language::Uzb as u16,
+    Language::Ven as u16,
+    Language::Vie as u16,
+    Language::Vol as u16,
+    Language::Wln as u16,
+    Language::Wol as u16,
+    Language::Xho as u16,
+    Language::Yid as u16,
+    Language::Yor as u16,
+    Language::Zha as u16,
+    Language::Zho as u16,
+    Language::Zul as u16,
+    Language::Zxx as u16,
+];
+
+#[cfg(feature = "display_names_zh")]
+pub(crate) static DISPLAY_NAMES_ZHO: &str = concat!(
+    "阿法尔语",
+    "阿布哈西亚语",
+    "南非荷兰语",
+    "阿肯语",
+    "阿姆哈拉语",
+    "阿拉伯语",
+    "阿拉贡语",
+    "阿萨姆语",
+    "阿瓦尔语",
+    "阿维斯塔语",
+    "艾马拉语",
+    "阿塞拜疆语",
+    "巴什基尔语",
+    "班巴拉语",
+    "白俄罗斯语",
+    "孟加拉语",
+    "比斯拉马语",
+    "藏语",
+    "波斯尼亚语",
+    "布列塔尼语",
+    "保加利亚语",
+    "加泰罗尼亚语",
+    "捷克语",
+    "查莫罗语",
+    "车臣语",
+    "教会斯拉夫语",
+    "楚瓦什语",
+    "康沃尔语",
+    "科西嘉语",
+    "克里语",
+    "威尔士语",
+    "丹麦语",
+    "德语",
+    "迪维希语",
+    "宗卡语",
+    "希腊语",
+    "英语",
+    "世界语",
+    "爱沙尼亚语",
+    "巴斯克语",
+    "埃维语",
+    "法罗语",
+    "波斯语",
+    "斐济语",
+    "芬兰语",
+    "法语",
+    "西弗里西亚语",
+    "富拉语",
+    "苏格兰盖尔语",
+    "爱尔兰语",
+    "加利西亚语",
+    "马恩语",
+    "瓜拉尼语",
+    "古吉拉特语",
+    "海地克里奥尔语",
+    "豪萨语",
+    "塞尔维亚-克罗地亚语",
+    "希伯来语",
+    "赫雷罗语",
+    "印地语",
+    "希里莫图语",
+    "克罗地亚语",
+    "匈牙利语",
+    "亚美尼亚语",
+    "伊博语",
+    "伊多语",
+    "凉山彝语",
+    "因纽特语",
+    "国际文字（E）",
+    "国际语",
+    "印度尼西亚语",
+    "伊努皮克语",
+    "冰岛语",
+    "意大利语",
+    "爪哇语",
+    "日语",
+    "格陵兰语",
+    "卡纳达语",
+    "克什米尔语",
+    "格鲁吉亚语",
+    "卡努里语",
+    "哈萨克语",
+    "高棉语",
+    "吉库尤语",
+    "卢旺达语",
+    "柯尔克孜语",
+    "科米语",
+    "刚果语",
+    "韩语",
+    "宽亚玛语",
+    "库尔德语",
+    "老挝语",
+    "拉丁语",
+    "拉脱维亚语",
+    "林堡语",
+    "林加拉语",
+    "立陶宛语",
+    "卢森堡语",
+    "鲁巴加丹加语",
+    "卢干达语",
+    "马绍尔语",
+    "马拉雅拉姆语",
+    "马拉地语",
+    "马其顿语",
+    "马拉加斯语",
+    "马耳他语",
+    "蒙古语",
+    "毛利语",
+    "马来语",
+    "多语种",
+    "缅甸语",
+    "瑙鲁语",
+    "纳瓦霍语",
+    "南恩德贝勒语",
+    "北恩德贝勒语",
+    "恩东加语",
+    "尼泊尔语",
+    "荷兰语",
+    "挪威尼诺斯克语",
+    "书面挪威语",
+    "挪威语",
+    "齐切瓦语",
+    "奥克语",
+    "奥吉布瓦语",
+    "奥里亚语",
+    "奥罗莫语",
+    "奥塞梯语",
+    "旁遮普语",
+    "巴利语",
+    "波兰语",
+    "葡萄牙语",
+    "普什图语",
+    "克丘亚语",
+    "罗曼什语",
+    "罗马尼亚语",
+    "隆迪语",
+    "俄语",
+    "桑戈语",
+    "梵语",
+    "僧伽罗语",
+    "斯洛伐克语",
+    "斯洛文尼亚语",
+    "北方萨米语",
+    "萨摩亚语",
+    "绍纳语",
+    "信德语",
+    "索马里语",
+    "南索托语",
+    "西班牙语",
+    "阿尔巴尼亚语",
+    "萨丁语",
+    "塞尔维亚语",
+    "斯瓦蒂语",
+    "巽他语",
+    "斯瓦希里语",
+    "瑞典语",
+    "塔希提语",
+    "泰米尔语",
+    "鞑靼语",
+    "泰卢固语",
+    "塔吉克语",
+    "他加禄语",
+    "泰语",
+    "提格利尼亚语",
+    "汤加语",
+    "茨瓦纳语",
+    "聪加语",
+    "土库曼语",
+    "土耳其语",
+    "契维语",
+    "维吾尔语",
+    "乌克兰语",
+    "未知语言",
+    "乌尔都语",
+    "乌兹别克语",
+    "文达语",
+    "越南语",
+    "沃拉普克语",
+    "瓦隆语",
+    "沃洛夫语",
+    "科萨语",
+    "意第绪语",
+    "约鲁巴语",
+    "壮语",
+    "中文",
+    "祖鲁语",
+    "无语言内容",
+);
+
+#[cfg(feature = "display_names_zh")]
+pub(crate) static DISPLAY_NAMES_ZHO_OFFSETS: [u16; 188] = [
+    0, 12, 30, 45, 54, 69, 81, 93, 105, 117, 132, 144, 159, 174, 186, 201, 213,
+    228, 234, 249, 264, 279, 297, 306, 318, 327, 345, 357, 369, 381, 390, 402,
+    411, 417, 429, 438, 447, 453, 462, 477, 489, 498, 507, 516, 525, 534, 540,
+    558, 567, 585, 597, 612, 621, 633, 648, 669, 678, 706, 718, 730, 739, 754,
+    769, 781, 796, 805, 814, 826, 838, 857, 866, 884, 899, 908, 920, 929, 935,
+    947, 959, 974, 989, 1001, 1013, 1022, 1034, 1046, 1061, 1070, 1079, 1085,
+    1097, 1109, 1118, 1127, 1142, 1151, 1163, 1175, 1187, 1205, 1217, 1229,
+    1247, 1259, 1271, 1286, 1298, 1307, 1316, 1325, 1334, 1343, 1352, 1364,
+    1382, 1400, 1412, 1424, 1433, 1454, 1469, 1478, 1490, 1499, 1514, 1526,
+    1538, 1550, 1562, 1571, 1580, 1592, 1604, 1616, 1628, 1643, 1652, 1658,
+    1667, 1673, 1685, 1700, 1718, 1733, 1745, 1754, 1763, 1775, 1787, 1799,
+    1817, 1826, 1841, 1853, 1862, 1877, 1886, 1898, 1910, 1919, 1931, 1943,
+    1955, 1961, 1979, 1988, 2000, 2009, 2021, 2033, 2042, 2054, 2066, 2078,
+    2090, 2105, 2114, 2123, 2138, 2147, 2159, 2168, 2180, 2192, 2198, 2204,
+    2213, 2228,
+];
+
+#[cfg(feature = "display_names")]
+pub(crate) fn display_names(
+    lang: Language,
+) -> (&'static [u16], &'static [u16], &'static str) {
+    match lang {
+        #[cfg(feature = "display_names_de")]
+        Language::Deu => (
+            &DISPLAY_NAMES_DEU_LANGUAGES,
+            &DISPLAY_NAMES_DEU_OFFSETS,
+            DISPLAY_NAMES_DEU,
+        ),
+        #[cfg(feature = "display_names_en")]
+        Language::Eng => (
+            &DISPLAY_NAMES_ENG_LANGUAGES,
+            &DISPLAY_NAMES_ENG_OFFSETS,
+            DISPLAY_NAMES_ENG,
+        ),
+        #[cfg(feature = "display_names_es")]
+        Language::Spa => (
+            &DISPLAY_NAMES_SPA_LANGUAGES,
+            &DISPLAY_NAMES_SPA_OFFSETS,
+            DISPLAY_NAMES_SPA,
+        ),
+        #[cfg(feature = "display_names_fr")]
+        Language::Fra => (
+            &DISPLAY_NAMES_FRA_LANGUAGES,
+            &DISPLAY_NAMES_FRA_OFFSETS,
+            DISPLAY_NAMES_FRA,
+        ),
+        #[cfg(feature = "display_names_it")]
+        Language::Ita => (
+            &DISPLAY_NAMES_ITA_LANGUAGES,
+            &DISPLAY_NAMES_ITA_OFFSETS,
+            DISPLAY_NAMES_ITA,
+        ),
+        #[cfg(feature = "display_names_ja")]
+        Language::Jpn => (
+            &DISPLAY_NAMES_JPN_LANGUAGES,
+            &DISPLAY_NAMES_JPN_OFFSETS,
+            DISPLAY_NAMES_JPN,
+        ),
+        #[cfg(feature = "display_names_pt")]
+        Language::Por => (
+            &DISPLAY_NAMES_POR_LANGUAGES,
+            &DISPLAY_NAMES_POR_OFFSETS,
+            DISPLAY_NAMES_POR,
+        ),
+        #[cfg(feature = "display_names_ru")]
+        Language::Rus => (
+            &DISPLAY_NAMES_RUS_LANGUAGES,
+            &DISPLAY_NAMES_RUS_OFFSETS,
+            DISPLAY_NAMES_RUS,
+        ),
+        #[cfg(feature = "display_names_zh")]
+        Language::Zho => (
+            &DISPLAY_NAMES_ZHO_LANGUAGES,
+            &DISPLAY_NAMES_ZHO_OFFSETS,
+            DISPLAY_NAMES_ZHO,
+        ),
+        _ => (&[], &[0], ""),
+    }
+}
@@ -0,0 +1,5601 @@
+/// This file is generated and should not be edited directly.
+use super::{LanguageData, Scope};
+
+/// Number of languages, i.e. variants of the `Language` enum
+pub(crate) const LANGUAGE_COUNT: usize = 178;
+
+#[allow(clippy::type_complexity)]
+pub(crate) static OVERVIEW: [LanguageData; LANGUAGE_COUNT] = [
+    LanguageData { code_3: [97, 97, 114], code_1: Some([97, 97]) },
+    LanguageData { code_3: [97, 98, 107], code_1: Some([97, 98]) },
+    LanguageData { code_3: [97, 102, 114], code_1: Some([97, 102]) },
+    LanguageData { code_3: [97, 107, 97], code_1: Some([97, 107]) },
+    LanguageData { code_3: [97, 109, 104], code_1: Some([97, 109]) },
+    LanguageData { code_3: [97, 114, 97], code_1: Some([97, 114]) },
+    LanguageData { code_3: [97, 114, 103], code_1: Some([97, 110]) },
+    LanguageData { code_3: [97, 115, 109], code_1: Some([97, 115]) },
+    LanguageData { code_3: [97, 118, 97], code_1: Some([97, 118]) },
+    LanguageData { code_3: [97, 121, 109], code_1: Some([97, 121]) },
+    LanguageData { code_3: [97, 122, 101], code_1: Some([97, 122]) },
+    LanguageData { code_3: [98, 97, 107], code_1: Some([98, 97]) },
+    LanguageData { code_3: [98, 97, 109], code_1: Some([98, 109]) },
+    LanguageData { code_3: [98, 101, 108], code_1: Some([98, 101]) },
+    LanguageData { code_3: [98, 101, 110], code_1: Some([98, 110]) },
+    LanguageData { code_3: [98, 105, 115], code_1: Some([98, 105]) },
+    LanguageData { code_3: [98, 111, 100], code_1: Some([98, 111]) },
+    LanguageData { code_3: [98, 111, 115], code_1: Some([98, 115]) },
+    LanguageData { code_3: [98, 114, 101], code_1: Some([98, 114]) },
+    LanguageData { code_3: [98, 117, 108], code_1: Some([98, 103]) },
+    LanguageData { code_3: [99, 97, 116], code_1: Some([99, 97]) },
+    LanguageData { code_3: [99, 101, 115], code_1: Some([99, 115]) },
+    LanguageData { code_3: [99, 104, 97], code_1: Some([99, 104]) },
+    LanguageData { code_3: [99, 104, 101], code_1: Some([99, 101]) },
+    LanguageData { code_3: [99, 104, 118], code_1: Some([99, 118]) },
+    LanguageData { code_3: [99, 111, 114], code_1: Some([107, 119]) },
+    LanguageData { code_3: [99, 111, 115], code_1: Some([99, 111]) },
+    LanguageData { code_3: [99, 114, 101], code_1: Some([99, 114]) },
+    LanguageData { code_3: [99, 121, 109], code_1: Some([99, 121]) },
+    LanguageData { code_3: [100, 97, 110], code_1: Some([100, 97]) },
+    LanguageData { code_3: [100, 101, 117], code_1: Some([100, 101]) },
+    LanguageData { code_3: [100, 105, 118], code_1: Some([100, 118]) },
+    LanguageData { code_3: [100, 122, 111], code_1: Some([100, 122]) },
+    LanguageData { code_3: [101, 108, 108], code_1: Some([101, 108]) },
+    LanguageData { code_3: [101, 110, 103], code_1: Some([101, 110]) },
+    LanguageData { code_3: [101, 115, 116], code_1: Some([101, 116]) },
+    LanguageData { code_3: [101, 117, 115], code_1: Some([101, 117]) },
+    LanguageData { code_3: [101, 119, 101], code_1: Some([101, 101]) },
+    LanguageData { code_3: [102, 97, 111], code_1: Some([102, 111]) },
+    LanguageData { code_3: [102, 97, 115], code_1: Some([102, 97]) },
+    LanguageData { code_3: [102, 105, 106], code_1: Some([102, 106]) },
+    LanguageData { code_3: [102, 105, 110], code_1: Some([102, 105]) },
+    LanguageData { code_3: [102, 114, 97], code_1: Some([102, 114]) },
+    LanguageData { code_3: [102, 114, 121], code_1: Some([102, 121]) },
+    LanguageData { code_3: [102, 117, 108], code_1: Some([102, 102]) },
+    LanguageData { code_3: [103, 108, 97], code_1: Some([103, 100]) },
+    LanguageData { code_3: [103, 108, 101], code_1: Some([103, 97]) },
+    LanguageData { code_3: [103, 108, 103], code_1: Some([103, 108]) },
+    LanguageData { code_3: [103, 108, 118], code_1: Some([103, 118]) },
+    LanguageData { code_3: [103, 114, 110], code_1: Some([103, 110]) },
+    LanguageData { code_3: [103, 117, 106], code_1: Some([103, 117]) },
+    LanguageData { code_3: [104, 97, 116], code_1: Some([104, 116]) },
+    LanguageData { code_3: [104, 97, 117], code_1: Some([104, 97]) },
+    LanguageData { code_3: [104, 98, 115], code_1: Some([115, 104]) },
+    LanguageData { code_3: [104, 101, 98], code_1: Some([104, 101]) },
+    LanguageData { code_3: [104, 101, 114], code_1: Some([104, 122]) },
+    LanguageData { code_3: [104, 105, 110], code_1: Some([104, 105]) },
+    LanguageData { code_3: [104, 109, 111], code_1: Some([104, 111]) },
+    LanguageData { code_3: [104, 114, 118], code_1: Some([104, 114]) },
+    LanguageData { code_3: [104, 117, 110], code_1: Some([104, 117]) },
+    LanguageData { code_3: [104, 121, 101], code_1: Some([104, 121]) },
+    LanguageData { code_3: [105, 98, 111], code_1: Some([105, 103]) },
+    LanguageData { code_3: [105, 105, 105], code_1: Some([105, 105]) },
+    LanguageData { code_3: [105, 107, 117], code_1: Some([105, 117]) },
+    LanguageData { code_3: [105, 110, 100], code_1: Some([105, 100]) },
+    LanguageData { code_3: [105, 112, 107], code_1: Some([105, 107]) },
+    LanguageData { code_3: [105, 115, 108], code_1: Some([105, 115]) },
+    LanguageData { code_3: [105, 116, 97], code_1: Some([105, 116]) },
+    LanguageData { code_3: [106, 97, 118], code_1: Some([106, 118]) },
+    LanguageData { code_3: [106, 112, 110], code_1: Some([106, 97]) },
+    LanguageData { code_3: [107, 97, 108], code_1: Some([107, 108]) },
+    LanguageData { code_3: [107, 97, 110], code_1: Some([107, 110]) },
+    LanguageData { code_3: [107, 97, 115], code_1: Some([107, 115]) },
+    LanguageData { code_3: [107, 97, 116], code_1: Some([107, 97]) },
+    LanguageData { code_3: [107, 97, 117], code_1: Some([107, 114]) },
+    LanguageData { code_3: [107, 97, 122], code_1: Some([107, 107]) },
+    LanguageData { code_3: [107, 104, 109], code_1: Some([107, 109]) },
+    LanguageData { code_3: [107, 105, 107], code_1: Some([107, 105]) },
+    LanguageData { code_3: [107, 105, 110], code_1: Some([114, 119]) },
+    LanguageData { code_3: [107, 105, 114], code_1: Some([107, 121]) },
+    LanguageData { code_3: [107, 111, 109], code_1: Some([107, 118]) },
+    LanguageData { code_3: [107, 111, 110], code_1: Some([107, 103]) },
+    LanguageData { code_3: [107, 111, 114], code_1: Some([107, 111]) },
+    LanguageData { code_3: [107, 117, 97], code_1: Some([107, 106]) },
+    LanguageData { code_3: [107, 117, 114], code_1: Some([107, 117]) },
+    LanguageData { code_3: [108, 97, 111], code_1: Some([108, 111]) },
+    LanguageData { code_3: [108, 97, 118], code_1: Some([108, 118]) },
+    LanguageData { code_3: [108, 105, 109], code_1: Some([108, 105]) },
+    LanguageData { code_3: [108, 105, 110], code_1: Some([108, 110]) },
+    LanguageData { code_3: [108, 105, 116], code_1: Some([108, 116]) },
+    LanguageData { code_3: [108, 116, 122], code_1: Some([108, 98]) },
+    LanguageData { code_3: [108, 117, 98], code_1: Some([108, 117]) },
+    LanguageData { code_3: [108, 117, 103], code_1: Some([108, 103]) },
+    LanguageData { code_3: [109, 97, 104], code_1: Some([109, 104]) },
+    LanguageData { code_3: [109, 97, 108], code_1: Some([109, 108]) },
+    LanguageData { code_3: [109, 97, 114], code_1: Some([109, 114]) },
+    LanguageData { code_3: [109, 105, 115], code_1: None },
+    LanguageData { code_3: [109, 107, 100], code_1: Some([109, 107]) },
+    LanguageData { code_3: [109, 108, 103], code_1: Some([109, 103]) },
+    LanguageData { code_3: [109, 108, 116], code_1: Some([109, 116]) },
+    LanguageData { code_3: [109, 111, 110], code_1: Some([109, 110]) },
+    LanguageData { code_3: [109, 114, 105], code_1: Some([109, 105]) },
+    LanguageData { code_3: [109, 115, 97], code_1: Some([109, 115]) },
+    LanguageData { code_3: [109, 117, 108], code_1: None },
+    LanguageData { code_3: [109, 121, 97], code_1: Some([109, 121]) },
+    LanguageData { code_3: [110, 97, 117], code_1: Some([110, 97]) },
+    LanguageData { code_3: [110, 97, 118], code_1: Some([110, 118]) },
+    LanguageData { code_3: [110, 98, 108], code_1: Some([110, 114]) },
+    LanguageData { code_3: [110, 100, 101], code_1: Some([110, 100]) },
+    LanguageData { code_3: [110, 100, 111], code_1: Some([110, 103]) },
+    LanguageData { code_3: [110, 101, 112], code_1: Some([110, 101]) },
+    LanguageData { code_3: [110, 108, 100], code_1: Some([110, 108]) },
+    LanguageData { code_3: [110, 110, 111], code_1: Some([110, 110]) },
+    LanguageData { code_3: [110, 111, 98], code_1: Some([110, 98]) },
+    LanguageData { code_3: [110, 111, 114], code_1: Some([110, 111]) },
+    LanguageData { code_3: [110, 121, 97], code_1: Some([110, 121]) },
+    LanguageData { code_3: [111, 99, 105], code_1: Some([111, 99]) },
+    LanguageData { code_3: [111, 106, 105], code_1: Some([111, 106]) },
+    LanguageData { code_3: [111, 114, 105], code_1: Some([111, 114]) },
+    LanguageData { code_3: [111, 114, 109], code_1: Some([111, 109]) },
+    LanguageData { code_3: [111, 115, 115], code_1: Some([111, 115]) },
+    LanguageData { code_3: [112, 97, 110], code_1: Some([112, 97]) },
+    LanguageData { code_3: [112, 111, 108], code_1: Some([112, 108]) },
+    LanguageData { code_3: [112, 111, 114], code_1: Some([112, 116]) },
+    LanguageData { code_3: [112, 117, 115], code_1: Some([112, 115]) },
+    LanguageData { code_3: [113, 117, 101], code_1: Some([113, 117]) },
+    LanguageData { code_3: [114, 111, 104], code_1: Some([114, 109]) },
+    LanguageData { code_3: [114, 111, 110], code_1: Some([114, 111]) },
+    LanguageData { code_3: [114, 117, 110], code_1: Some([114, 110]) },
+    LanguageData { code_3: [114, 117, 115], code_1: Some([114, 117]) },
+    LanguageData { code_3: [115, 97, 103], code_1: Some([115, 103]) },
+    LanguageData { code_3: [115, 105, 110], code_1: Some([115, 105]) },
+    LanguageData { code_3: [115, 108, 107], code_1: Some([115, 107]) },
+    LanguageData { code_3: [115, 108, 118], code_1: Some([115, 108]) },
+    LanguageData { code_3: [115, 109, 101], code_1: Some([115, 101]) },
+    LanguageData { code_3: [115, 109, 111], code_1: Some([115, 109]) },
+    LanguageData { code_3: [115, 110, 97], code_1: Some([115, 110]) },
+    LanguageData { code_3: [115, 110, 100], code_1: Some([115, 100]) },
+    LanguageData { code_3: [115, 111, 109], code_1: Some([115, 111]) },
+    LanguageData { code_3: [115, 111, 116], code_1: Some([115, 116]) },
+    LanguageData { code_3: [115, 112, 97], code_1: Some([101, 115]) },
+    LanguageData { code_3: [115, 113, 105], code_1: Some([115, 113]) },
+    LanguageData { code_3: [115, 114, 100], code_1: Some([115, 99]) },
+    LanguageData { code_3: [115, 114, 112], code_1: Some([115, 114]) },
+    LanguageData { code_3: [115, 115, 119], code_1: Some([115, 115]) },
+    LanguageData { code_3: [115, 117, 110], code_1: Some([115, 117]) },
+    LanguageData { code_3: [115, 119, 97], code_1: Some([115, 119]) },
+    LanguageData { code_3: [115, 119, 101], code_1: Some([115, 118]) },
+    LanguageData { code_3: [116, 97, 104], code_1: Some([116, 121]) },
+    LanguageData { code_3: [116, 97, 109], code_1: Some([116, 97]) },
+    LanguageData { code_3: [116, 97, 116], code_1: Some([116, 116]) },
+    LanguageData { code_3: [116, 101, 108], code_1: Some([116, 101]) },
+    LanguageData { code_3: [116, 103, 107], code_1: Some([116, 103]) },
+    LanguageData { code_3: [116, 103, 108], code_1: Some([116, 108]) },
+    LanguageData { code_3: [116, 104, 97], code_1: Some([116, 104]) },
+    LanguageData { code_3: [116, 105, 114], code_1: Some([116, 105]) },
+    LanguageData { code_3: [116, 111, 110], code_1: Some([116, 111]) },
+    LanguageData { code_3: [116, 115, 110], code_1: Some([116, 110]) },
+    LanguageData { code_3: [116, 115, 111], code_1: Some([116, 115]) },
+    LanguageData { code_3: [116, 117, 107], code_1: Some([116, 107]) },
+    LanguageData { code_3: [116, 117, 114], code_1: Some([116, 114]) },
+    LanguageData { code_3: [116, 119, 105], code_1: Some([116, 119]) },
+    LanguageData { code_3: [117, 105, 103], code_1: Some([117, 103]) },
+    LanguageData { code_3: [117, 107, 114], code_1: Some([117, 107]) },
+    LanguageData { code_3: [117, 110, 100], code_1: None },
+    LanguageData { code_3: [117, 114, 100], code_1: Some([117, 114]) },
+    LanguageData { code_3: [117, 122, 98], code_1: Some([117, 122]) },
+    LanguageData { code_3: [118, 101, 110], code_1: Some([118, 101]) },
+    LanguageData { code_3: [118, 105, 101], code_1: Some([118, 105]) },
+    LanguageData { code_3: [119, 108, 110], code_1: Some([119, 97]) },
+    LanguageData { code_3: [119, 111, 108], code_1: Some([119, 111]) },
+    LanguageData { code_3: [120, 104, 111], code_1: Some([120, 104]) },
+    LanguageData { code_3: [121, 105, 100], code_1: Some([121, 105]) },
+    LanguageData { code_3: [121, 111, 114], code_1: Some([121, 111]) },
+    LanguageData { code_3: [122, 104, 97], code_1: Some([122, 97]) },
+    LanguageData { code_3: [122, 104, 111], code_1: Some([122, 104]) },
+    LanguageData { code_3: [122, 117, 108], code_1: Some([122, 117]) },
+    LanguageData { code_3: [122, 120, 120], code_1: None },
+];
+
+#[cfg(feature = "english_names")]
+pub(crate) static NAMES_EN: &str = concat!(
+    "Afar",
+    "Abkhazian",
+    "Afrikaans",
+    "Akan",
+    "Amharic",
+    "Arabic",
+    "Aragonese",
+    "Assamese",
+    "Avaric",
+    "Aymara",
+    "Azerbaijani",
+    "Bashkir",
+    "Bambara",
+    "Belarusian",
+    "Bengali",
+    "Bislama",
+    "Tibetan",
+    "Bosnian",
+    "Breton",
+    "Bulgarian",
+    "Catalan",
+    "Czech",
+    "Chamorro",
+    "Chechen",
+    "Chuvash",
+    "Cornish",
+    "Corsican",
+    "Cree",
+    "Welsh",
+    "Danish",
+    "German",
+    "Dhivehi",
+    "Dzongkha",
+    "Modern Greek",
+    "English",
+    "Estonian",
+    "Basque",
+    "Ewe",
+    "Faroese",
+    "Persian",
+    "Fijian",
+    "Finnish",
+    "French",
+    "Western Frisian",
+    "Fulah",
+    "Scottish Gaelic",
+    "Irish",
+    "Galician",
+    "Manx",
+    "Guarani",
+    "Gujarati",
+    "Haitian",
+    "Hausa",
+    "Serbo-Croatian",
+    "Hebrew",
+    "Herero",
+    "Hindi",
+    "Hiri Motu",
+    "Croatian",
+    "Hungarian",
+    "Armenian",
+    "Igbo",
+    "Sichuan Yi",
+    "Inuktitut",
+    "Indonesian",
+    "Inupiaq",
+    "Icelandic",
+    "Italian",
+    "Javanese",
+    "Japanese",
+    "Kalaallisut",
+    "Kannada",
+    "Kashmiri",
+    "Georgian",
+    "Kanuri",
+    "Kazakh",
+    "Khmer",
+    "Kikuyu",
+    "Kinyarwanda",
+    "Kirghiz",
+    "Komi",
+    "Kongo",
+    "Korean",
+    "Kuanyama",
+    "Kurdish",
+    "Lao",
+    "Latvian",
+    "Limburgan",
+    "Lingala",
+    "Lithuanian",
+    "Luxembourgish",
+    "Luba-Katanga",
+    "Ganda",
+    "Marshallese",
+    "Malayalam",
+    "Marathi",
+    "Uncoded languages",
+    "Macedonian",
+    "Malagasy",
+    "Maltese",
+    "Mongolian",
+    "Maori",
+    "Malay",
+    "Multiple languages",
+    "Burmese",
+    "Nauru",
+    "Navajo",
+    "South Ndebele",
+    "North Ndebele",
+    "Ndonga",
+    "Nepali",
+    "Dutch",
+    "Norwegian Nynorsk",
+    "Norwegian Bokmål",
+    "Norwegian",
+    "Chichewa",
+    "Occitan",
+    "Ojibwa",
+    "Oriya",
+    "Oromo",
+    "Iron Ossetic",
+    "Panjabi",
+    "Polish",
+    "Portuguese",
+    "Pushto",
+    "Quechua",
+    "Romansh",
+    "Romanian",
+    "Rundi",
+    "Russian",
+    "Sango",
+    "Sinhala",
+    "Slovak",
+    "Slovenian",
+    "Northern Sami",
+    "Samoan",
+    "Shona",
+    "Sindhi",
+    "Somali",
+    "Southern Sotho",
+    "Spanish",
+    "Albanian",
+    "Sardinian",
+    "Serbian",
+    "Swati",
+    "Sundanese",
+    "Swahili",
+    "Swedish",
+    "Tahitian",
+    "Tamil",
+    "Tatar",
+    "Telugu",
+    "Tajik",
+    "Tagalog",
+    "Thai",
+    "Tigrinya",
+    "Tonga",
+    "Tswana",
+    "Tsonga",
+    "Turkmen",
+    "Turkish",
+    "Twi",
+    "Uighur",
+    "Ukrainian",
+    "Undetermined",
+    "Urdu",
+    "Uzbek",
+    "Venda",
+    "Vietnamese",
+    "Walloon",
+    "Wolof",
+    "Xhosa",
+    "Yiddish",
+    "Yoruba",
+    "Zhuang",
+    "Chinese",
+    "Zulu",
+    "No linguistic content",
+);
+
+#[cfg(feature = "english_names")]
+pub(crate) static NAMES_EN_OFFSETS: [u32; LANGUAGE_COUNT + 1] = [
+    0, 4, 13, 22, 26, 33, 39, 48, 56, 62, 68, 79, 86, 93, 103, 110, 117, 124,
+    131, 137, 146, 153, 158, 166, 173, 180, 187, 195, 199, 204, 210, 216, 223,
+    231, 243, 250, 258, 264, 267, 274, 281, 287, 294, 300, 315, 320, 335, 340,
+    348, 352, 359, 367, 374, 379, 393, 399, 405, 410, 419, 427, 436, 444, 448,
+    458, 467, 477, 484, 493, 500, 508, 516, 527, 534, 542, 550, 556, 562, 567,
+    573, 584, 591, 595, 600, 606, 614, 621, 624, 631, 640, 647, 657, 670, 682,
+    687, 698, 707, 714, 731, 741, 749, 756, 765, 770, 775, 793, 800, 805, 811,
+    824, 837, 843, 849, 854, 871, 888, 897, 905, 912, 918, 923, 928, 940, 947,
+    953, 963, 969, 976, 983, 991, 996, 1003, 1008, 1015, 1021, 1030, 1043,
+    1049, 1054, 1060, 1066, 1080, 1087, 1095, 1104, 1111, 1116, 1125, 1132,
+    1139, 1147, 1152, 1157, 1163, 1168, 1175, 1179, 1187, 1192, 1198, 1204,
+    1211, 1218, 1221, 1227, 1236, 1248, 1252, 1257, 1262, 1272, 1279, 1284,
+    1289, 1296, 1302, 1308, 1315, 1319, 1340,
+];
+
+#[cfg(feature = "local_names")]
+pub(crate) static AUTONYMS: &str = concat!(
+    "Qafar",
+    "аҧсуа бызшәа\u{200e} (Aṗsua byzšwa), аҧсшәа\u{200e} (Aṗsšwa)",
+    "Afrikaans",
+    "Akan",
+    "አማርኛ",
+    "العربية",
+    "Aragonés",
+    "অসমীয\u{9bc}\u{9be}",
+    "авар мацӏ\u{200e} (Awar mac\u{323}), магӏарул мацӏ\u{200e} (Maʿarul mac\u{323})",
+    "azərbaycan",
+    "башҡорт теле\u{200e} (Başķort tele), башҡортса\u{200e} (Başķortsa)",
+    "bamanakan",
+    "беларуская",
+    "ব\u{9be}ংল\u{9be}",
+    "Bislama",
+    "བ\u{f7c}ད་ས\u{f90}ད་",
+    "bosanski",
+    "brezhoneg",
+    "български",
+    "català",
+    "čeština",
+    "Chamorru",
+    "нохчийн",
+    "Чӑвашла",
+    "kernewek",
+    "Corsu",
+    "Cymraeg",
+    "dansk",
+    "Deutsch",
+    "ދ\u{7a8}ވ\u{7ac}ހ\u{7a8}ބ\u{7a6}ސ\u{7b0}",
+    "ར\u{fab}\u{f7c}ང་ཁ",
+    "Ελληνικά",
+    "English",
+    "eesti",
+    "euskara",
+    "Eʋegbe",
+    "føroyskt",
+    "فارسی",
+    "suomi",
+    "français",
+    "Frysk",
+    "Pulaar",
+    "Gàidhlig",
+    "Gaeilge",
+    "galego",
+    "Gaelg",
+    "ગ\u{ac1}જરાતી",
+    "Ayisyen, Kreyòl",
+    "Hausa",
+    "עברית",
+    "हिन\u{94d}दी",
+    "hrvatski",
+    "magyar",
+    "հայերեն",
+    "Igbo",
+    "ꆈꌠꉙ",
+    "ᐃᓄᒃᑎᑐᑦ",
+    "Indonesia",
+    "íslenska",
+    "italiano",
+    "Basa Jawa",
+    "日本語",
+    "kalaallisut",
+    "ಕನ\u{ccd}ನಡ",
+    "کٲش\u{64f}ر",
+    "ქართული",
+    "қазақ тілі",
+    "ខ\u{17d2}មែរ",
+    "Gikuyu",
+    "Kinyarwanda",
+    "кыргызча",
+    "한국어",
+    "Oshikwanyama",
+    "kurdî",
+    "ລາວ",
+    "latviešu",
+    "Limbourgeois, Limburgs",
+    "lingála",
+    "lietuvių",
+    "Lëtzebuergesch",
+    "Tshiluba",
+    "Luganda",
+    "മലയ\u{d3e}ളം",
+    "मराठी",
+    "македонски",
+    "Malagasy",
+    "Malti",
+    "монгол",
+    "te reo Maori",
+    "Melayu",
+    "မြန\u{103a}မာ",
+    "Diné",
+    "isiNdebele",
+    "isiNdebele",
+    "न\u{947}पाली",
+    "Nederlands",
+    "nynorsk",
+    "norsk bokmål",
+    "Norsk",
+    "Chichewa, chiCheŵa\u{200e} (Chichewa), chiNyanja",
+    "occitan",
+    "ଓଡ\u{b3c}\u{b3f}ଆ",
+    "Oromoo",
+    "ирон",
+    "ਪ\u{a70}ਜਾਬੀ",
+    "polski",
+    "português",
+    "پښتو",
+    "Runasimi",
+    "rumantsch",
+    "română",
+    "Ikirundi",
+    "русский",
+    "Sängö",
+    "ස\u{dd2}ංහල",
+    "slovenčina",
+    "slovenščina",
+    "davvisámegiella",
+    "Gagana Samoa",
+    "chiShona",
+    "سنڌي",
+    "Soomaali",
+    "Sesotho",
+    "español",
+    "shqip",
+    "српски",
+    "siSwati",
+    "Sunda",
+    "Kiswahili",
+    "svenska",
+    "Reo Tahiti",
+    "தமிழ\u{bcd}",
+    "татар",
+    "త\u{c46}లుగు",
+    "тоҷикӣ",
+    "Tagalog",
+    "ไทย",
+    "ትግርኛ",
+    "lea fakatonga",
+    "Setswana",
+    "Xitsonga",
+    "Türkmen dili",
+    "Türkçe",
+    "ئۇيغۇرچە",
+    "українська",
+    "und",
+    "اردو",
+    "o‘zbek",
+    "Tshivenḓa",
+    "Tiếng Việt",
+    "Wolof",
+    "isiXhosa",
+    "יי\u{5b4}דיש",
+    "Èdè Yorùbá",
+    "中文",
+    "isiZulu",
+);
+
+#[cfg(feature = "local_names")]
+pub(crate) static AUTONYMS_OFFSETS: [u16; LANGUAGE_COUNT + 1] = [
+    0, 5, 78, 87, 91, 103, 117, 126, 147, 227, 227, 238, 318, 327, 347, 362,
+    369, 393, 401, 410, 428, 435, 444, 452, 466, 480, 488, 493, 493, 500, 505,
+    512, 532, 550, 566, 573, 578, 585, 592, 601, 611, 611, 616, 625, 630, 636,
+    645, 652, 658, 663, 663, 684, 700, 705, 705, 715, 715, 733, 733, 741, 747,
+    761, 765, 774, 792, 801, 801, 810, 818, 827, 836, 847, 862, 872, 893, 893,
+    912, 927, 933, 944, 960, 960, 960, 969, 981, 987, 996, 1005, 1027, 1035,
+    1044, 1059, 1067, 1074, 1074, 1092, 1107, 1107, 1127, 1135, 1140, 1152,
+    1164, 1170, 1170, 1188, 1188, 1193, 1203, 1213, 1213, 1231, 1241, 1248,
+    1261, 1266, 1310, 1317, 1317, 1332, 1338, 1346, 1364, 1370, 1380, 1388,
+    1396, 1405, 1413, 1421, 1435, 1442, 1457, 1468, 1481, 1497, 1509, 1517,
+    1525, 1533, 1540, 1548, 1553, 1553, 1565, 1572, 1577, 1586, 1593, 1603,
+    1618, 1628, 1646, 1658, 1665, 1674, 1686, 1699, 1707, 1715, 1728, 1736,
+    1736, 1752, 1772, 1775, 1783, 1791, 1802, 1816, 1816, 1821, 1829, 1841,
+    1855, 1855, 1861, 1868, 1868,
+];
+
+#[derive(Clone, Copy, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u16)]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "diesel", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow), diesel(sql_type = diesel::sql_types::Text))]
+pub enum Language {
+    /// Afar
+    Aar = 0,
+    /// Abkhazian
+    Abk = 1,
+    /// Afrikaans
+    Afr = 2,
+    /// Akan
+    Aka = 3,
+    /// Amharic
+    Amh = 4,
+    /// Arabic
+    Ara = 5,
+    /// Aragonese
+    Arg = 6,
+    /// Assamese
+    Asm = 7,
+    /// Avaric
+    Ava = 8,
+    /// Aymara
+    Aym = 9,
+    /// Azerbaijani
+    Aze = 10,
+    /// Bashkir
+    Bak = 11,
+    /// Bambara
+    Bam = 12,
+    /// Belarusian
+    Bel = 13,
+    /// Bengali
+    Ben = 14,
+    /// Bislama
+    Bis = 15,
+    /// Tibetan
+    Bod = 16,
+    /// Bosnian
+    Bos = 17,
+    /// Breton
+    Bre = 18,
+    /// Bulgarian
+    Bul = 19,
+    /// Catalan
+    Cat = 20,
+    /// Czech
+    Ces = 21,
+    /// Chamorro
+    Cha = 22,
+    /// Chechen
+    Che = 23,
+    /// Chuvash
+    Chv = 24,
+    /// Cornish
+    Cor = 25,
+    /// Corsican
+    Cos = 26,
+    /// Cree
+    Cre = 27,
+    /// Welsh
+    Cym = 28,
+    /// Danish
+    Dan = 29,
+    /// German
+    Deu = 30,
+    /// Dhivehi
+    Div = 31,
+    /// Dzongkha
+    Dzo = 32,
+    /// Modern Greek
+    Ell = 33,
+    /// English
+    Eng = 34,
+    /// Estonian
+    Est = 35,
+    /// Basque
+    Eus = 36,
+    /// Ewe
+    Ewe = 37,
+    /// Faroese
+    Fao = 38,
+    /// Persian
+    Fas = 39,
+    /// Fijian
+    Fij = 40,
+    /// Finnish
+    Fin = 41,
+    /// French
+    Fra = 42,
+    /// Western Frisian
+    Fry = 43,
+    /// Fulah
+    Ful = 44,
+    /// Scottish Gaelic
+    Gla = 45,
+    /// Irish
+    Gle = 46,
+    /// Galician
+    Glg = 47,
+    /// Manx
+    Glv = 48,
+    /// Guarani
+    Grn = 49,
+    /// Gujarati
+    Guj = 50,
+    /// Haitian
+    Hat = 51,
+    /// Hausa
+    Hau = 52,
+    /// Serbo-Croatian
+    Hbs = 53,
+    /// Hebrew
+    Heb = 54,
+    /// Herero
+    Her = 55,
+    /// Hindi
+    Hin = 56,
+    /// Hiri Motu
+    Hmo = 57,
+    /// Croatian
+    Hrv = 58,
+    /// Hungarian
+    Hun = 59,
+    /// Armenian
+    Hye = 60,
+    /// Igbo
+    Ibo = 61,
+    /// Sichuan Yi
+    Iii = 62,
+    /// Inuktitut
+    Iku = 63,
+    /// Indonesian
+    Ind = 64,
+    /// Inupiaq
+    Ipk = 65,
+    /// Icelandic
+    Isl = 66,
+    /// Italian
+    Ita = 67,
+    /// Javanese
+    Jav = 68,
+    /// Japanese
+    Jpn = 69,
+    /// Kalaallisut
+    Kal = 70,
+    /// Kannada
+    Kan = 71,
+    /// Kashmiri
+    Kas = 72,
+    /// Georgian
+    Kat = 73,
+    /// Kanuri
+    Kau = 74,
+    /// Kazakh
+    Kaz = 75,
+    /// Khmer
+    Khm = 76,
+    /// Kikuyu
+    Kik = 77,
+    /// Kinyarwanda
+    Kin = 78,
+    /// Kirghiz
+    Kir = 79,
+    /// Komi
+    Kom = 80,
+    /// Kongo
+    Kon = 81,
+    /// Korean
+    Kor = 82,
+    /// Kuanyama
+    Kua = 83,
+    /// Kurdish
+    Kur = 84,
+    /// Lao
+    Lao = 85,
+    /// Latvian
+    Lav = 86,
+    /// Limburgan
+    Lim = 87,
+    /// Lingala
+    Lin = 88,
+    /// Lithuanian
+    Lit = 89,
+    /// Luxembourgish
+    Ltz = 90,
+    /// Luba-Katanga
+    Lub = 91,
+    /// Ganda
+    Lug = 92,
+    /// Marshallese
+    Mah = 93,
+    /// Malayalam
+    Mal = 94,
+    /// Marathi
+    Mar = 95,
+    /// Uncoded languages
+    Mis = 96,
+    /// Macedonian
+    Mkd = 97,
+    /// Malagasy
+    Mlg = 98,
+    /// Maltese
+    Mlt = 99,
+    /// Mongolian
+    Mon = 100,
+    /// Maori
+    Mri = 101,
+    /// Malay
+    Msa = 102,
+    /// Multiple languages
+    Mul = 103,
+    /// Burmese
+    Mya = 104,
+    /// Nauru
+    Nau = 105,
+    /// Navajo
+    Nav = 106,
+    /// South Ndebele
+    Nbl = 107,
+    /// North Ndebele
+    Nde = 108,
+    /// Ndonga
+    Ndo = 109,
+    /// Nepali
+    Nep = 110,
+    /// Dutch
+    Nld = 111,
+    /// Norwegian Nynorsk
+    Nno = 112,
+    /// Norwegian Bokmål
+    Nob = 113,
+    /// Norwegian
+    Nor = 114,
+    /// Chichewa
+    Nya = 115,
+    /// Occitan
+    Oci = 116,
+    /// Ojibwa
+    Oji = 117,
+    /// Oriya
+    Ori = 118,
+    /// Oromo
+    Orm = 119,
+    /// Iron Ossetic
+    Oss = 120,
+    /// Panjabi
+    Pan = 121,
+    /// Polish
+    Pol = 122,
+    /// Portuguese
+    Por = 123,
+    /// Pushto
+    Pus = 124,
+    /// Quechua
+    Que = 125,
+    /// Romansh
+    Roh = 126,
+    /// Romanian
+    Ron = 127,
+    /// Rundi
+    Run = 128,
+    /// Russian
+    Rus = 129,
+    /// Sango
+    Sag = 130,
+    /// Sinhala
+    Sin = 131,
+    /// Slovak
+    Slk = 132,
+    /// Slovenian
+    Slv = 133,
+    /// Northern Sami
+    Sme = 134,
+    /// Samoan
+    Smo = 135,
+    /// Shona
+    Sna = 136,
+    /// Sindhi
+    Snd = 137,
+    /// Somali
+    Som = 138,
+    /// Southern Sotho
+    Sot = 139,
+    /// Spanish
+    Spa = 140,
+    /// Albanian
+    Sqi = 141,
+    /// Sardinian
+    Srd = 142,
+    /// Serbian
+    Srp = 143,
+    /// Swati
+    Ssw = 144,
+    /// Sundanese
+    Sun = 145,
+    /// Swahili
+    Swa = 146,
+    /// Swedish
+    Swe = 147,
+    /// Tahitian
+    Tah = 148,
+    /// Tamil
+    Tam = 149,
+    /// Tatar
+    Tat = 150,
+    /// Telugu
+    Tel = 151,
+    /// Tajik
+    Tgk = 152,
+    /// Tagalog
+    Tgl = 153,
+    /// Thai
+    Tha = 154,
+    /// Tigrinya
+    Tir = 155,
+    /// Tonga
+    Ton = 156,
+    /// Tswana
+    Tsn = 157,
+    /// Tsonga
+    Tso = 158,
+    /// Turkmen
+    Tuk = 159,
+    /// Turkish
+    Tur = 160,
+    /// Twi
+    Twi = 161,
+    /// Uighur
+    Uig = 162,
+    /// Ukrainian
+    Ukr = 163,
+    /// Undetermined
+    Und = 164,
+    /// Urdu
+    Urd = 165,
+    /// Uzbek
+    Uzb = 166,
+    /// Venda
+    Ven = 167,
+    /// Vietnamese
+    Vie = 168,
+    /// Walloon
+    Wln = 169,
+    /// Wolof
+    Wol = 170,
+    /// Xhosa
+    Xho = 171,
+    /// Yiddish
+    Yid = 172,
+    /// Yoruba
+    Yor = 173,
+    /// Zhuang
+    Zha = 174,
+    /// Chinese
+    Zho = 175,
+    /// Zulu
+    Zul = 176,
+    /// No linguistic content
+    Zxx = 177,
+}
+
+pub(crate) const TWO_TO_THREE: phf::Map<&str, u16> = ::phf::Map {
+    key: 16287231350648472473,
+    disps: &[
+        (0, 0),
+        (0, 0),
+        (0, 29),
+        (0, 20),
+        (0, 0),
+        (7, 165),
+        (0, 9),
+        (3, 26),
+        (0, 1),
+        (0, 29),
+        (1, 88),
+        (0, 118),
+        (0, 14),
+        (1, 1),
+        (5, 54),
+        (0, 25),
+        (9, 168),
+        (4, 57),
+        (2, 137),
+        (0, 0),
+        (0, 12),
+        (0, 15),
+        (0, 43),
+        (1, 2),
+        (0, 154),
+        (0, 18),
+        (18, 100),
+        (19, 79),
+        (0, 126),
+        (30, 100),
+        (8, 130),
+        (0, 8),
+        (1, 61),
+        (0, 21),
+        (22, 53),
+    ],
+    entries: &[
+        ("iu", Language::Iku as u16),
+        ("bi", Language::Bis as u16),
+        ("mh", Language::Mah as u16),
+        ("sv", Language::Swe as u16),
+        ("sc", Language::Srd as u16),
+        ("os", Language::Oss as u16),
+        ("li", Language::Lim as u16),
+        ("gu", Language::Guj as u16),
+        ("cy", Language::Cym as u16),
+        ("cr", Language::Cre as u16),
+        ("mt", Language::Mlt as u16),
+        ("ik", Language::Ipk as u16),
+        ("ny", Language::Nya as u16),
+        ("ch", Language::Cha as u16),
+        ("my", Language::Mya as u16),
+        ("su", Language::Sun as u16),
+        ("mg", Language::Mlg as u16),
+        ("ml", Language::Mal as u16),
+        ("gl", Language::Glg as u16),
+        ("ki", Language::Kik as u16),
+        ("ty", Language::Tah as u16),
+        ("kn", Language::Kan as u16),
+        ("ky", Language::Kir as u16),
+        ("tt", Language::Tat as u16),
+        ("bg", Language::Bul as u16),
+        ("it", Language::Ita as u16),
+        ("aa", Language::Aar as u16),
+        ("el", Language::Ell as u16),
+        ("is", Language::Isl as u16),
+        ("rm", Language::Roh as u16),
+        ("ig", Language::Ibo as u16),
+        ("ff", Language::Ful as u16),
+        ("am", Language::Amh as u16),
+        ("ti", Language::Tir as u16),
+        ("si", Language::Sin as u16),
+        ("tw", Language::Twi as u16),
+        ("mn", Language::Mon as u16),
+        ("pt", Language::Por as u16),
+        ("lo", Language::Lao as u16),
+        ("ng", Language::Ndo as u16),
+        ("kv", Language::Kom as u16),
+        ("co", Language::Cos as u16),
+        ("to", Language::Ton as u16),
+        ("th", Language::Tha as u16),
+        ("yo", Language::Yor as u16),
+        ("ay", Language::Aym as u16),
+        ("so", Language::Som as u16),
+        ("sq", Language::Sqi as u16),
+        ("kl", Language::Kal as u16),
+        ("wa", Language::Wln as u16),
+        ("sk", Language::Slk as u16),
+        ("hi", Language::Hin as u16),
+        ("kk", Language::Kaz as u16),
+        ("sm", Language::Smo as u16),
+        ("sn", Language::Sna as u16),
+        ("ln", Language::Lin as u16),
+        ("ss", Language::Ssw as u16),
+        ("sd", Language::Snd as u16),
+        ("fo", Language::Fao as u16),
+        ("tn", Language::Tsn as u16),
+        ("dv", Language::Div as u16),
+        ("se", Language::Sme as u16),
+        ("ha", Language::Hau as u16),
+        ("mr", Language::Mar as u16),
+        ("cv", Language::Chv as u16),
+        ("ve", Language::Ven as u16),
+        ("cs", Language::Ces as u16),
+        ("sg", Language::Sag as u16),
+        ("uk", Language::Ukr as u16),
+        ("na", Language::Nau as u16),
+        ("wo", Language::Wol as u16),
+        ("gv", Language::Glv as u16),
+        ("nl", Language::Nld as u16),
+        ("ii", Language::Iii as u16),
+        ("hz", Language::Her as u16),
+        ("lv", Language::Lav as u16),
+        ("ko", Language::Kor as u16),
+        ("ja", Language::Jpn as u16),
+        ("br", Language::Bre as u16),
+        ("km", Language::Khm as u16),
+        ("vi", Language::Vie as u16),
+        ("av", Language::Ava as u16),
+        ("kw", Language::Cor as u16),
+        ("mk", Language::Mkd as u16),
+        ("zh", Language::Zho as u16),
+        ("fr", Language::Fra as u16),
+        ("hy", Language::Hye as u16),
+        ("no", Language::Nor as u16),
+        ("ug", Language::Uig as u16),
+        ("ak", Language::Aka as u16),
+        ("gn", Language::Grn as u16),
+        ("es", Language::Spa as u16),
+        ("lb", Language::Ltz as u16),
+        ("tr", Language::Tur as u16),
+        ("pa", Language::Pan as u16),
+        ("fy", Language::Fry as u16),
+        ("hu", Language::Hun as u16),
+        ("mi", Language::Mri as u16),
+        ("bm", Language::Bam as u16),
+        ("ts", Language::Tso as u16),
+        ("rn", Language::Run as u16),
+        ("sr", Language::Srp as u16),
+        ("da", Language::Dan as u16),
+        ("ka", Language::Kat as u16),
+        ("nv", Language::Nav as u16),
+        ("zu", Language::Zul as u16),
+        ("az", Language::Aze as u16),
+        ("ht", Language::Hat as u16),
+        ("ba", Language::Bak as u16),
+        ("ne", Language::Nep as u16),
+        ("kg", Language::Kon as u16),
+        ("nn", Language::Nno as u16),
+        ("om", Language::Orm as u16),
+        ("ks", Language::Kas as u16),
+        ("en", Language::Eng as u16),
+        ("nb", Language::Nob as u16),
+        ("tk", Language::Tuk as u16),
+        ("ce", Language::Che as u16),
+        ("te", Language::Tel as u16),
+        ("kj", Language::Kua as u16),
+        ("ar", Language::Ara as u16),
+        ("sl", Language::Slv as u16),
+        ("af", Language::Afr as u16),
+        ("ps", Language::Pus as u16),
+        ("or", Language::Ori as u16),
+        ("sh", Language::Hbs as u16),
+        ("id", Language::Ind as u16),
+        ("dz", Language::Dzo as u16),
+        ("lg", Language::Lug as u16),
+        ("hr", Language::Hrv as u16),
+        ("be", Language::Bel as u16),
+        ("ku", Language::Kur as u16),
+        ("as", Language::Asm as u16),
+        ("bn", Language::Ben as u16),
+        ("ho", Language::Hmo as u16),
+        ("bo", Language::Bod as u16),
+        ("ee", Language::Ewe as u16),
+        ("ms", Language::Msa as u16),
+        ("nr", Language::Nbl as u16),
+        ("fi", Language::Fin as u16),
+        ("kr", Language::Kau as u16),
+        ("he", Language::Heb as u16),
+        ("ca", Language::Cat as u16),
+        ("pl", Language::Pol as u16),
+        ("ur", Language::Urd as u16),
+        ("qu", Language::Que as u16),
+        ("an", Language::Arg as u16),
+        ("xh", Language::Xho as u16),
+        ("st", Language::Sot as u16),
+        ("nd", Language::Nde as u16),
+        ("ru", Language::Rus as u16),
+        ("ga", Language::Gle as u16),
+        ("ab", Language::Abk as u16),
+        ("tg", Language::Tgk as u16),
+        ("eu", Language::Eus as u16),
+        ("rw", Language::Kin as u16),
+        ("oj", Language::Oji as u16),
+        ("fa", Language::Fas as u16),
+        ("ro", Language::Ron as u16),
+        ("gd", Language::Gla as u16),
+        ("za", Language::Zha as u16),
+        ("et", Language::Est as u16),
+        ("fj", Language::Fij as u16),
+        ("jv", Language::Jav as u16),
+        ("bs", Language::Bos as u16),
+        ("yi", Language::Yid as u16),
+        ("lt", Language::Lit as u16),
+        ("uz", Language::Uzb as u16),
+        ("lu", Language::Lub as u16),
+        ("tl", Language::Tgl as u16),
+        ("de", Language::Deu as u16),
+        ("ta", Language::Tam as u16),
+        ("sw", Language::Swa as u16),
+        ("oc", Language::Oci as u16),
+    ],
+};
+
+pub(crate) const THREE_TO_THREE: phf::Map<&str, u16> = ::phf::Map {
+    key: 16287231350648472473,
+    disps: &[
+        (0, 49),
+        (0, 5),
+        (0, 147),
+        (2, 22),
+        (0, 92),
+        (0, 9),
+        (2, 58),
+        (0, 2),
+        (1, 150),
+        (0, 75),
+        (0, 6),
+        (0, 111),
+        (1, 120),
+        (0, 28),
+        (0, 20),
+        (15, 0),
+        (0, 80),
+        (0, 34),
+        (16, 48),
+        (0, 2),
+        (0, 1),
+        (0, 40),
+        (0, 0),
+        (0, 20),
+        (3, 36),
+        (0, 5),
+        (0, 1),
+        (0, 56),
+        (1, 48),
+        (0, 78),
+        (1, 0),
+        (1, 110),
+        (0, 4),
+        (1, 65),
+        (2, 151),
+        (0, 65),
+    ],
+    entries: &[
+        ("kas", Language::Kas as u16),
+        ("yid", Language::Yid as u16),
+        ("ava", Language::Ava as u16),
+        ("oji", Language::Oji as u16),
+        ("kaz", Language::Kaz as u16),
+        ("tel", Language::Tel as u16),
+        ("deu", Language::Deu as u16),
+        ("fas", Language::Fas as u16),
+        ("ara", Language::Ara as u16),
+        ("mlg", Language::Mlg as u16),
+        ("guj", Language::Guj as u16),
+        ("sin", Language::Sin as u16),
+        ("glv", Language::Glv as u16),
+        ("aar", Language::Aar as u16),
+        ("bak", Language::Bak as u16),
+        ("iii", Language::Iii as u16),
+        ("tah", Language::Tah as u16),
+        ("sna", Language::Sna as u16),
+        ("zho", Language::Zho as u16),
+        ("ell", Language::Ell as u16),
+        ("bre", Language::Bre as u16),
+        ("kom", Language::Kom as u16),
+        ("cym", Language::Cym as u16),
+        ("mul", Language::Mul as u16),
+        ("twi", Language::Twi as u16),
+        ("zxx", Language::Zxx as u16),
+        ("asm", Language::Asm as u16),
+        ("cha", Language::Cha as u16),
+        ("tir", Language::Tir as u16),
+        ("tuk", Language::Tuk as u16),
+        ("fao", Language::Fao as u16),
+        ("her", Language::Her as u16),
+        ("bod", Language::Bod as u16),
+        ("fra", Language::Fra as u16),
+        ("por", Language::Por as u16),
+        ("ori", Language::Ori as u16),
+        ("mal", Language::Mal as u16),
+        ("afr", Language::Afr as u16),
+        ("cat", Language::Cat as u16),
+        ("xho", Language::Xho as u16),
+        ("gla", Language::Gla as u16),
+        ("und", Language::Und as u16),
+        ("roh", Language::Roh as u16),
+        ("tam", Language::Tam as u16),
+        ("aze", Language::Aze as u16),
+        ("aka", Language::Aka as u16),
+        ("hat", Language::Hat as u16),
+        ("hun", Language::Hun as u16),
+        ("kon", Language::Kon as u16),
+        ("khm", Language::Khm as u16),
+        ("zul", Language::Zul as u16),
+        ("yor", Language::Yor as u16),
+        ("mlt", Language::Mlt as u16),
+        ("snd", Language::Snd as u16),
+        ("ron", Language::Ron as u16),
+        ("ben", Language::Ben as u16),
+        ("nob", Language::Nob as u16),
+        ("kik", Language::Kik as u16),
+        ("nbl", Language::Nbl as u16),
+        ("nya", Language::Nya as u16),
+        ("ibo", Language::Ibo as u16),
+        ("ukr", Language::Ukr as u16),
+        ("kor", Language::Kor as u16),
+        ("mkd", Language::Mkd as u16),
+        ("ton", Language::Ton as u16),
+        ("kat", Language::Kat as u16),
+        ("hye", Language::Hye as u16),
+        ("ind", Language::Ind as u16),
+        ("kan", Language::Kan as u16),
+        ("nav", Language::Nav as u16),
+        ("fry", Language::Fry as u16),
+        ("hau", Language::Hau as u16),
+        ("wol", Language::Wol as u16),
+        ("srp", Language::Srp as u16),
+        ("pus", Language::Pus as u16),
+        ("abk", Language::Abk as u16),
+        ("swe", Language::Swe as u16),
+        ("srd", Language::Srd as u16),
+        ("kau", Language::Kau as u16),
+        ("ssw", Language::Ssw as u16),
+        ("kal", Language::Kal as u16),
+        ("ndo", Language::Ndo as u16),
+        ("vie", Language::Vie as u16),
+        ("mah", Language::Mah as u16),
+        ("amh", Language::Amh as u16),
+        ("oss", Language::Oss as u16),
+        ("ful", Language::Ful as u16),
+        ("arg", Language::Arg as u16),
+        ("dan", Language::Dan as u16),
+        ("ita", Language::Ita as u16),
+        ("pol", Language::Pol as u16),
+        ("kir", Language::Kir as u16),
+        ("bam", Language::Bam as u16),
+        ("msa", Language::Msa as u16),
+        ("hbs", Language::Hbs as u16),
+        ("sqi", Language::Sqi as u16),
+        ("rus", Language::Rus as u16),
+        ("slv", Language::Slv as u16),
+        ("sag", Language::Sag as u16),
+        ("pan", Language::Pan as u16),
+        ("mar", Language::Mar as u16),
+        ("tso", Language::Tso as u16),
+        ("som", Language::Som as u16),
+        ("eng", Language::Eng as u16),
+        ("jav", Language::Jav as u16),
+        ("ltz", Language::Ltz as u16),
+        ("cor", Language::Cor as u16),
+        ("lav", Language::Lav as u16),
+        ("uig", Language::Uig as u16),
+        ("sme", Language::Sme as u16),
+        ("slk", Language::Slk as u16),
+        ("iku", Language::Iku as u16),
+        ("lug", Language::Lug as u16),
+        ("nor", Language::Nor as u16),
+        ("spa", Language::Spa as u16),
+        ("sot", Language::Sot as u16),
+        ("dzo", Language::Dzo as u16),
+        ("bel", Language::Bel as u16),
+        ("tha", Language::Tha as u16),
+        ("lit", Language::Lit as u16),
+        ("nld", Language::Nld as u16),
+        ("kin", Language::Kin as u16),
+        ("orm", Language::Orm as u16),
+        ("cre", Language::Cre as u16),
+        ("run", Language::Run as u16),
+        ("que", Language::Que as u16),
+        ("est", Language::Est as u16),
+        ("urd", Language::Urd as u16),
+        ("tat", Language::Tat as u16),
+        ("zha", Language::Zha as u16),
+        ("bis", Language::Bis as u16),
+        ("fij", Language::Fij as u16),
+        ("eus", Language::Eus as u16),
+        ("gle", Language::Gle as u16),
+        ("mya", Language::Mya as u16),
+        ("mis", Language::Mis as u16),
+        ("tgk", Language::Tgk as u16),
+        ("isl", Language::Isl as u16),
+        ("hrv", Language::Hrv as u16),
+        ("lim", Language::Lim as u16),
+        ("ven", Language::Ven as u16),
+        ("fin", Language::Fin as u16),
+        ("glg", Language::Glg as u16),
+        ("chv", Language::Chv as u16),
+        ("lub", Language::Lub as u16),
+        ("lao", Language::Lao as u16),
+        ("heb", Language::Heb as u16),
+        ("oci", Language::Oci as u16),
+        ("jpn", Language::Jpn as u16),
+        ("smo", Language::Smo as u16),
+        ("uzb", Language::Uzb as u16),
+        ("nde", Language::Nde as u16),
+        ("aym", Language::Aym as u16),
+        ("hmo", Language::Hmo as u16),
+        ("ipk", Language::Ipk as u16),
+        ("swa", Language::Swa as u16),
+        ("hin", Language::Hin as u16),
+        ("kur", Language::Kur as u16),
+        ("kua", Language::Kua as u16),
+        ("nep", Language::Nep as u16),
+        ("tur", Language::Tur as u16),
+        ("tsn", Language::Tsn as u16),
+        ("bos", Language::Bos as u16),
+        ("nno", Language::Nno as u16),
+        ("sun", Language::Sun as u16),
+        ("bul", Language::Bul as u16),
+        ("wln", Language::Wln as u16),
+        ("che", Language::Che as u16),
+        ("div", Language::Div as u16),
+        ("grn", Language::Grn as u16),
+        ("ces", Language::Ces as u16),
+        ("ewe", Language::Ewe as u16),
+        ("mon", Language::Mon as u16),
+        ("nau", Language::Nau as u16),
+        ("tgl", Language::Tgl as u16),
+        ("mri", Language::Mri as u16),
+        ("cos", Language::Cos as u16),
+        ("lin", Language::Lin as u16),
+    ],
+};
+pub(crate) const fn language_from_639_1(code: &[u8; 2]) -> Option<Language> {
+    match code {
+        b"aa" => Some(Language::Aar),
+        b"ab" => Some(Language::Abk),
+        b"af" => Some(Language::Afr),
+        b"ak" => Some(Language::Aka),
+        b"am" => Some(Language::Amh),
+        b"ar" => Some(Language::Ara),
+        b"an" => Some(Language::Arg),
+        b"as" => Some(Language::Asm),
+        b"av" => Some(Language::Ava),
+        b"ay" => Some(Language::Aym),
+        b"az" => Some(Language::Aze),
+        b"ba" => Some(Language::Bak),
+        b"bm" => Some(Language::Bam),
+        b"be" => Some(Language::Bel),
+        b"bn" => Some(Language::Ben),
+        b"bi" => Some(Language::Bis),
+        b"bo" => Some(Language::Bod),
+        b"bs" => Some(Language::Bos),
+        b"br" => Some(Language::Bre),
+        b"bg" => Some(Language::Bul),
+        b"ca" => Some(Language::Cat),
+        b"cs" => Some(Language::Ces),
+        b"ch" => Some(Language::Cha),
+        b"ce" => Some(Language::Che),
+        b"cv" => Some(Language::Chv),
+        b"kw" => Some(Language::Cor),
+        b"co" => Some(Language::Cos),
+        b"cr" => Some(Language::Cre),
+        b"cy" => Some(Language::Cym),
+        b"da" => Some(Language::Dan),
+        b"de" => Some(Language::Deu),
+        b"dv" => Some(Language::Div),
+        b"dz" => Some(Language::Dzo),
+        b"el" => Some(Language::Ell),
+        b"en" => Some(Language::Eng),
+        b"et" => Some(Language::Est),
+        b"eu" => Some(Language::Eus),
+        b"ee" => Some(Language::Ewe),
+        b"fo" => Some(Language::Fao),
+        b"fa" => Some(Language::Fas),
+        b"fj" => Some(Language::Fij),
+        b"fi" => Some(Language::Fin),
+        b"fr" => Some(Language::Fra),
+        b"fy" => Some(Language::Fry),
+        b"ff" => Some(Language::Ful),
+        b"gd" => Some(Language::Gla),
+        b"ga" => Some(Language::Gle),
+        b"gl" => Some(Language::Glg),
+        b"gv" => Some(Language::Glv),
+        b"gn" => Some(Language::Grn),
+        b"gu" => Some(Language::Guj),
+        b"ht" => Some(Language::Hat),
+        b"ha" => Some(Language::Hau),
+        b"sh" => Some(Language::Hbs),
+        b"he" => Some(Language::Heb),
+        b"hz" => Some(Language::Her),
+        b"hi" => Some(Language::Hin),
+        b"ho" => Some(Language::Hmo),
+        b"hr" => Some(Language::Hrv),
+        b"hu" => Some(Language::Hun),
+        b"hy" => Some(Language::Hye),
+        b"ig" => Some(Language::Ibo),
+        b"ii" => Some(Language::Iii),
+        b"iu" => Some(Language::Iku),
+        b"id" => Some(Language::Ind),
+        b"ik" => Some(Language::Ipk),
+        b"is" => Some(Language::Isl),
+        b"it" => Some(Language::Ita),
+        b"jv" => Some(Language::Jav),
+        b"ja" => Some(Language::Jpn),
+        b"kl" => Some(Language::Kal),
+        b"kn" => Some(Language::Kan),
+        b"ks" => Some(Language::Kas),
+        b"ka" => Some(Language::Kat),
+        b"kr" => Some(Language::Kau),
+        b"kk" => Some(Language::Kaz),
+        b"km" => Some(Language::Khm),
+        b"ki" => Some(Language::Kik),
+        b"rw" => Some(Language::Kin),
+        b"ky" => Some(Language::Kir),
+        b"kv" => Some(Language::Kom),
+        b"kg" => Some(Language::Kon),
+        b"ko" => Some(Language::Kor),
+        b"kj" => Some(Language::Kua),
+        b"ku" => Some(Language::Kur),
+        b"lo" => Some(Language::Lao),
+        b"lv" => Some(Language::Lav),
+        b"li" => Some(Language::Lim),
+        b"ln" => Some(Language::Lin),
+        b"lt" => Some(Language::Lit),
+        b"lb" => Some(Language::Ltz),
+        b"lu" => Some(Language::Lub),
+        b"lg" => Some(Language::Lug),
+        b"mh" => Some(Language::Mah),
+        b"ml" => Some(Language::Mal),
+        b"mr" => Some(Language::Mar),
+        b"mk" => Some(Language::Mkd),
+        b"mg" => Some(Language::Mlg),
+        b"mt" => Some(Language::Mlt),
+        b"mn" => Some(Language::Mon),
+        b"mi" => Some(Language::Mri),
+        b"ms" => Some(Language::Msa),
+        b"my" => Some(Language::Mya),
+        b"na" => Some(Language::Nau),
+        b"nv" => Some(Language::Nav),
+        b"nr" => Some(Language::Nbl),
+        b"nd" => Some(Language::Nde),
+        b"ng" => Some(Language::Ndo),
+        b"ne" => Some(Language::Nep),
+        b"nl" => Some(Language::Nld),
+        b"nn" => Some(Language::Nno),
+        b"nb" => Some(Language::Nob),
+        b"no" => Some(Language::Nor),
+        b"ny" => Some(Language::Nya),
+        b"oc" => Some(Language::Oci),
+        b"oj" => Some(Language::Oji),
+        b"or" => Some(Language::Ori),
+        b"om" => Some(Language::Orm),
+        b"os" => Some(Language::Oss),
+        b"pa" => Some(Language::Pan),
+        b"pl" => Some(Language::Pol),
+        b"pt" => Some(Language::Por),
+        b"ps" => Some(Language::Pus),
+        b"qu" => Some(Language::Que),
+        b"rm" => Some(Language::Roh),
+        b"ro" => Some(Language::Ron),
+        b"rn" => Some(Language::Run),
+        b"ru" => Some(Language::Rus),
+        b"sg" => Some(Language::Sag),
+        b"si" => Some(Language::Sin),
+        b"sk" => Some(Language::Slk),
+        b"sl" => Some(Language::Slv),
+        b"se" => Some(Language::Sme),
+        b"sm" => Some(Language::Smo),
+        b"sn" => Some(Language::Sna),
+        b"sd" => Some(Language::Snd),
+        b"so" => Some(Language::Som),
+        b"st" => Some(Language::Sot),
+        b"es" => Some(Language::Spa),
+        b"sq" => Some(Language::Sqi),
+        b"sc" => Some(Language::Srd),
+        b"sr" => Some(Language::Srp),
+        b"ss" => Some(Language::Ssw),
+        b"su" => Some(Language::Sun),
+        b"sw" => Some(Language::Swa),
+        b"sv" => Some(Language::Swe),
+        b"ty" => Some(Language::Tah),
+        b"ta" => Some(Language::Tam),
+        b"tt" => Some(Language::Tat),
+        b"te" => Some(Language::Tel),
+        b"tg" => Some(Language::Tgk),
+        b"tl" => Some(Language::Tgl),
+        b"th" => Some(Language::Tha),
+        b"ti" => Some(Language::Tir),
+        b"to" => Some(Language::Ton),
+        b"tn" => Some(Language::Tsn),
+        b"ts" => Some(Language::Tso),
+        b"tk" => Some(Language::Tuk),
+        b"tr" => Some(Language::Tur),
+        b"tw" => Some(Language::Twi),
+        b"ug" => Some(Language::Uig),
+        b"uk" => Some(Language::Ukr),
+        b"ur" => Some(Language::Urd),
+        b"uz" => Some(Language::Uzb),
+        b"ve" => Some(Language::Ven),
+        b"vi" => Some(Language::Vie),
+        b"wa" => Some(Language::Wln),
+        b"wo" => Some(Language::Wol),
+        b"xh" => Some(Language::Xho),
+        b"yi" => Some(Language::Yid),
+        b"yo" => Some(Language::Yor),
+        b"za" => Some(Language::Zha),
+        b"zh" => Some(Language::Zho),
+        b"zu" => Some(Language::Zul),
+        _ => None,
+    }
+}
+pub(crate) const fn language_from_639_3(code: &[u8; 3]) -> Option<Language> {
+    match code {
+        b"aar" => Some(Language::Aar),
+        b"abk" => Some(Language::Abk),
+        b"afr" => Some(Language::Afr),
+        b"aka" => Some(Language::Aka),
+        b"amh" => Some(Language::Amh),
+        b"ara" => Some(Language::Ara),
+        b"arg" => Some(Language::Arg),
+        b"asm" => Some(Language::Asm),
+        b"ava" => Some(Language::Ava),
+        b"aym" => Some(Language::Aym),
+        b"aze" => Some(Language::Aze),
+        b"bak" => Some(Language::Bak),
+        b"bam" => Some(Language::Bam),
+        b"bel" => Some(Language::Bel),
+        b"ben" => Some(Language::Ben),
+        b"bis" => Some(Language::Bis),
+        b"bod" => Some(Language::Bod),
+        b"bos" => Some(Language::Bos),
+        b"bre" => Some(Language::Bre),
+        b"bul" => Some(Language::Bul),
+        b"cat" => Some(Language::Cat),
+        b"ces" => Some(Language::Ces),
+        b"cha" => Some(Language::Cha),
+        b"che" => Some(Language::Che),
+        b"chv" => Some(Language::Chv),
+        b"cor" => Some(Language::Cor),
+        b"cos" => Some(Language::Cos),
+        b"cre" => Some(Language::Cre),
+        b"cym" => Some(Language::Cym),
+        b"dan" => Some(Language::Dan),
+        b"deu" => Some(Language::Deu),
+        b"div" => Some(Language::Div),
+        b"dzo" => Some(Language::Dzo),
+        b"ell" => Some(Language::Ell),
+        b"eng" => Some(Language::Eng),
+        b"est" => Some(Language::Est),
+        b"eus" => Some(Language::Eus),
+        b"ewe" => Some(Language::Ewe),
+        b"fao" => Some(Language::Fao),
+        b"fas" => Some(Language::Fas),
+        b"fij" => Some(Language::Fij),
+        b"fin" => Some(Language::Fin),
+        b"fra" => Some(Language::Fra),
+        b"fry" => Some(Language::Fry),
+        b"ful" => Some(Language::Ful),
+        b"gla" => Some(Language::Gla),
+        b"gle" => Some(Language::Gle),
+        b"glg" => Some(Language::Glg),
+        b"glv" => Some(Language::Glv),
+        b"grn" => Some(Language::Grn),
+        b"guj" => Some(Language::Guj),
+        b"hat" => Some(Language::Hat),
+        b"hau" => Some(Language::Hau),
+        b"hbs" => Some(Language::Hbs),
+        b"heb" => Some(Language::Heb),
+        b"her" => Some(Language::Her),
+        b"hin" => Some(Language::Hin),
+        b"hmo" => Some(Language::Hmo),
+        b"hrv" => Some(Language::Hrv),
+        b"hun" => Some(Language::Hun),
+        b"hye" => Some(Language::Hye),
+        b"ibo" => Some(Language::Ibo),
+        b"iii" => Some(Language::Iii),
+        b"iku" => Some(Language::Iku),
+        b"ind" => Some(Language::Ind),
+        b"ipk" => Some(Language::Ipk),
+        b"isl" => Some(Language::Isl),
+        b"ita" => Some(Language::Ita),
+        b"jav" => Some(Language::Jav),
+        b"jpn" => Some(Language::Jpn),
+        b"kal" => Some(Language::Kal),
+        b"kan" => Some(Language::Kan),
+        b"kas" => Some(Language::Kas),
+        b"kat" => Some(Language::Kat),
+        b"kau" => Some(Language::Kau),
+        b"kaz" => Some(Language::Kaz),
+        b"khm" => Some(Language::Khm),
+        b"kik" => Some(Language::Kik),
+        b"kin" => Some(Language::Kin),
+        b"kir" => Some(Language::Kir),
+        b"kom" => Some(Language::Kom),
+        b"kon" => Some(Language::Kon),
+        b"kor" => Some(Language::Kor),
+        b"kua" => Some(Language::Kua),
+        b"kur" => Some(Language::Kur),
+        b"lao" => Some(Language::Lao),
+        b"lav" => Some(Language::Lav),
+        b"lim" => Some(Language::Lim),
+        b"lin" => Some(Language::Lin),
+        b"lit" => Some(Language::Lit),
+        b"ltz" => Some(Language::Ltz),
+        b"lub" => Some(Language::Lub),
+        b"lug" => Some(Language::Lug),
+        b"mah" => Some(Language::Mah),
+        b"mal" => Some(Language::Mal),
+        b"mar" => Some(Language::Mar),
+        b"mis" => Some(Language::Mis),
+        b"mkd" => Some(Language::Mkd),
+        b"mlg" => Some(Language::Mlg),
+        b"mlt" => Some(Language::Mlt),
+        b"mon" => Some(Language::Mon),
+        b"mri" => Some(Language::Mri),
+        b"msa" => Some(Language::Msa),
+        b"mul" => Some(Language::Mul),
+        b"mya" => Some(Language::Mya),
+        b"nau" => Some(Language::Nau),
+        b"nav" => Some(Language::Nav),
+        b"nbl" => Some(Language::Nbl),
+        b"nde" => Some(Language::Nde),
+        b"ndo" => Some(Language::Ndo),
+        b"nep" => Some(Language::Nep),
+        b"nld" => Some(Language::Nld),
+        b"nno" => Some(Language::Nno),
+        b"nob" => Some(Language::Nob),
+        b"nor" => Some(Language::Nor),
+        b"nya" => Some(Language::Nya),
+        b"oci" => Some(Language::Oci),
+        b"oji" => Some(Language::Oji),
+        b"ori" => Some(Language::Ori),
+        b"orm" => Some(Language::Orm),
+        b"oss" => Some(Language::Oss),
+        b"pan" => Some(Language::Pan),
+        b"pol" => Some(Language::Pol),
+        b"por" => Some(Language::Por),
+        b"pus" => Some(Language::Pus),
+        b"que" => Some(Language::Que),
+        b"roh" => Some(Language::Roh),
+        b"ron" => Some(Language::Ron),
+        b"run" => Some(Language::Run),
+        b"rus" => Some(Language::Rus),
+        b"sag" => Some(Language::Sag),
+        b"sin" => Some(Language::Sin),
+        b"slk" => Some(Language::Slk),
+        b"slv" => Some(Language::Slv),
+        b"sme" => Some(Language::Sme),
+        b"smo" => Some(Language::Smo),
+        b"sna" => Some(Language::Sna),
+        b"snd" => Some(Language::Snd),
+        b"som" => Some(Language::Som),
+        b"sot" => Some(Language::Sot),
+        b"spa" => Some(Language::Spa),
+        b"sqi" => Some(Language::Sqi),
+        b"srd" => Some(Language::Srd),
+        b"srp" => Some(Language::Srp),
+        b"ssw" => Some(Language::Ssw),
+        b"sun" => Some(Language::Sun),
+        b"swa" => Some(Language::Swa),
+        b"swe" => Some(Language::Swe),
+        b"tah" => Some(Language::Tah),
+        b"tam" => Some(Language::Tam),
+        b"tat" => Some(Language::Tat),
+        b"tel" => Some(Language::Tel),
+        b"tgk" => Some(Language::Tgk),
+        b"tgl" => Some(Language::Tgl),
+        b"tha" => Some(Language::Tha),
+        b"tir" => Some(Language::Tir),
+        b"ton" => Some(Language::Ton),
+        b"tsn" => Some(Language::Tsn),
+        b"tso" => Some(Language::Tso),
+        b"tuk" => Some(Language::Tuk),
+        b"tur" => Some(Language::Tur),
+        b"twi" => Some(Language::Twi),
+        b"uig" => Some(Language::Uig),
+        b"ukr" => Some(Language::Ukr),
+        b"und" => Some(Language::Und),
+        b"urd" => Some(Language::Urd),
+        b"uzb" => Some(Language::Uzb),
+        b"ven" => Some(Language::Ven),
+        b"vie" => Some(Language::Vie),
+        b"wln" => Some(Language::Wln),
+        b"wol" => Some(Language::Wol),
+        b"xho" => Some(Language::Xho),
+        b"yid" => Some(Language::Yid),
+        b"yor" => Some(Language::Yor),
+        b"zha" => Some(Language::Zha),
+        b"zho" => Some(Language::Zho),
+        b"zul" => Some(Language::Zul),
+        b"zxx" => Some(Language::Zxx),
+        _ => None,
+    }
+}
+pub(crate) const fn iso_639_3_to_2b(code: &[u8; 3]) -> Option<&'static str> {
+    match code {
+        b"bod" => Some("tib"),
+        b"ces" => Some("cze"),
+        b"cym" => Some("wel"),
+        b"deu" => Some("ger"),
+        b"ell" => Some("gre"),
+        b"eus" => Some("baq"),
+        b"fas" => Some("per"),
+        b"fra" => Some("fre"),
+        b"hye" => Some("arm"),
+        b"isl" => Some("ice"),
+        b"kat" => Some("geo"),
+        b"mkd" => Some("mac"),
+        b"mri" => Some("mao"),
+        b"msa" => Some("may"),
+        b"mya" => Some("bur"),
+        b"nld" => Some("dut"),
+        b"ron" => Some("rum"),
+        b"slk" => Some("slo"),
+        b"sqi" => Some("alb"),
+        b"zho" => Some("chi"),
+        _ => None,
+    }
+}
+pub(crate) const fn language_from_639_2b(code: &[u8; 3]) -> Option<Language> {
+    match code {
+        b"tib" => Some(Language::Bod),
+        b"cze" => Some(Language::Ces),
+        b"wel" => Some(Language::Cym),
+        b"ger" => Some(Language::Deu),
+        b"gre" => Some(Language::Ell),
+        b"baq" => Some(Language::Eus),
+        b"per" => Some(Language::Fas),
+        b"fre" => Some(Language::Fra),
+        b"arm" => Some(Language::Hye),
+        b"ice" => Some(Language::Isl),
+        b"geo" => Some(Language::Kat),
+        b"mac" => Some(Language::Mkd),
+        b"mao" => Some(Language::Mri),
+        b"may" => Some(Language::Msa),
+        b"bur" => Some(Language::Mya),
+        b"dut" => Some(Language::Nld),
+        b"rum" => Some(Language::Ron),
+        b"slo" => Some(Language::Slk),
+        b"alb" => Some(Language::Sqi),
+        b"chi" => Some(Language::Zho),
+        _ => None,
+    }
+}
+pub(crate) const fn scope(lang: Language) -> Scope {
+    match lang {
+        Language::Aka => Scope::Macrolanguage,
+        Language::Ara => Scope::Macrolanguage,
+        Language::Aym => Scope::Macrolanguage,
+        Language::Aze => Scope::Macrolanguage,
+        Language::Cre => Scope::Macrolanguage,
+        Language::Est => Scope::Macrolanguage,
+        Language::Fas => Scope::Macrolanguage,
+        Language::Ful => Scope::Macrolanguage,
+        Language::Grn => Scope::Macrolanguage,
+        Language::Hbs => Scope::Macrolanguage,
+        Language::Iku => Scope::Macrolanguage,
+        Language::Ipk => Scope::Macrolanguage,
+        Language::Kau => Scope::Macrolanguage,
+        Language::Kom => Scope::Macrolanguage,
+        Language::Kon => Scope::Macrolanguage,
+        Language::Kur => Scope::Macrolanguage,
+        Language::Lav => Scope::Macrolanguage,
+        Language::Mis => Scope::Special,
+        Language::Mlg => Scope::Macrolanguage,
+        Language::Mon => Scope::Macrolanguage,
+        Language::Msa => Scope::Macrolanguage,
+        Language::Mul => Scope::Special,
+        Language::Nep => Scope::Macrolanguage,
+        Language::Nor => Scope::Macrolanguage,
+        Language::Oji => Scope::Macrolanguage,
+        Language::Ori => Scope::Macrolanguage,
+        Language::Orm => Scope::Macrolanguage,
+        Language::Pus => Scope::Macrolanguage,
+        Language::Que => Scope::Macrolanguage,
+        Language::Sqi => Scope::Macrolanguage,
+        Language::Srd => Scope::Macrolanguage,
+        Language::Swa => Scope::Macrolanguage,
+        Language::Und => Scope::Special,
+        Language::Uzb => Scope::Macrolanguage,
+        Language::Yid => Scope::Macrolanguage,
+        Language::Zha => Scope::Macrolanguage,
+        Language::Zho => Scope::Macrolanguage,
+        Language::Zxx => Scope::Special,
+        _ => Scope::Individual,
+    }
+}
+
+#[cfg(all(feature = "english_names", feature = "serde"))]
+pub(crate) const fn has_shared_name(lang: Language) -> bool {
+    let _ = lang;
+    false
+}
+
+pub(crate) fn macrolanguage(lang: Language) -> Option<Language> {
+    match lang {
+        Language::Twi => Some(Language::Aka),
+        _ => None,
+    }
+}
+
+pub(crate) const PARENT_LOCALES: phf::Map<&str, &str> = ::phf::Map {
+    key: 15995050791870030928,
+    disps: &[
+        (0, 19),
+        (0, 126),
+        (14, 78),
+        (1, 93),
+        (0, 9),
+        (0, 57),
+        (0, 7),
+        (5, 55),
+        (1, 17),
+        (0, 50),
+        (0, 140),
+        (1, 36),
+        (1, 79),
+        (0, 0),
+        (0, 132),
+        (0, 0),
+        (0, 51),
+        (0, 3),
+        (0, 5),
+        (0, 0),
+        (6, 119),
+        (0, 41),
+        (2, 138),
+        (27, 150),
+        (14, 81),
+        (0, 19),
+        (0, 13),
+        (1, 149),
+        (72, 45),
+        (4, 11),
+        (0, 93),
+    ],
+    entries: &[
+        ("no-NO", "no"),
+        ("pt-TL", "pt-PT"),
+        ("en-NG", "en-001"),
+        ("en-SC", "en-001"),
+        ("en-GS", "en-001"),
+        ("pt-CH", "pt-PT"),
+        ("en-AG", "en-001"),
+        ("es-CL", "es-419"),
+        ("en-ZW", "en-001"),
+        ("pt-CV", "pt-PT"),
+        ("es-VE", "es-419"),
+        ("en-ES", "en-150"),
+        ("en-TK", "en-001"),
+        ("en-LT", "en-150"),
+        ("pt-AO", "pt-PT"),
+        ("en-SL", "en-001"),
+        ("en-AI", "en-001"),
+        ("pt-LU", "pt-PT"),
+        ("en-LR", "en-001"),
+        ("en-DE", "en-150"),
+        ("en-SG", "en-001"),
+        ("en-GH", "en-001"),
+        ("en-MU", "en-001"),
+        ("en-BM", "en-001"),
+        ("hi-Latn", "en-IN"),
+        ("en-VG", "en-001"),
+        ("pt-MO", "pt-PT"),
+        ("en-GM", "en-001"),
+        ("en-GD", "en-001"),
+        ("en-GG", "en-001"),
+        ("en-MY", "en-001"),
+        ("en-IO", "en-001"),
+        ("en-PG", "en-001"),
+        ("es-MX", "es-419"),
+        ("en-MT", "en-001"),
+        ("en-HK", "en-001"),
+        ("und-Hans", "und-Hani"),
+        ("en-NL", "en-150"),
+        ("en-SB", "en-001"),
+        ("en-SH", "en-001"),
+        ("en-UG", "en-001"),
+        ("en-ZA", "en-001"),
+        ("es-CU", "es-419"),
+        ("en-BE", "en-150"),
+        ("en-GI", "en-001"),
+        ("en-CC", "en-001"),
+        ("en-ZM", "en-001"),
+        ("en-KY", "en-001"),
+        ("en-MV", "en-001"),
+        ("en-PT", "en-150"),
+        ("en-NR", "en-001"),
+        ("en-NZ", "en-001"),
+        ("en-BS", "en-001"),
+        ("pt-MZ", "pt-PT"),
+        ("en-CY", "en-001"),
+        ("en-IE", "en-001"),
+        ("es-JP", "es-419"),
+        ("es-CO", "es-419"),
+        ("en-PK", "en-001"),
+        ("en-SK", "en-150"),
+        ("en-SZ", "en-001"),
+        ("es-BR", "es-419"),
+        ("es-NI", "es-419"),
+        ("pt-GW", "pt-PT"),
+        ("en-IM", "en-001"),
+        ("en-IL", "en-001"),
+        ("en-KI", "en-001"),
+        ("en-NA", "en-001"),
+        ("nb", "no"),
+        ("es-PE", "es-419"),
+        ("en-PW", "en-001"),
+        ("en-LS", "en-001"),
+        ("en-ER", "en-001"),
+        ("en-IN", "en-001"),
+        ("en-CZ", "en-150"),
+        ("en-LV", "en-150"),
+        ("en-DK", "en-150"),
+        ("en-EE", "en-150"),
+        ("en-KE", "en-001"),
+        ("en-MO", "en-001"),
+        ("en-PL", "en-150"),
+        ("en-SX", "en-001"),
+        ("en-FR", "en-150"),
+        ("en-FK", "en-001"),
+        ("en-JM", "en-001"),
+        ("en-FI", "en-150"),
+        ("es-GT", "es-419"),
+        ("en-150", "en-001"),
+        ("ht", "fr-HT"),
+        ("en-CH", "en-150"),
+        ("es-BZ", "es-419"),
+        ("und-Hant", "und-Hani"),
+        ("en-HU", "en-150"),
+        ("en-CX", "en-001"),
+        ("es-US", "es-419"),
+        ("en-AU", "en-001"),
+        ("en-MS", "en-001"),
+        ("es-PR", "es-419"),
+        ("en-JE", "en-001"),
+        ("en-NO", "en-150"),
+        ("en-BW", "en-001"),
+        ("en-SE", "en-150"),
+        ("en-TC", "en-001"),
+        ("en-GB", "en-001"),
+        ("en-BZ", "en-001"),
+        ("en-DM", "en-001"),
+        ("en-TT", "en-001"),
+        ("en-WS", "en-001"),
+        ("es-DO", "es-419"),
+        ("en-NU", "en-001"),
+        ("en-SD", "en-001"),
+        ("es-PY", "es-419"),
+        ("es-CR", "es-419"),
+        ("en-UA", "en-150"),
+        ("en-RW", "en-001"),
+        ("es-AR", "es-419"),
+        ("en-MG", "en-001"),
+        ("en-GE", "en-150"),
+        ("en-TO", "en-001"),
+        ("en-KN", "en-001"),
+        ("en-ID", "en-001"),
+        ("en-TV", "en-001"),
+        ("pt-GQ", "pt-PT"),
+        ("en-VU", "en-001"),
+        ("en-MW", "en-001"),
+        ("en-SS", "en-001"),
+        ("en-VC", "en-001"),
+        ("en-IT", "en-150"),
+        ("es-PA", "es-419"),
+        ("pt-ST", "pt-PT"),
+        ("en-NF", "en-001"),
+        ("en-DG", "en-001"),
+        ("en-CK", "en-001"),
+        ("en-AT", "en-150"),
+        ("en-GY", "en-001"),
+        ("en-LC", "en-001"),
+        ("en-TZ", "en-001"),
+        ("en-FJ", "en-001"),
+        ("pt-FR", "pt-PT"),
+        ("nn", "no"),
+        ("en-CM", "en-001"),
+        ("es-UY", "es-419"),
+        ("es-BO", "es-419"),
+        ("en-BB", "en-001"),
+        ("en-SI", "en-150"),
+        ("es-SV", "es-419"),
+        ("en-FM", "en-001"),
+        ("es-HN", "es-419"),
+        ("es-EC", "es-419"),
+        ("en-RO", "en-150"),
+        ("en-PN", "en-001"),
+        ("zh-Hant-MO", "zh-Hant-HK"),
+    ],
+};
+
+pub(crate) static LANGUAGE_MATCHES: &[(Language, Language, u16, bool)] = &[
+    (Language::Nob, Language::Nor, 1, false),
+    (Language::Hrv, Language::Bos, 4, false),
+    (Language::Hbs, Language::Bos, 4, false),
+    (Language::Srp, Language::Bos, 4, false),
+    (Language::Hbs, Language::Hrv, 4, false),
+    (Language::Srp, Language::Hrv, 4, false),
+    (Language::Hbs, Language::Srp, 4, false),
+    (Language::Ltz, Language::Deu, 4, true),
+    (Language::Dan, Language::Nor, 8, false),
+    (Language::Dan, Language::Nob, 8, false),
+    (Language::Nno, Language::Nob, 10, false),
+    (Language::Nno, Language::Nor, 10, false),
+    (Language::Msa, Language::Ind, 15, true),
+    (Language::Abk, Language::Rus, 30, true),
+    (Language::Afr, Language::Nld, 20, true),
+    (Language::Aka, Language::Eng, 30, true),
+    (Language::Amh, Language::Eng, 30, true),
+    (Language::Aym, Language::Spa, 20, true),
+    (Language::Aze, Language::Rus, 30, true),
+    (Language::Bel, Language::Rus, 20, true),
+    (Language::Ben, Language::Eng, 30, true),
+    (Language::Bod, Language::Zho, 20, true),
+    (Language::Bre, Language::Fra, 20, true),
+    (Language::Cat, Language::Spa, 20, true),
+    (Language::Cos, Language::Fra, 20, true),
+    (Language::Cym, Language::Eng, 20, true),
+    (Language::Ewe, Language::Eng, 30, true),
+    (Language::Est, Language::Fin, 30, true),
+    (Language::Eus, Language::Spa, 20, true),
+    (Language::Fao, Language::Dan, 20, true),
+    (Language::Fry, Language::Nld, 20, true),
+    (Language::Gle, Language::Eng, 20, true),
+    (Language::Gla, Language::Eng, 20, true),
+    (Language::Glg, Language::Spa, 20, true),
+    (Language::Grn, Language::Spa, 20, true),
+    (Language::Guj, Language::Hin, 30, true),
+    (Language::Hau, Language::Eng, 30, true),
+    (Language::Hat, Language::Fra, 25, true),
+    (Language::Hye, Language::Rus, 30, true),
+    (Language::Ibo, Language::Eng, 30, true),
+    (Language::Isl, Language::Eng, 20, true),
+    (Language::Jav, Language::Ind, 20, true),
+    (Language::Kat, Language::Eng, 30, true),
+    (Language::Kon, Language::Fra, 30, true),
+    (Language::Kaz, Language::Rus, 30, true),
+    (Language::Khm, Language::Eng, 30, true),
+    (Language::Kan, Language::Eng, 30, true),
+    (Language::Kur, Language::Tur, 30, true),
+    (Language::Kir, Language::Rus, 30, true),
+    (Language::Lug, Language::Eng, 30, true),
+    (Language::Lin, Language::Fra, 30, true),
+    (Language::Lao, Language::Eng, 30, true),
+    (Language::Mlg, Language::Fra, 30, true),
+    (Language::Mri, Language::Eng, 20, true),
+    (Language::Mkd, Language::Bul, 30, true),
+    (Language::Mal, Language::Eng, 30, true),
+    (Language::Mon, Language::Rus, 30, true),
+    (Language::Mar, Language::Hin, 30, true),
+    (Language::Mlt, Language::Eng, 30, true),
+    (Language::Mya, Language::Eng, 30, true),
+    (Language::Nep, Language::Eng, 30, true),
+    (Language::Nya, Language::Eng, 30, true),
+    (Language::Oci, Language::Fra, 20, true),
+    (Language::Orm, Language::Eng, 30, true),
+    (Language::Ori, Language::Eng, 30, true),
+    (Language::Pan, Language::Eng, 30, true),
+    (Language::Pus, Language::Eng, 30, true),
+    (Language::Que, Language::Spa, 30, true),
+    (Language::Roh, Language::Deu, 20, true),
+    (Language::Run, Language::Eng, 30, true),
+    (Language::Kin, Language::Fra, 30, true),
+    (Language::Snd, Language::Eng, 30, true),
+    (Language::Sin, Language::Eng, 30, true),
+    (Language::Sna, Language::Eng, 30, true),
+    (Language::Som, Language::Eng, 30, true),
+    (Language::Sqi, Language::Eng, 30, true),
+    (Language::Sot, Language::Eng, 30, true),
+    (Language::Sun, Language::Ind, 20, true),
+    (Language::Swa, Language::Eng, 30, true),
+    (Language::Tam, Language::Eng, 30, true),
+    (Language::Tel, Language::Eng, 30, true),
+    (Language::Tgk, Language::Rus, 30, true),
+    (Language::Tir, Language::Eng, 30, true),
+    (Language::Tuk, Language::Rus, 30, true),
+    (Language::Tsn, Language::Eng, 30, true),
+    (Language::Ton, Language::Eng, 30, true),
+    (Language::Tat, Language::Rus, 30, true),
+    (Language::Uig, Language::Zho, 20, true),
+    (Language::Urd, Language::Eng, 30, true),
+    (Language::Uzb, Language::Rus, 30, true),
+    (Language::Wol, Language::Fra, 30, true),
+    (Language::Xho, Language::Eng, 30, true),
+    (Language::Yid, Language::Eng, 30, true),
+    (Language::Yor, Language::Eng, 30, true),
+    (Language::Zha, Language::Zho, 20, true),
+    (Language::Zul, Language::Eng, 30, true),
+];
+
+#[cfg(feature = "display_names_de")]
+static DISPLAY_NAMES_DEU_LANGUAGES: [u16; 177] = [
+    Language::Aar as u16,
+    Language::Abk as u16,
+    Language::Afr as u16,
+    Language::Aka as u16,
+    Language::Amh as u16,
+    Language::Ara as u16,
+    Language::Arg as u16,
+    Language::Asm as u16,
+    Language::Ava as u16,
+    Language::Aym as u16,
+    Language::Aze as u16,
+    Language::Bak as u16,
+    Language::Bam as u16,
+    Language::Bel as u16,
+    Language::Ben as u16,
+    Language::Bis as u16,
+    Language::Bod as u16,
+    Language::Bos as u16,
+    Language::Bre as u16,
+    Language::Bul as u16,
+    Language::Cat as u16,
+    Language::Ces as u16,
+    Language::Cha as u16,
+    Language::Che as u16,
+    Language::Chv as u16,
+    Language::Cor as u16,
+    Language::Cos as u16,
+    Language::Cre as u16,
+    Language::Cym as u16,
+    Language::Dan as u16,
+    Language::Deu as u16,
+    Language::Div as u16,
+    Language::Dzo as u16,
+    Language::Ell as u16,
+    Language::Eng as u16,
+    Language::Est as u16,
+    Language::Eus as u16,
+    Language::Ewe as u16,
+    Language::Fao as u16,
+    Language::Fas as u16,
+    Language::Fij as u16,
+    Language::Fin as u16,
+    Language::Fra as u16,
+    Language::Fry as u16,
+    Language::Ful as u16,
+    Language::Gla as u16,
+    Language::Gle as u16,
+    Language::Glg as u16,
+    Language::Glv as u16,
+    Language::Grn as u16,
+    Language::Guj as u16,
+    Language::Hat as u16,
+    Language::Hau as u16,
+    Language::Hbs as u16,
+    Language::Heb as u16,
+    Language::Her as u16,
+    Language::Hin as u16,
+    Language::Hmo as u16,
+    Language::Hrv as u16,
+    Language::Hun as u16,
+    Language::Hye as u16,
+    Language::Ibo as u16,
+    Language::Iii as u16,
+    Language::Iku as u16,
+    Language::Ind as u16,
+    Language::Ipk as u16,
+    Language::Isl as u16,
+    Language::Ita as u16,
+    Language::Jav as u16,
+    Language::Jpn as u16,
+    Language::Kal as u16,
+    Language::Kan as u16,
+    Language::Kas as u16,
+    Language::Kat as u16,
+    Language::Kau as u16,
+    Language::Kaz as u16,
+    Language::Khm as u16,
+    Language::Kik as u16,
+    Language::Kin as u16,
+    Language::Kir as u16,
+    Language::Kom as u16,
+    Language::Kon as u16,
+    Language::Kor as u16,
+    Language::Kua as u16,
+    Language::Kur as u16,
+    Language::Lao as u16,
+    Language::Lav as u16,
+    Language::Lim as u16,
+    Language::Lin as u16,
+    Language::Lit as u16,
+    Language::Ltz as u16,
+    Language::Lub as u16,
+    Language::Lug as u16,
+    Language::Mah as u16,
+    Language::Mal as u16,
+    Language::Mar as u16,
+    Language::Mkd as u16,
+    Language::Mlg as u16,
+    Language::Mlt as u16,
+    Language::Mon as u16,
+    Language::Mri as u16,
+    Language::Msa as u16,
+    Language::Mul as u16,
+    Language::Mya as u16,
+    Language::Nau as u16,
+    Language::Nav as u16,
+    Language::Nbl as u16,
+    Language::Nde as u16,
+    Language::Ndo as u16,
+    Language::Nep as u16,
+    Language::Nld as u16,
+    Language::Nno as u16,
+    Language::Nob as u16,
+    Language::Nor as u16,
+    Language::Nya as u16,
+    Language::Oci as u16,
+    Language::Oji as u16,
+    Language::Ori as u16,
+    Language::Orm as u16,
+    Language::Oss as u16,
+    Language::Pan as u16,
+    Language::Pol as u16,
+    Language::Por as u16,
+    Language::Pus as u16,
+    Language::Que as u16,
+    Language::Roh as u16,
+    Language::Ron as u16,
+    Language::Run as u16,
+    Language::Rus as u16,
+    Language::Sag as u16,
+    Language::Sin as u16,
+    Language::Slk as u16,
+    Language::Slv as u16,
+    Language::Sme as u16,
+    Language::Smo as u16,
+    Language::Sna as u16,
+    Language::Snd as u16,
+    Language::Som as u16,
+    Language::Sot as u16,
+    Language::Spa as u16,
+    Language::Sqi as u16,
+    Language::Srd as u16,
+    Language::Srp as u16,
+    Language::Ssw as u16,
+    Language::Sun as u16,
+    Language::Swa as u16,
+    Language::Swe as u16,
+    Language::Tah as u16,
+    Language::Tam as u16,
+    Language::Tat as u16,
+    Language::Tel as u16,
+    Language::Tgk as u16,
+    Language::Tgl as u16,
+    Language::Tha as u16,
+    Language::Tir as u16,
+    Language::Ton as u16,
+    Language::Tsn as u16,
+    Language::Tso as u16,
+    Language::Tuk as u16,
+    Language::Tur as u16,
+    Language::Twi as u16,
+    Language::Uig as u16,
+    Language::Ukr as u16,
+    Language::Und as u16,
+    Language::Urd as u16,
+    Language::Uzb as u16,
+    Language::Ven as u16,
+    Language::Vie as u16,
+    Language::Wln as u16,
+    Language::Wol as u16,
+    Language::Xho as u16,
+    Language::Yid as u16,
+    Language::Yor as u16,
+    Language::Zha as u16,
+    Language::Zho as u16,
+    Language::Zul as u16,
+    Language::Zxx as u16,
+];
+
+#[cfg(feature = "display_names_de")]
+pub(crate) static DISPLAY_NAMES_DEU: &str = concat!(
+    "Afar",
+    "Abchasisch",
+    "Afrikaans",
+    "Akan",
+    "Amharisch",
+    "Arabisch",
+    "Aragonesisch",
+    "Assamesisch",
+    "Awarisch",
+    "Aymara",
+    "Aserbaidschanisch",
+    "Baschkirisch",
+    "Bambara",
+    "Belarussisch",
+    "Bengalisch",
+    "Bislama",
+    "Tibetisch",
+    "Bosnisch",
+    "Bretonisch",
+    "Bulgarisch",
+    "Katalanisch",
+    "Tschechisch",
+    "Chamorro",
+    "Tschetschenisch",
+    "Tschuwaschisch",
+    "Kornisch",
+    "Korsisch",
+    "Cree",
+    "Walisisch",
+    "Dänisch",
+    "Deutsch",
+    "Dhivehi",
+    "Dzongkha",
+    "Griechisch",
+    "Englisch",
+    "Estnisch",
+    "Baskisch",
+    "Ewe",
+    "Färöisch",
+    "Persisch",
+    "Fidschi",
+    "Finnisch",
+    "Französisch",
+    "Westfriesisch",
+    "Ful",
+    "Gälisch (Schottland)",
+    "Irisch",
+    "Galicisch",
+    "Manx",
+    "Guaraní",
+    "Gujarati",
+    "Haiti-Kreolisch",
+    "Haussa",
+    "Serbo-Kroatisch",
+    "Hebräisch",
+    "Herero",
+    "Hindi",
+    "Hiri-Motu",
+    "Kroatisch",
+    "Ungarisch",
+    "Armenisch",
+    "Igbo",
+    "Yi",
+    "Inuktitut",
+    "Indonesisch",
+    "Inupiak",
+    "Isländisch",
+    "Italienisch",
+    "Javanisch",
+    "Japanisch",
+    "Grönländisch",
+    "Kannada",
+    "Kaschmiri",
+    "Georgisch",
+    "Kanuri",
+    "Kasachisch",
+    "Khmer",
+    "Kikuyu",
+    "Kinyarwanda",
+    "Kirgisisch",
+    "Komi",
+    "Kongolesisch",
+    "Koreanisch",
+    "Kwanyama",
+    "Kurdisch",
+    "Laotisch",
+    "Lettisch",
+    "Limburgisch",
+    "Lingala",
+    "Litauisch",
+    "Luxemburgisch",
+    "Luba-Katanga",
+    "Ganda",
+    "Marschallesisch",
+    "Malayalam",
+    "Marathi",
+    "Mazedonisch",
+    "Malagasy",
+    "Maltesisch",
+    "Mongolisch",
+    "Māori",
+    "Malaiisch",
+    "Mehrsprachig",
+    "Birmanisch",
+    "Nauruisch",
+    "Navajo",
+    "Süd-Ndebele",
+    "Nord-Ndebele",
+    "Ndonga",
+    "Nepalesisch",
+    "Niederländisch",
+    "Norwegisch (Nynorsk)",
+    "Norwegisch (Bokmål)",
+    "Norwegisch",
+    "Nyanja",
+    "Okzitanisch",
+    "Ojibwa",
+    "Oriya",
+    "Oromo",
+    "Ossetisch",
+    "Punjabi",
+    "Polnisch",
+    "Portugiesisch",
+    "Paschtu",
+    "Quechua",
+    "Rätoromanisch",
+    "Rumänisch",
+    "Rundi",
+    "Russisch",
+    "Sango",
+    "Singhalesisch",
+    "Slowakisch",
+    "Slowenisch",
+    "Nordsamisch",
+    "Samoanisch",
+    "Shona",
+    "Sindhi",
+    "Somali",
+    "Süd-Sotho",
+    "Spanisch",
+    "Albanisch",
+    "Sardisch",
+    "Serbisch",
+    "Swazi",
+    "Sundanesisch",
+    "Suaheli",
+    "Schwedisch",
+    "Tahitisch",
+    "Tamil",
+    "Tatarisch",
+    "Telugu",
+    "Tadschikisch",
+    "Tagalog",
+    "Thailändisch",
+    "Tigrinya",
+    "Tongaisch",
+    "Tswana",
+    "Tsonga",
+    "Turkmenisch",
+    "Türkisch",
+    "Twi",
+    "Uigurisch",
+    "Ukrainisch",
+    "Unbekannte Sprache",
+    "Urdu",
+    "Usbekisch",
+    "Venda",
+    "Vietnamesisch",
+    "Wallonisch",
+    "Wolof",
+    "Xhosa",
+    "Jiddisch",
+    "Yoruba",
+    "Zhuang",
+    "Chinesisch",
+    "Zulu",
+    "Keine Sprachinhalte",
+);
+
+#[cfg(feature = "display_names_de")]
+pub(crate) static DISPLAY_NAMES_DEU_OFFSETS: [u16; 178] = [
+    0, 4, 14, 23, 27, 36, 44, 56, 67, 75, 81, 98, 110, 117, 129, 139, 146, 155,
+    163, 173, 183, 194, 205, 213, 228, 242, 250, 258, 262, 271, 279, 286, 293,
+    301, 311, 319, 327, 335, 338, 348, 356, 363, 371, 383, 396, 399, 420, 426,
+    435, 439, 447, 455, 470, 476, 491, 501, 507, 512, 521, 530, 539, 548, 552,
+    554, 563, 574, 581, 592, 603, 612, 621, 635, 642, 651, 660, 666, 676, 681,
+    687, 698, 708, 712, 724, 734, 742, 750, 758, 766, 777, 784, 793, 806, 818,
+    823, 838, 847, 854, 865, 873, 883, 893, 899, 908, 920, 930, 939, 945, 957,
+    969, 975, 986, 1001, 1021, 1041, 1051, 1057, 1068, 1074, 1079, 1084, 1093,
+    1100, 1108, 1121, 1128, 1135, 1149, 1159, 1164, 1172, 1177, 1190, 1200,
+    1210, 1221, 1231, 1236, 1242, 1248, 1258, 1266, 1275, 1283, 1291, 1296,
+    1308, 1315, 1325, 1334, 1339, 1348, 1354, 1366, 1373, 1386, 1394, 1403,
+    1409, 1415, 1426, 1435, 1438, 1447, 1457, 1475, 1479, 1488, 1493, 1506,
+    1516, 1521, 1526, 1534, 1540, 1546, 1556, 1560, 1579,
+];
+
+#[cfg(feature = "display_names_en")]
+static DISPLAY_NAMES_ENG_LANGUAGES: [u16; 177] = [
+    Language::Aar as u16,
+    Language::Abk as u16,
+    Language::Afr as u16,
+    Language::Aka as u16,
+    Language::Amh as u16,
+    Language::Ara as u16,
+    Language::Arg as u16,
+    Language::Asm as u16,
+    Language::Ava as u16,
+    Language::Aym as u16,
+    Language::Aze as u16,
+    Language::Bak as u16,
+    Language::Bam as u16,
+    Language::Bel as u16,
+    Language::Ben as u16,
+    Language::Bis as u16,
+    Language::Bod as u16,
+    Language::Bos as u16,
+    Language::Bre as u16,
+    Language::Bul as u16,
+    Language::Cat as u16,
+    Language::Ces as u16,
+    Language::Cha as u16,
+    Language::Che as u16,
+    Language::Chv as u16,
+    Language::Cor as u16,
+    Language::Cos as u16,
+    Language::Cre as u16,
+    Language::Cym as u16,
+    Language::Dan as u16,
+    Language::Deu as u16,
+    Language::Div as u16,
+    Language::Dzo as u16,
+    Language::Ell as u16,
+    Language::Eng as u16,
+    Language::Est as u16,
+    Language::Eus as u16,
+    Language::Ewe as u16,
+    Language::Fao as u16,
+    Language::Fas as u16,
+    Language::Fij as u16,
+    Language::Fin as u16,
+    Language::Fra as u16,
+    Language::Fry as u16,
+    Language::Ful as u16,
+    Language::Gla as u16,
+    Language::Gle as u16,
+    Language::Glg as u16,
+    Language::Glv as u16,
+    Language::Grn as u16,
+    Language::Guj as u16,
+    Language::Hat as u16,
+    Language::Hau as u16,
+    Language::Hbs as u16,
+    Language::Heb as u16,
+    Language::Her as u16,
+    Language::Hin as u16,
+    Language::Hmo as u16,
+    Language::Hrv as u16,
+    Language::Hun as u16,
+    Language::Hye as u16,
+    Language::Ibo as u16,
+    Language::Iii as u16,
+    Language::Iku as u16,
+    Language::Ind as u16,
+    Language::Ipk as u16,
+    Language::Isl as u16,
+    Language::Ita as u16,
+    Language::Jav as u16,
+    Language::Jpn as u16,
+    Language::Kal as u16,
+    Language::Kan as u16,
+    Language::Kas as u16,
+    Language::Kat as u16,
+    Language::Kau as u16,
+    Language::Kaz as u16,
+    Language::Khm as u16,
+    Language::Kik as u16,
+    Language::Kin as u16,
+    Language::Kir as u16,
+    Language::Kom as u16,
+    Language::Kon as u16,
+    Language::Kor as u16,
+    Language::Kua as u16,
+    Language::Kur as u16,
+    Language::Lao as u16,
+    Language::Lav as u16,
+    Language::Lim as u16,
+    Language::Lin as u16,
+    Language::Lit as u16,
+    Language::Ltz as u16,
+    Language::Lub as u16,
+    Language::Lug as u16,
+    Language::Mah as u16,
+    Language::Mal as u16,
+    Language::Mar as u16,
+    Language::Mkd as u16,
+    Language::Mlg as u16,
+    Language::Mlt as u16,
+    Language::Mon as u16,
+    Language::Mri as u16,
+    Language::Msa as u16,
+    Language::Mul as u16,
+    Language::Mya as u16,
+    Language::Nau as u16,
+    Language::Nav as u16,
+    Language::Nbl as u16,
+    Language::Nde as u16,
+    Language::Ndo as u16,
+    Language::Nep as u16,
+    Language::Nld as u16,
+    Language::Nno as u16,
+    Language::Nob as u16,
+    Language::Nor as u16,
+    Language::Nya as u16,
+    Language::Oci as u16,
+    Language::Oji as u16,
+    Language::Ori as u16,
+    Language::Orm as u16,
+    Language::Oss as u16,
+    Language::Pan as u16,
+    Language::Pol as u16,
+    Language::Por as u16,
+    Language::Pus as u16,
+    Language::Que as u16,
+    Language::Roh as u16,
+    Language::Ron as u16,
+    Language::Run as u16,
+    Language::Rus as u16,
+    Language::Sag as u16,
+    Language::Sin as u16,
+    Language::Slk as u16,
+    Language::Slv as u16,
+    Language::Sme as u16,
+    Language::Smo as u16,
+    Language::Sna as u16,
+    Language::Snd as u16,
+    Language::Som as u16,
+    Language::Sot as u16,
+    Language::Spa as u16,
+    Language::Sqi as u16,
+    Language::Srd as u16,
+    Language::Srp as u16,
+    Language::Ssw as u16,
+    Language::Sun as u16,
+    Language::Swa as u16,
+    Language::Swe as u16,
+    Language::Tah as u16,
+    Language::Tam as u16,
+    Language::Tat as u16,
+    Language::Tel as u16,
+    Language::Tgk as u16,
+    Language::Tgl as u16,
+    Language::Tha as u16,
+    Language::Tir as u16,
+    Language::Ton as u16,
+    Language::Tsn as u16,
+    Language::Tso as u16,
+    Language::Tuk as u16,
+    Language::Tur as u16,
+    Language::Twi as u16,
+    Language::Uig as u16,
+    Language::Ukr as u16,
+    Language::Und as u16,
+    Language::Urd as u16,
+    Language::Uzb as u16,
+    Language::Ven as u16,
+    Language::Vie as u16,
+    Language::Wln as u16,
+    Language::Wol as u16,
+    Language::Xho as u16,
+    Language::Yid as u16,
+    Language::Yor as u16,
+    Language::Zha as u16,
+    Language::Zho as u16,
+    Language::Zul as u16,
+    Language::Zxx as u16,
+];
+
+#[cfg(feature = "display_names_en")]
+pub(crate) static DISPLAY_NAMES_ENG: &str = concat!(
+    "Afar",
+    "Abkhazian",
+    "Afrikaans",
+    "Akan",
+    "Amharic",
+    "Arabic",
+    "Aragonese",
+    "Assamese",
+    "Avaric",
+    "Aymara",
+    "Azerbaijani",
+    "Bashkir",
+    "Bambara",
+    "Belarusian",
+    "Bangla",
+    "Bislama",
+    "Tibetan",
+    "Bosnian",
+    "Breton",
+    "Bulgarian",
+    "Catalan",
+    "Czech",
+    "Chamorro",
+    "Chechen",
+    "Chuvash",
+    "Cornish",
+    "Corsican",
+    "Cree",
+    "Welsh",
+    "Danish",
+    "German",
+    "Divehi",
+    "Dzongkha",
+    "Greek",
+    "English",
+    "Estonian",
+    "Basque",
+    "Ewe",
+    "Faroese",
+    "Persian",
+    "Fijian",
+    "Finnish",
+    "French",
+    "Western Frisian",
+    "Fula",
+    "Scottish Gaelic",
+    "Irish",
+    "Galician",
+    "Manx",
+    "Guarani",
+    "Gujarati",
+    "Haitian Creole",
+    "Hausa",
+    "Serbo-Croatian",
+    "Hebrew",
+    "Herero",
+    "Hindi",
+    "Hiri Motu",
+    "Croatian",
+    "Hungarian",
+    "Armenian",
+    "Igbo",
+    "Sichuan Yi",
+    "Inuktitut",
+    "Indonesian",
+    "Inupiaq",
+    "Icelandic",
+    "Italian",
+    "Javanese",
+    "Japanese",
+    "Kalaallisut",
+    "Kannada",
+    "Kashmiri",
+    "Georgian",
+    "Kanuri",
+    "Kazakh",
+    "Khmer",
+    "Kikuyu",
+    "Kinyarwanda",
+    "Kyrgyz",
+    "Komi",
+    "Kongo",
+    "Korean",
+    "Kuanyama",
+    "Kurdish",
+    "Lao",
+    "Latvian",
+    "Limburgish",
+    "Lingala",
+    "Lithuanian",
+    "Luxembourgish",
+    "Luba-Katanga",
+    "Ganda",
+    "Marshallese",
+    "Malayalam",
+    "Marathi",
+    "Macedonian",
+    "Malagasy",
+    "Maltese",
+    "Mongolian",
+    "Māori",
+    "Malay",
+    "Multiple languages",
+    "Burmese",
+    "Nauru",
+    "Navajo",
+    "South Ndebele",
+    "North Ndebele",
+    "Ndonga",
+    "Nepali",
+    "Dutch",
+    "Norwegian Nynorsk",
+    "Norwegian Bokmål",
+    "Norwegian",
+    "Nyanja",
+    "Occitan",
+    "Ojibwa",
+    "Odia",
+    "Oromo",
+    "Ossetic",
+    "Punjabi",
+    "Polish",
+    "Portuguese",
+    "Pashto",
+    "Quechua",
+    "Romansh",
+    "Romanian",
+    "Rundi",
+    "Russian",
+    "Sango",
+    "Sinhala",
+    "Slovak",
+    "Slovenian",
+    "Northern Sami",
+    "Samoan",
+    "Shona",
+    "Sindhi",
+    "Somali",
+    "Southern Sotho",
+    "Spanish",
+    "Albanian",
+    "Sardinian",
+    "Serbian",
+    "Swati",
+    "Sundanese",
+    "Swahili",
+    "Swedish",
+    "Tahitian",
+    "Tamil",
+    "Tatar",
+    "Telugu",
+    "Tajik",
+    "Tagalog",
+    "Thai",
+    "Tigrinya",
+    "Tongan",
+    "Tswana",
+    "Tsonga",
+    "Turkmen",
+    "Turkish",
+    "Twi",
+    "Uyghur",
+    "Ukrainian",
+    "Unknown language",
+    "Urdu",
+    "Uzbek",
+    "Venda",
+    "Vietnamese",
+    "Walloon",
+    "Wolof",
+    "Xhosa",
+    "Yiddish",
+    "Yoruba",
+    "Zhuang",
+    "Chinese",
+    "Zulu",
+    "No linguistic content",
+);
+
+#[cfg(feature = "display_names_en")]
+pub(crate) static DISPLAY_NAMES_ENG_OFFSETS: [u16; 178] = [
+    0, 4, 13, 22, 26, 33, 39, 48, 56, 62, 68, 79, 86, 93, 103, 109, 116, 123,
+    130, 136, 145, 152, 157, 165, 172, 179, 186, 194, 198, 203, 209, 215, 221,
+    229, 234, 241, 249, 255, 258, 265, 272, 278, 285, 291, 306, 310, 325, 330,
+    338, 342, 349, 357, 371, 376, 390, 396, 402, 407, 416, 424, 433, 441, 445,
+    455, 464, 474, 481, 490, 497, 505, 513, 524, 531, 539, 547, 553, 559, 564,
+    570, 581, 587, 591, 596, 602, 610, 617, 620, 627, 637, 644, 654, 667, 679,
+    684, 695, 704, 711, 721, 729, 736, 745, 751, 756, 774, 781, 786, 792, 805,
+    818, 824, 830, 835, 852, 869, 878, 884, 891, 897, 901, 906, 913, 920, 926,
+    936, 942, 949, 956, 964, 969, 976, 981, 988, 994, 1003, 1016, 1022, 1027,
+    1033, 1039, 1053, 1060, 1068, 1077, 1084, 1089, 1098, 1105, 1112, 1120,
+    1125, 1130, 1136, 1141, 1148, 1152, 1160, 1166, 1172, 1178, 1185, 1192,
+    1195, 1201, 1210, 1226, 1230, 1235, 1240, 1250, 1257, 1262, 1267, 1274,
+    1280, 1286, 1293, 1297, 1318,
+];
+
+#[cfg(feature = "display_names_es")]
+static DISPLAY_NAMES_SPA_LANGUAGES: [u16; 177] = [
+    Language::Aar as u16,
+    Language::Abk as u16,
+    Language::Afr as u16,
+    Language::Aka as u16,
+    Language::Amh as u16,
+    Language::Ara as u16,
+    Language::Arg as u16,
+    Language::Asm as u16,
+    Language::Ava as u16,
+    Language::Aym as u16,
+    Language::Aze as u16,
+    Language::Bak as u16,
+    Language::Bam as u16,
+    Language::Bel as u16,
+    Language::Ben as u16,
+    Language::Bis as u16,
+    Language::Bod as u16,
+    Language::Bos as u16,
+    Language::Bre as u16,
+    Language::Bul as u16,
+    Language::Cat as u16,
+    Language::Ces as u16,
+    Language::Cha as u16,
+    Language::Che as u16,
+    Language::Chv as u16,
+    Language::Cor as u16,
+    Language::Cos as u16,
+    Language::Cre as u16,
+    Language::Cym as u16,
+    Language::Dan as u16,
+    Language::Deu as u16,
+    Language::Div as u16,
+    Language::Dzo as u16,
+    Language::Ell as u16,
+    Language::Eng as u16,
+    Language::Est as u16,
+    Language::Eus as u16,
+    Language::Ewe as u16,
+    Language::Fao as u16,
+    Language::Fas as u16,
+    Language::Fij as u16,
+    Language::Fin as u16,
+    Language::Fra as u16,
+    Language::Fry as u16,
+    Language::Ful as u16,
+    Language::Gla as u16,
+    Language::Gle as u16,
+    Language::Glg as u16,
+    Language::Glv as u16,
+    Language::Grn as u16,
+    Language::Guj as u16,
+    Language::Hat as u16,
+    Language::Hau as u16,
+    Language::Hbs as u16,
+    Language::Heb as u16,
+    Language::Her as u16,
+    Language::Hin as u16,
+    Language::Hmo as u16,
+    Language::Hrv as u16,
+    Language::Hun as u16,
+    Language::Hye as u16,
+    Language::Ibo as u16,
+    Language::Iii as u16,
+    Language::Iku as u16,
+    Language::Ind as u16,
+    Language::Ipk as u16,
+    Language::Isl as u16,
+    Language::Ita as u16,
+    Language::Jav as u16,
+    Language::Jpn as u16,
+    Language::Kal as u16,
+    Language::Kan as u16,
+    Language::Kas as u16,
+    Language::Kat as u16,
+    Language::Kau as u16,
+    Language::Kaz as u16,
+    Language::Khm as u16,
+    Language::Kik as u16,
+    Language::Kin as u16,
+    Language::Kir as u16,
+    Language::Kom as u16,
+    Language::Kon as u16,
+    Language::Kor as u16,
+    Language::Kua as u16,
+    Language::Kur as u16,
+    Language::Lao as u16,
+    Language::Lav as u16,
+    Language::Lim as u16,
+    Language::Lin as u16,
+    Language::Lit as u16,
+    Language::Ltz as u16,
+    Language::Lub as u16,
+    Language::Lug as u16,
+    Language::Mah as u16,
+    Language::Mal as u16,
+    Language::Mar as u16,
+    Language::Mkd as u16,
+    Language::Mlg as u16,
+    Language::Mlt as u16,
+    Language::Mon as u16,
+    Language::Mri as u16,
+    Language::Msa as u16,
+    Language::Mul as u16,
+    Language::Mya as u16,
+    Language::Nau as u16,
+    Language::Nav as u16,
+    Language::Nbl as u16,
+    Language::Nde as u16,
+    Language::Ndo as u16,
+    Language::Nep as u16,
+    Language::Nld as u16,
+    Language::Nno as u16,
+    Language::Nob as u16,
+    Language::Nor as u16,
+    Language::Nya as u16,
+    Language::Oci as u16,
+    Language::Oji as u16,
+    Language::Ori as u16,
+    Language::Orm as u16,
+    Language::Oss as u16,
+    Language::Pan as u16,
+    Language::Pol as u16,
+    Language::Por as u16,
+    Language::Pus as u16,
+    Language::Que as u16,
+    Language::Roh as u16,
+    Language::Ron as u16,
+    Language::Run as u16,
+    Language::Rus as u16,
+    Language::Sag as u16,
+    Language::Sin as u16,
+    Language::Slk as u16,
+    Language::Slv as u16,
+    Language::Sme as u16,
+    Language::Smo as u16,
+    Language::Sna as u16,
+    Language::Snd as u16,
+    Language::Som as u16,
+    Language::Sot as u16,
+    Language::Spa as u16,
+    Language::Sqi as u16,
+    Language::Srd as u16,
+    Language::Srp as u16,
+    Language::Ssw as u16,
+    Language::Sun as u16,
+    Language::Swa as u16,
+    Language::Swe as u16,
+    Language::Tah as u16,
+    Language::Tam as u16,
+    Language::Tat as u16,
+    Language::Tel as u16,
+    Language::Tgk as u16,
+    Language::Tgl as u16,
+    Language::Tha as u16,
+    Language::Tir as u16,
+    Language::Ton as u16,
+    Language::Tsn as u16,
+    Language::Tso as u16,
+    Language::Tuk as u16,
+    Language::Tur as u16,
+    Language::Twi as u16,
+    Language::Uig as u16,
+    Language::Ukr as u16,
+    Language::Und as u16,
+    Language::Urd as u16,
+    Language::Uzb as u16,
+    Language::Ven as u16,
+    Language::Vie as u16,
+    Language::Wln as u16,
+    Language::Wol as u16,
+    Language::Xho as u16,
+    Language::Yid as u16,
+    Language::Yor as u16,
+    Language::Zha as u16,
+    Language::Zho as u16,
+    Language::Zul as u16,
+    Language::Zxx as u16,
+];
+
+#[cfg(feature = "display_names_es")]
+pub(crate) static DISPLAY_NAMES_SPA: &str = concat!(
+    "afar",
+    "abjasio",
+    "afrikáans",
+    "akan",
+    "amárico",
+    "árabe",
+    "aragonés",
+    "asamés",
+    "avar",
+    "aimara",
+    "azerbaiyano",
+    "baskir",
+    "bambara",
+    "bielorruso",
+    "bengalí",
+    "bislama",
+    "tibetano",
+    "bosnio",
+    "bretón",
+    "búlgaro",
+    "catalán",
+    "checo",
+    "chamorro",
+    "checheno",
+    "chuvasio",
+    "córnico",
+    "corso",
+    "cree",
+    "galés",
+    "danés",
+    "alemán",
+    "divehi",
+    "dzongkha",
+    "griego",
+    "inglés",
+    "estonio",
+    "euskera",
+    "ewé",
+    "feroés",
+    "persa",
+    "fiyiano",
+    "finés",
+    "francés",
+    "frisón occidental",
+    "fula",
+    "gaélico escocés",
+    "irlandés",
+    "gallego",
+    "manés",
+    "guaraní",
+    "guyaratí",
+    "criollo haitiano",
+    "hausa",
+    "serbocroata",
+    "hebreo",
+    "herero",
+    "hindi",
+    "hiri motu",
+    "croata",
+    "húngaro",
+    "armenio",
+    "igbo",
+    "yi de Sichuán",
+    "inuktitut",
+    "indonesio",
+    "inupiaq",
+    "islandés",
+    "italiano",
+    "javanés",
+    "japonés",
+    "groenlandés",
+    "canarés",
+    "cachemir",
+    "georgiano",
+    "kanuri",
+    "kazajo",
+    "jemer",
+    "kikuyu",
+    "kinyarwanda",
+    "kirguís",
+    "komi",
+    "kongo",
+    "coreano",
+    "kuanyama",
+    "kurdo",
+    "lao",
+    "letón",
+    "limburgués",
+    "lingala",
+    "lituano",
+    "luxemburgués",
+    "luba-katanga",
+    "ganda",
+    "marshalés",
+    "malayálam",
+    "maratí",
+    "macedonio",
+    "malgache",
+    "maltés",
+    "mongol",
+    "maorí",
+    "malayo",
+    "varios idiomas",
+    "birmano",
+    "nauruano",
+    "navajo",
+    "ndebele meridional",
+    "ndebele septentrional",
+    "ndonga",
+    "nepalí",
+    "neerlandés",
+    "noruego nynorsk",
+    "noruego bokmal",
+    "noruego",
+    "nyanja",
+    "occitano",
+    "ojibwa",
+    "oriya",
+    "oromo",
+    "osético",
+    "punyabí",
+    "polaco",
+    "portugués",
+    "pastún",
+    "quechua",
+    "romanche",
+    "rumano",
+    "kirundi",
+    "ruso",
+    "sango",
+    "cingalés",
+    "eslovaco",
+    "esloveno",
+    "sami septentrional",
+    "samoano",
+    "shona",
+    "sindi",
+    "somalí",
+    "sotho meridional",
+    "español",
+    "albanés",
+    "sardo",
+    "serbio",
+    "suazi",
+    "sundanés",
+    "suajili",
+    "sueco",
+    "tahitiano",
+    "tamil",
+    "tártaro",
+    "telugu",
+    "tayiko",
+    "tagalo",
+    "tailandés",
+    "tigriña",
+    "tongano",
+    "setsuana",
+    "tsonga",
+    "turcomano",
+    "turco",
+    "twi",
+    "uigur",
+    "ucraniano",
+    "lengua desconocida",
+    "urdu",
+    "uzbeko",
+    "venda",
+    "vietnamita",
+    "valón",
+    "wólof",
+    "xhosa",
+    "yidis",
+    "yoruba",
+    "zhuang",
+    "chino",
+    "zulú",
+    "sin contenido lingüístico",
+);
+
+#[cfg(feature = "display_names_es")]
+pub(crate) static DISPLAY_NAMES_SPA_OFFSETS: [u16; 178] = [
+    0, 4, 11, 21, 25, 33, 39, 48, 55, 59, 65, 76, 82, 89, 99, 107, 114, 122,
+    128, 135, 143, 151, 156, 164, 172, 180, 188, 193, 197, 203, 209, 216, 222,
+    230, 236, 243, 250, 257, 261, 268, 273, 280, 286, 294, 312, 316, 333, 342,
+    349, 355, 363, 372, 388, 393, 404, 410, 416, 421, 430, 436, 444, 451, 455,
+    469, 478, 487, 494, 503, 511, 519, 527, 539, 547, 555, 564, 570, 576, 581,
+    587, 598, 606, 610, 615, 622, 630, 635, 638, 644, 655, 662, 669, 682, 694,
+    699, 709, 719, 726, 735, 743, 750, 756, 762, 768, 782, 789, 797, 803, 821,
+    842, 848, 855, 866, 881, 895, 902, 908, 916, 922, 927, 932, 940, 948, 954,
+    964, 971, 978, 986, 992, 999, 1003, 1008, 1017, 1025, 1033, 1051, 1058,
+    1063, 1068, 1075, 1091, 1099, 1107, 1112, 1118, 1123, 1132, 1139, 1144,
+    1153, 1158, 1166, 1172, 1178, 1184, 1194, 1202, 1209, 1217, 1223, 1232,
+    1237, 1240, 1245, 1254, 1272, 1276, 1282, 1287, 1297, 1303, 1309, 1314,
+    1319, 1325, 1331, 1336, 1341, 1368,
+];
+
+#[cfg(feature = "display_names_fr")]
+static DISPLAY_NAMES_FRA_LANGUAGES: [u16; 177] = [
+    Language::Aar as u16,
+    Language::Abk as u16,
+    Language::Afr as u16,
+    Language::Aka as u16,
+    Language::Amh as u16,
+    Language::Ara as u16,
+    Language::Arg as u16,
+    Language::Asm as u16,
+    Language::Ava as u16,
+    Language::Aym as u16,
+    Language::Aze as u16,
+    Language::Bak as u16,
+    Language::Bam as u16,
+    Language::Bel as u16,
+    Language::Ben as u16,
+    Language::Bis as u16,
+    Language::Bod as u16,
+    Language::Bos as u16,
+    Language::Bre as u16,
+    Language::Bul as u16,
+    Language::Cat as u16,
+    Language::Ces as u16,
+    Language::Cha as u16,
+    Language::Che as u16,
+    Language::Chv as u16,
+    Language::Cor as u16,
+    Language::Cos as u16,
+    Language::Cre as u16,
+    Language::Cym as u16,
+    Language::Dan as u16,
+    Language::Deu as u16,
+    Language::Div as u16,
+    Language::Dzo as u16,
+    Language::Ell as u16,
+    Language::Eng as u16,
+    Language::Est as u16,
+    Language::Eus as u16,
+    Language::Ewe as u16,
+    Language::Fao as u16,
+    Language::Fas as u16,
+    Language::Fij as u16,
+    Language::Fin as u16,
+    Language::Fra as u16,
+    Language::Fry as u16,
+    Language::Ful as u16,
+    Language::Gla as u16,
+    Language::Gle as u16,
+    Language::Glg as u16,
+    Language::Glv as u16,
+    Language::Grn as u16,
+    Language::Guj as u16,
+    Language::Hat as u16,
+    Language::Hau as u16,
+    Language::Hbs as u16,
+    Language::Heb as u16,
+    Language::Her as u16,
+    Language::Hin as u16,
+    Language::Hmo as u16,
+    Language::Hrv as u16,
+    Language::Hun as u16,
+    Language::Hye as u16,
+    Language::Ibo as u16,
+    Language::Iii as u16,
+    Language::Iku as u16,
+    Language::Ind as u16,
+    Language::Ipk as u16,
+    Language::Isl as u16,
+    Language::Ita as u16,
+    Language::Jav as u16,
+    Language::Jpn as u16,
+    Language::Kal as u16,
+    Language::Kan as u16,
+    Language::Kas as u16,
+    Language::Kat as u16,
+    Language::Kau as u16,
+    Language::Kaz as u16,
+    Language::Khm as u16,
+    Language::Kik as u16,
+    Language::Kin as u16,
+    Language::Kir as u16,
+    Language::Kom as u16,
+    Language::Kon as u16,
+    Language::Kor as u16,
+    Language::Kua as u16,
+    Language::Kur as u16,
+    Language::Lao as u16,
+    Language::Lav as u16,
+    Language::Lim as u16,
+    Language::Lin as u16,
+    Language::Lit as u16,
+    Language::Ltz as u16,
+    Language::Lub as u16,
+    Language::Lug as u16,
+    Language::Mah as u16,
+    Language::Mal as u16,
+    Language::Mar as u16,
+    Language::Mkd as u16,
+    Language::Mlg as u16,
+    Language::Mlt as u16,
+    Language::Mon as u16,
+    Language::Mri as u16,
+    Language::Msa as u16,
+    Language::Mul as u16,
+    Language::Mya as u16,
+    Language::Nau as u16,
+    Language::Nav as u16,
+    Language::Nbl as u16,
+    Language::Nde as u16,
+    Language::Ndo as u16,
+    Language::Nep as u16,
+    Language::Nld as u16,
+    Language::Nno as u16,
+    Language::Nob as u16,
+    Language::Nor as u16,
+    Language::Nya as u16,
+    Language::Oci as u16,
+    Language::Oji as u16,
+    Language::Ori as u16,
+    Language::Orm as u16,
+    Language::Oss as u16,
+    Language::Pan as u16,
+    Language::Pol as u16,
+    Language::Por as u16,
+    Language::Pus as u16,
+    Language::Que as u16,
+    Language::Roh as u16,
+    Language::Ron as u16,
+    Language::Run as u16,
+    Language::Rus as u16,
+    Language::Sag as u16,
+    Language::Sin as u16,
+    Language::Slk as u16,
+    Language::Slv as u16,
+    Language::Sme as u16,
+    Language::Smo as u16,
+    Language::Sna as u16,
+    Language::Snd as u16,
+    Language::Som as u16,
+    Language::Sot as u16,
+    Language::Spa as u16,
+    Language::Sqi as u16,
+    Language::Srd as u16,
+    Language::Srp as u16,
+    Language::Ssw as u16,
+    Language::Sun as u16,
+    Language::Swa as u16,
+    Language::Swe as u16,
+    Language::Tah as u16,
+    Language::Tam as u16,
+    Language::Tat as u16,
+    Language::Tel as u16,
+    Language::Tgk as u16,
+    Language::Tgl as u16,
+    Language::Tha as u16,
+    Language::Tir as u16,
+    Language::Ton as u16,
+    Language::Tsn as u16,
+    Language::Tso as u16,
+    Language::Tuk as u16,
+    Language::Tur as u16,
+    Language::Twi as u16,
+    Language::Uig as u16,
+    Language::Ukr as u16,
+    Language::Und as u16,
+    Language::Urd as u16,
+    Language::Uzb as u16,
+    Language::Ven as u16,
+    Language::Vie as u16,
+    Language::Wln as u16,
+    Language::Wol as u16,
+    Language::Xho as u16,
+    Language::Yid as u16,
+    Language::Yor as u16,
+    Language::Zha as u16,
+    Language::Zho as u16,
+    Language::Zul as u16,
+    Language::Zxx as u16,
+];
+
+#[cfg(feature = "display_names_fr")]
+pub(crate) static DISPLAY_NAMES_FRA: &str = concat!(
+    "afar",
+    "abkhaze",
+    "afrikaans",
+    "akan",
+    "amharique",
+    "arabe",
+    "aragonais",
+    "assamais",
+    "avar",
+    "aymara",
+    "azerbaïdjanais",
+    "bachkir",
+    "bambara",
+    "biélorusse",
+    "bengali",
+    "bichelamar",
+    "tibétain",
+    "bosniaque",
+    "breton",
+    "bulgare",
+    "catalan",
+    "tchèque",
+    "chamorro",
+    "tchétchène",
+    "tchouvache",
+    "cornique",
+    "corse",
+    "cree",
+    "gallois",
+    "danois",
+    "allemand",
+    "maldivien",
+    "dzongkha",
+    "grec",
+    "anglais",
+    "estonien",
+    "basque",
+    "éwé",
+    "féroïen",
+    "persan",
+    "fidjien",
+    "finnois",
+    "français",
+    "frison occidental",
+    "peul",
+    "gaélique écossais",
+    "irlandais",
+    "galicien",
+    "mannois",
+    "guarani",
+    "goudjarati",
+    "créole haïtien",
+    "haoussa",
+    "serbo-croate",
+    "hébreu",
+    "héréro",
+    "hindi",
+    "hiri motu",
+    "croate",
+    "hongrois",
+    "arménien",
+    "igbo",
+    "yi du Sichuan",
+    "inuktitut",
+    "indonésien",
+    "inupiaq",
+    "islandais",
+    "italien",
+    "javanais",
+    "japonais",
+    "groenlandais",
+    "kannada",
+    "cachemiri",
+    "géorgien",
+    "kanouri",
+    "kazakh",
+    "khmer",
+    "kikuyu",
+    "kinyarwanda",
+    "kirghize",
+    "komi",
+    "kikongo",
+    "coréen",
+    "kuanyama",
+    "kurde",
+    "lao",
+    "letton",
+    "limbourgeois",
+    "lingala",
+    "lituanien",
+    "luxembourgeois",
+    "luba-katanga (kiluba)",
+    "ganda",
+    "marshallais",
+    "malayalam",
+    "marathi",
+    "macédonien",
+    "malgache",
+    "maltais",
+    "mongol",
+    "maori",
+    "malais",
+    "multilingue",
+    "birman",
+    "nauruan",
+    "navajo",
+    "ndébélé du Sud",
+    "ndébélé du Nord",
+    "ndonga",
+    "népalais",
+    "néerlandais",
+    "norvégien nynorsk",
+    "norvégien bokmål",
+    "norvégien",
+    "chewa",
+    "occitan",
+    "ojibwa",
+    "odia",
+    "oromo",
+    "ossète",
+    "pendjabi",
+    "polonais",
+    "portugais",
+    "pachto",
+    "quechua",
+    "romanche",
+    "roumain",
+    "roundi",
+    "russe",
+    "sango",
+    "cingalais",
+    "slovaque",
+    "slovène",
+    "same du Nord",
+    "samoan",
+    "shona",
+    "sindhi",
+    "somali",
+    "sotho du Sud",
+    "espagnol",
+    "albanais",
+    "sarde",
+    "serbe",
+    "swati",
+    "soundanais",
+    "swahili",
+    "suédois",
+    "tahitien",
+    "tamoul",
+    "tatar",
+    "télougou",
+    "tadjik",
+    "tagalog",
+    "thaï",
+    "tigrigna",
+    "tongien",
+    "tswana",
+    "tsonga",
+    "turkmène",
+    "turc",
+    "twi",
+    "ouïghour",
+    "ukrainien",
+    "langue indéterminée",
+    "ourdou",
+    "ouzbek",
+    "venda",
+    "vietnamien",
+    "wallon",
+    "wolof",
+    "xhosa",
+    "yiddish",
+    "yoruba",
+    "zhuang",
+    "chinois",
+    "zoulou",
+    "sans contenu linguistique",
+);
+
+#[cfg(feature = "display_names_fr")]
+pub(crate) static DISPLAY_NAMES_FRA_OFFSETS: [u16; 178] = [
+    0, 4, 11, 20, 24, 33, 38, 47, 55, 59, 65, 80, 87, 94, 105, 112, 122, 131,
+    140, 146, 153, 160, 168, 176, 188, 198, 206, 211, 215, 222, 228, 236, 245,
+    253, 257, 264, 272, 278, 283, 292, 298, 305, 312, 321, 338, 342, 361, 370,
+    378, 385, 392, 402, 418, 425, 437, 444, 452, 457, 466, 472, 480, 489, 493,
+    506, 515, 526, 533, 542, 549, 557, 565, 577, 584, 593, 602, 609, 615, 620,
+    626, 637, 645, 649, 656, 663, 671, 676, 679, 685, 697, 704, 713, 727, 748,
+    753, 764, 773, 780, 791, 799, 806, 812, 817, 823, 834, 840, 847, 853, 870,
+    888, 894, 903, 915, 933, 951, 961, 966, 973, 979, 983, 988, 995, 1003,
+    1011, 1020, 1026, 1033, 1041, 1048, 1054, 1059, 1064, 1073, 1081, 1089,
+    1101, 1107, 1112, 1118, 1124, 1136, 1144, 1152, 1157, 1162, 1167, 1177,
+    1184, 1192, 1200, 1206, 1211, 1220, 1226, 1233, 1238, 1246, 1253, 1259,
+    1265, 1274, 1278, 1281, 1290, 1299, 1320, 1326, 1332, 1337, 1347, 1353,
+    1358, 1363, 1370, 1376, 1382, 1389, 1395, 1420,
+];
+
+#[cfg(feature = "display_names_it")]
+static DISPLAY_NAMES_ITA_LANGUAGES: [u16; 177] = [
+    Language::Aar as u16,
+    Language::Abk as u16,
+    Language::Afr as u16,
+    Language::Aka as u16,
+    Language::Amh as u16,
+    Language::Ara as u16,
+    Language::Arg as u16,
+    Language::Asm as u16,
+    Language::Ava as u16,
+    Language::Aym as u16,
+    Language::Aze as u16,
+    Language::Bak as u16,
+    Language::Bam as u16,
+    Language::Bel as u16,
+    Language::Ben as u16,
+    Language::Bis as u16,
+    Language::Bod as u16,
+    Language::Bos as u16,
+    Language::Bre as u16,
+    Language::Bul as u16,
+    Language::Cat as u16,
+    Language::Ces as u16,
+    Language::Cha as u16,
+    Language::Che as u16,
+    Language::Chv as u16,
+    Language::Cor as u16,
+    Language::Cos as u16,
+    Language::Cre as u16,
+    Language::Cym as u16,
+    Language::Dan as u16,
+    Language::Deu as u16,
+    Language::Div as u16,
+    Language::Dzo as u16,
+    Language::Ell as u16,
+    Language::Eng as u16,
+    Language::Est as u16,
+    Language::Eus as u16,
+    Language::Ewe as u16,
+    Language::Fao as u16,
+    Language::Fas as u16,
+    Language::Fij as u16,
+    Language::Fin as u16,
+    Language::Fra as u16,
+    Language::Fry as u16,
+    Language::Ful as u16,
+    Language::Gla as u16,
+    Language::Gle as u16,
+    Language::Glg as u16,
+    Language::Glv as u16,
+    Language::Grn as u16,
+    Language::Guj as u16,
+    Language::Hat as u16,
+    Language::Hau as u16,
+    Language::Hbs as u16,
+    Language::Heb as u16,
+    Language::Her as u16,
+    Language::Hin as u16,
+    Language::Hmo as u16,
+    Language::Hrv as u16,
+    Language::Hun as u16,
+    Language::Hye as u16,
+    Language::Ibo as u16,
+    Language::Iii as u16,
+    Language::Iku as u16,
+    Language::Ind as u16,
+    Language::Ipk as u16,
+    Language::Isl as u16,
+    Language::Ita as u16,
+    Language::Jav as u16,
+    Language::Jpn as u16,
+    Language::Kal as u16,
+    Language::Kan as u16,
+    Language::Kas as u16,
+    Language::Kat as u16,
+    Language::Kau as u16,
+    Language::Kaz as u16,
+    Language::Khm as u16,
+    Language::Kik as u16,
+    Language::Kin as u16,
+    Language::Kir as u16,
+    Language::Kom as u16,
+    Language::Kon as u16,
+    Language::Kor as u16,
+    Language::Kua as u16,
+    Language::Kur as u16,
+    Language::Lao as u16,
+    Language::Lav as u16,
+    Language::Lim as u16,
+    Language::Lin as u16,
+    Language::Lit as u16,
+    Language::Ltz as u16,
+    Language::Lub as u16,
+    Language::Lug as u16,
+    Language::Mah as u16,
+    Language::Mal as u16,
+    Language::Mar as u16,
+    Language::Mkd as u16,
+    Language::Mlg as u16,
+    Language::Mlt as u16,
+    Language::Mon as u16,
+    Language::Mri as u16,
+    Language::Msa as u16,
+    Language::Mul as u16,
+    Language::Mya as u16,
+    Language::Nau as u16,
+    Language::Nav as u16,
+    Language::Nbl as u16,
+    Language::Nde as u16,
+    Language::Ndo as u16,
+    Language::Nep as u16,
+    Language::Nld as u16,
+    Language::Nno as u16,
+    Language::Nob as u16,
+    Language::Nor as u16,
+    Language::Nya as u16,
+    Language::Oci as u16,
+    Language::Oji as u16,
+    Language::Ori as u16,
+    Language::Orm as u16,
+    Language::Oss as u16,
+    Language::Pan as u16,
+    Language::Pol as u16,
+    Language::Por as u16,
+    Language::Pus as u16,
+    Language::Que as u16,
+    Language::Roh as u16,
+    Language::Ron as u16,
+    Language::Run as u16,
+    Language::Rus as u16,
+    Language::Sag as u16,
+    Language::Sin as u16,
+    Language::Slk as u16,
+    Language::Slv as u16,
+    Language::Sme as u16,
+    Language::Smo as u16,
+    Language::Sna as u16,
+    Language::Snd as u16,
+    Language::Som as u16,
+    Language::Sot as u16,
+    Language::Spa as u16,
+    Language::Sqi as u16,
+    Language::Srd as u16,
+    Language::Srp as u16,
+    Language::Ssw as u16,
+    Language::Sun as u16,
+    Language::Swa as u16,
+    Language::Swe as u16,
+    Language::Tah as u16,
+    Language::Tam as u16,
+    Language::Tat as u16,
+    Language::Tel as u16,
+    Language::Tgk as u16,
+    Language::Tgl as u16,
+    Language::Tha as u16,
+    Language::Tir as u16,
+    Language::Ton as u16,
+    Language::Tsn as u16,
+    Language::Tso as u16,
+    Language::Tuk as u16,
+    Language::Tur as u16,
+    Language::Twi as u16,
+    Language::Uig as u16,
+    Language::Ukr as u16,
+    Language::Und as u16,
+    Language::Urd as u16,
+    Language::Uzb as u16,
+    Language::Ven as u16,
+    Language::Vie as u16,
+    Language::Wln as u16,
+    Language::Wol as u16,
+    Language::Xho as u16,
+    Language::Yid as u16,
+    Language::Yor as u16,
+    Language::Zha as u16,
+    Language::Zho as u16,
+    Language::Zul as u16,
+    Language::Zxx as u16,
+];
+
+#[cfg(feature = "display_names_it")]
+pub(crate) static DISPLAY_NAMES_ITA: &str = concat!(
+    "afar",
+    "abcaso",
+    "afrikaans",
+    "akan",
+    "amarico",
+    "arabo",
+    "aragonese",
+    "assamese",
+    "avaro",
+    "aymara",
+    "azerbaigiano",
+    "baschiro",
+    "bambara",
+    "bielorusso",
+    "bengalese",
+    "bislama",
+    "tibetano",
+    "bosniaco",
+    "bretone",
+    "bulgaro",
+    "catalano",
+    "ceco",
+    "chamorro",
+    "ceceno",
+    "ciuvascio",
+    "cornico",
+    "corso",
+    "cree",
+    "gallese",
+    "danese",
+    "tedesco",
+    "divehi",
+    "dzongkha",
+    "greco",
+    "inglese",
+    "estone",
+    "basco",
+    "ewe",
+    "faroese",
+    "persiano",
+    "figiano",
+    "finlandese",
+    "francese",
+    "frisone occidentale",
+    "fulah",
+    "gaelico scozzese",
+    "irlandese",
+    "galiziano",
+    "mannese",
+    "guaraní",
+    "gujarati",
+    "creolo haitiano",
+    "hausa",
+    "serbo-croato",
+    "ebraico",
+    "herero",
+    "hindi",
+    "hiri motu",
+    "croato",
+    "ungherese",
+    "armeno",
+    "igbo",
+    "sichuan yi",
+    "inuktitut",
+    "indonesiano",
+    "inupiak",
+    "islandese",
+    "italiano",
+    "giavanese",
+    "giapponese",
+    "groenlandese",
+    "kannada",
+    "kashmiri",
+    "georgiano",
+    "kanuri",
+    "kazako",
+    "khmer",
+    "kikuyu",
+    "kinyarwanda",
+    "kirghiso",
+    "komi",
+    "kongo",
+    "coreano",
+    "kuanyama",
+    "curdo",
+    "lao",
+    "lettone",
+    "limburghese",
+    "lingala",
+    "lituano",
+    "lussemburghese",
+    "luba-katanga",
+    "ganda",
+    "marshallese",
+    "malayalam",
+    "marathi",
+    "macedone",
+    "malgascio",
+    "maltese",
+    "mongolo",
+    "maori",
+    "malese",
+    "multilingua",
+    "birmano",
+    "nauru",
+    "navajo",
+    "ndebele del sud",
+    "ndebele del nord",
+    "ndonga",
+    "nepalese",
+    "olandese",
+    "norvegese nynorsk",
+    "norvegese bokmål",
+    "norvegese",
+    "nyanja",
+    "occitano",
+    "ojibwa",
+    "odia",
+    "oromo",
+    "ossetico",
+    "punjabi",
+    "polacco",
+    "portoghese",
+    "pashto",
+    "quechua",
+    "romancio",
+    "rumeno",
+    "rundi",
+    "russo",
+    "sango",
+    "singalese",
+    "slovacco",
+    "sloveno",
+    "sami del nord",
+    "samoano",
+    "shona",
+    "sindhi",
+    "somalo",
+    "sotho del sud",
+    "spagnolo",
+    "albanese",
+    "sardo",
+    "serbo",
+    "swati",
+    "sundanese",
+    "swahili",
+    "svedese",
+    "taitiano",
+    "tamil",
+    "tataro",
+    "telugu",
+    "tagico",
+    "tagalog",
+    "thailandese",
+    "tigrino",
+    "tongano",
+    "tswana",
+    "tsonga",
+    "turcomanno",
+    "turco",
+    "ci",
+    "uiguro",
+    "ucraino",
+    "lingua imprecisata",
+    "urdu",
+    "uzbeco",
+    "venda",
+    "vietnamita",
+    "vallone",
+    "wolof",
+    "xhosa",
+    "yiddish",
+    "yoruba",
+    "zhuang",
+    "cinese",
+    "zulu",
+    "nessun contenuto linguistico",
+);
+
+#[cfg(feature = "display_names_it")]
+pub(crate) static DISPLAY_NAMES_ITA_OFFSETS: [u16; 178] = [
+    0, 4, 10, 19, 23, 30, 35, 44, 52, 57, 63, 75, 83, 90, 100, 109, 116, 124,
+    132, 139, 146, 154, 158, 166, 172, 181, 188, 193, 197, 204, 210, 217, 223,
+    231, 236, 243, 249, 254, 257, 264, 272, 279, 289, 297, 316, 321, 337, 346,
+    355, 362, 370, 378, 393, 398, 410, 417, 423, 428, 437, 443, 452, 458, 462,
+    472, 481, 492, 499, 508, 516, 525, 535, 547, 554, 562, 571, 577, 583, 588,
+    594, 605, 613, 617, 622, 629, 637, 642, 645, 652, 663, 670, 677, 691, 703,
+    708, 719, 728, 735, 743, 752, 759, 766, 771, 777, 788, 795, 800, 806, 821,
+    837, 843, 851, 859, 876, 893, 902, 908, 916, 922, 926, 931, 939, 946, 953,
+    963, 969, 976, 984, 990, 995, 1000, 1005, 1014, 1022, 1029, 1042, 1049,
+    1054, 1060, 1066, 1079, 1087, 1095, 1100, 1105, 1110, 1119, 1126, 1133,
+    1141, 1146, 1152, 1158, 1164, 1171, 1182, 1189, 1196, 1202, 1208, 1218,
+    1223, 1225, 1231, 1238, 1256, 1260, 1266, 1271, 1281, 1288, 1293, 1298,
+    1305, 1311, 1317, 1323, 1327, 1355,
+];
+
+#[cfg(feature = "display_names_ja")]
+static DISPLAY_NAMES_JPN_LANGUAGES: [u16; 177] = [
+    Language::Aar as u16,
+    Language::Abk as u16,
+    Language::Afr as u16,
+    Language::Aka as u16,
+    Language::Amh as u16,
+    Language::Ara as u16,
+    Language::Arg as u16,
+    Language::Asm as u16,
+    Language::Ava as u16,
+    Language::Aym as u16,
+    Language::Aze as u16,
+    Language::Bak as u16,
+    Language::Bam as u16,
+    Language::Bel as u16,
+    Language::Ben as u16,
+    Language::Bis as u16,
+    Language::Bod as u16,
+    Language::Bos as u16,
+    Language::Bre as u16,
+    Language::Bul as u16,
+    Language::Cat as u16,
+    Language::Ces as u16,
+    Language::Cha as u16,
+    Language::Che as u16,
+    Language::Chv as u16,
+    Language::Cor as u16,
+    Language::Cos as u16,
+    Language::Cre as u16,
+    Language::Cym as u16,
+    Language::Dan as u16,
+    Language::Deu as u16,
+    Language::Div as u16,
+    Language::Dzo as u16,
+    Language::Ell as u16,
+    Language::Eng as u16,
+    Language::Est as u16,
+    Language::Eus as u16,
+    Language::Ewe as u16,
+    Language::Fao as u16,
+    Language::Fas as u16,
+    Language::Fij as u16,
+    Language::Fin as u16,
+    Language::Fra as u16,
+    Language::Fry as u16,
+    Language::Ful as u16,
+    Language::Gla as u16,
+    Language::Gle as u16,
+    Language::Glg as u16,
+    Language::Glv as u16,
+    Language::Grn as u16,
+    Language::Guj as u16,
+    Language::Hat as u16,
+    Language::Hau as u16,
+    Language::Hbs as u16,
+    Language::Heb as u16,
+    Language::Her as u16,
+    Language::Hin as u16,
+    Language::Hmo as u16,
+    Language::Hrv as u16,
+    Language::Hun as u16,
+    Language::Hye as u16,
+    Language::Ibo as u16,
+    Language::Iii as u16,
+    Language::Iku as u16,
+    Language::Ind as u16,
+    Language::Ipk as u16,
+    Language::Isl as u16,
+    Language::Ita as u16,
+    Language::Jav as u16,
+    Language::Jpn as u16,
+    Language::Kal as u16,
+    Language::Kan as u16,
+    Language::Kas as u16,
+    Language::Kat as u16,
+    Language::Kau as u16,
+    Language::Kaz as u16,
+    Language::Khm as u16,
+    Language::Kik as u16,
+    Language::Kin as u16,
+    Language::Kir as u16,
+    Language::Kom as u16,
+    Language::Kon as u16,
+    Language::Kor as u16,
+    Language::Kua as u16,
+    Language::Kur as u16,
+    Language::Lao as u16,
+    Language::Lav as u16,
+    Language::Lim as u16,
+    Language::Lin as u16,
+    Language::Lit as u16,
+    Language::Ltz as u16,
+    Language::Lub as u16,
+    Language::Lug as u16,
+    Language::Mah as u16,
+    Language::Mal as u16,
+    Language::Mar as u16,
+    Language::Mkd as u16,
+    Language::Mlg as u16,
+    Language::Mlt as u16,
+    Language::Mon as u16,
+    Language::Mri as u16,
+    Language::Msa as u16,
+    Language::Mul as u16,
+    Language::Mya as u16,
+    Language::Nau as u16,
+    Language::Nav as u16,
+    Language::Nbl as u16,
+    Language::Nde as u16,
+    Language::Ndo as u16,
+    Language::Nep as u16,
+    Language::Nld as u16,
+    Language::Nno as u16,
+    Language::Nob as u16,
+    Language::Nor as u16,
+    Language::Nya as u16,
+    Language::Oci as u16,
+    Language::Oji as u16,
+    Language::Ori as u16,
+    Language::Orm as u16,
+    Language::Oss as u16,
+    Language::Pan as u16,
+    Language::Pol as u16,
+    Language::Por as u16,
+    Language::Pus as u16,
+    Language::Que as u16,
+    Language::Roh as u16,
+    Language::Ron as u16,
+    Language::Run as u16,
+    Language::Rus as u16,
+    Language::Sag as u16,
+    Language::Sin as u16,
+    Language::Slk as u16,
+    Language::Slv as u16,
+    Language::Sme as u16,
+    Language::Smo as u16,
+    Language::Sna as u16,
+    Language::Snd as u16,
+    Language::Som as u16,
+    Language::Sot as u16,
+    Language::Spa as u16,
+    Language::Sqi as u16,
+    Language::Srd as u16,
+    Language::Srp as u16,
+    Language::Ssw as u16,
+    Language::Sun as u16,
+    Language::Swa as u16,
+    Language::Swe as u16,
+    Language::Tah as u16,
+    Language::Tam as u16,
+    Language::Tat as u16,
+    Language::Tel as u16,
+    Language::Tgk as u16,
+    Language::Tgl as u16,
+    Language::Tha as u16,
+    Language::Tir as u16,
+    Language::Ton as u16,
+    Language::Tsn as u16,
+    Language::Tso as u16,
+    Language::Tuk as u16,
+    Language::Tur as u16,
+    Language::Twi as u16,
+    Language::Uig as u16,
+    Language::Ukr as u16,
+    Language::Und as u16,
+    Language::Urd as u16,
+    Language::Uzb as u16,
+    Language::Ven as u16,
+    Language::Vie as u16,
+    Language::Wln as u16,
+    Language::Wol as u16,
+    Language::Xho as u16,
+    Language::Yid as u16,
+    Language::Yor as u16,
+    Language::Zha as u16,
+    Language::Zho as u16,
+    Language::Zul as u16,
+    Language::Zxx as u16,
+];
+
+#[cfg(feature = "display_names_ja")]
+pub(crate) static DISPLAY_NAMES_JPN: &str = concat!(
+    "アファル語",
+    "アブハズ語",
+    "アフリカーンス語",
+    "アカン語",
+    "アムハラ語",
+    "アラビア語",
+    "アラゴン語",
+    "アッサム語",
+    "アヴァル語",
+    "アイマラ語",
+    "アゼルバイジャン語",
+    "バシキール語",
+    "バンバラ語",
+    "ベラルーシ語",
+    "ベンガル語",
+    "ビスラマ語",
+    "チベット語",
+    "ボスニア語",
+    "ブルトン語",
+    "ブルガリア語",
+    "カタロニア語",
+    "チェコ語",
+    "チャモロ語",
+    "チェチェン語",
+    "チュヴァシ語",
+    "コーンウォール語",
+    "コルシカ語",
+    "クリー語",
+    "ウェールズ語",
+    "デンマーク語",
+    "ドイツ語",
+    "ディベヒ語",
+    "ゾンカ語",
+    "ギリシャ語",
+    "英語",
+    "エストニア語",
+    "バスク語",
+    "エウェ語",
+    "フェロー語",
+    "ペルシア語",
+    "フィジー語",
+    "フィンランド語",
+    "フランス語",
+    "西フリジア語",
+    "フラ語",
+    "スコットランド・ゲール語",
+    "アイルランド語",
+    "ガリシア語",
+    "マン島語",
+    "グアラニー語",
+    "グジャラート語",
+    "ハイチ・クレオール語",
+    "ハウサ語",
+    "セルボ・クロアチア語",
+    "ヘブライ語",
+    "ヘレロ語",
+    "ヒンディー語",
+    "ヒリモツ語",
+    "クロアチア語",
+    "ハンガリー語",
+    "アルメニア語",
+    "イボ語",
+    "四川イ語",
+    "イヌクティトット語",
+    "インドネシア語",
+    "イヌピアック語",
+    "アイスランド語",
+    "イタリア語",
+    "ジャワ語",
+    "日本語",
+    "グリーンランド語",
+    "カンナダ語",
+    "カシミール語",
+    "ジョージア語",
+    "カヌリ語",
+    "カザフ語",
+    "クメール語",
+    "キクユ語",
+    "キニアルワンダ語",
+    "キルギス語",
+    "コミ語",
+    "コンゴ語",
+    "韓国語",
+    "クワニャマ語",
+    "クルド語",
+    "ラオ語",
+    "ラトビア語",
+    "リンブルフ語",
+    "リンガラ語",
+    "リトアニア語",
+    "ルクセンブルク語",
+    "ルバ・カタンガ語",
+    "ガンダ語",
+    "マーシャル語",
+    "マラヤーラム語",
+    "マラーティー語",
+    "マケドニア語",
+    "マダガスカル語",
+    "マルタ語",
+    "モンゴル語",
+    "マオリ語",
+    "マレー語",
+    "複数言語",
+    "ミャンマー語",
+    "ナウル語",
+    "ナバホ語",
+    "南ンデベレ語",
+    "北ンデベレ語",
+    "ンドンガ語",
+    "ネパール語",
+    "オランダ語",
+    "ノルウェー語(ニーノシュク)",
+    "ノルウェー語(ブークモール)",
+    "ノルウェー語",
+    "ニャンジャ語",
+    "オック語",
+    "オジブウェー語",
+    "オディア語",
+    "オロモ語",
+    "オセット語",
+    "パンジャブ語",
+    "ポーランド語",
+    "ポルトガル語",
+    "パシュトゥー語",
+    "ケチュア語",
+    "ロマンシュ語",
+    "ルーマニア語",
+    "ルンディ語",
+    "ロシア語",
+    "サンゴ語",
+    "シンハラ語",
+    "スロバキア語",
+    "スロベニア語",
+    "北サーミ語",
+    "サモア語",
+    "ショナ語",
+    "シンド語",
+    "ソマリ語",
+    "南部ソト語",
+    "スペイン語",
+    "アルバニア語",
+    "サルデーニャ語",
+    "セルビア語",
+    "スワジ語",
+    "スンダ語",
+    "スワヒリ語",
+    "スウェーデン語",
+    "タヒチ語",
+    "タミル語",
+    "タタール語",
+    "テルグ語",
+    "タジク語",
+    "タガログ語",
+    "タイ語",
+    "ティグリニア語",
+    "トンガ語",
+    "ツワナ語",
+    "ツォンガ語",
+    "トルクメン語",
+    "トルコ語",
+    "トウィ語",
+    "ウイグル語",
+    "ウクライナ語",
+    "言語不明",
+    "ウルドゥー語",
+    "ウズベク語",
+    "ベンダ語",
+    "ベトナム語",
+    "ワロン語",
+    "ウォロフ語",
+    "コサ語",
+    "イディッシュ語",
+    "ヨルバ語",
+    "チワン語",
+    "中国語",
+    "ズールー語",
+    "言語的内容なし",
+);
+
+#[cfg(feature = "display_names_ja")]
+pub(crate) static DISPLAY_NAMES_JPN_OFFSETS: [u16; 178] = [
+    0, 15, 30, 54, 66, 81, 96, 111, 126, 141, 156, 183, 201, 216, 234, 249,
+    264, 279, 294, 309, 327, 345, 357, 372, 390, 408, 432, 447, 459, 477, 495,
+    507, 522, 534, 549, 555, 573, 585, 597, 612, 627, 642, 663, 678, 696, 705,
+    741, 762, 777, 789, 807, 828, 858, 870, 900, 915, 927, 945, 960, 978, 996,
+    1014, 1023, 1035, 1062, 1083, 1104, 1125, 1140, 1152, 1161, 1185, 1200,
+    1218, 1236, 1248, 1260, 1275, 1287, 1311, 1326, 1335, 1347, 1356, 1374,
+    1386, 1395, 1410, 1428, 1443, 1461, 1485, 1509, 1521, 1539, 1560, 1581,
+    1599, 1620, 1632, 1647, 1659, 1671, 1683, 1701, 1713, 1725, 1743, 1761,
+    1776, 1791, 1806, 1844, 1882, 1900, 1918, 1930, 1951, 1966, 1978, 1993,
+    2011, 2029, 2047, 2068, 2083, 2101, 2119, 2134, 2146, 2158, 2173, 2191,
+    2209, 2224, 2236, 2248, 2260, 2272, 2287, 2302, 2320, 2341, 2356, 2368,
+    2380, 2395, 2416, 2428, 2440, 2455, 2467, 2479, 2494, 2503, 2524, 2536,
+    2548, 2563, 2581, 2593, 2605, 2620, 2638, 2650, 2668, 2683, 2695, 2710,
+    2722, 2737, 2746, 2767, 2779, 2791, 2800, 2815, 2836,
+];
+
+#[cfg(feature = "display_names_pt")]
+static DISPLAY_NAMES_POR_LANGUAGES: [u16; 177] = [
+    Language::Aar as u16,
+    Language::Abk as u16,
+    Language::Afr as u16,
+    Language::Aka as u16,
+    Language::Amh as u16,
+    Language::Ara as u16,
+    Language::Arg as u16,
+    Language::Asm as u16,
+    Language::Ava as u16,
+    Language::Aym as u16,
+    Language::Aze as u16,
+    Language::Bak as u16,
+    Language::Bam as u16,
+    Language::Bel as u16,
+    Language::Ben as u16,
+    Language::Bis as u16,
+    Language::Bod as u16,
+    Language::Bos as u16,
+    Language::Bre as u16,
+    Language::Bul as u16,
+    Language::Cat as u16,
+    Language::Ces as u16,
+    Language::Cha as u16,
+    Language::Che as u16,
+    Language::Chv as u16,
+    Language::Cor as u16,
+    Language::Cos as u16,
+    Language::Cre as u16,
+    Language::Cym as u16,
+    Language::Dan as u16,
+    Language::Deu as u16,
+    Language::Div as u16,
+    Language::Dzo as u16,
+    Language::Ell as u16,
+    Language::Eng as u16,
+    Language::Est as u16,
+    Language::Eus as u16,
+    Language::Ewe as u16,
+    Language::Fao as u16,
+    Language::Fas as u16,
+    Language::Fij as u16,
+    Language::Fin as u16,
+    Language::Fra as u16,
+    Language::Fry as u16,
+    Language::Ful as u16,
+    Language::Gla as u16,
+    Language::Gle as u16,
+    Language::Glg as u16,
+    Language::Glv as u16,
+    Language::Grn as u16,
+    Language::Guj as u16,
+    Language::Hat as u16,
+    Language::Hau as u16,
+    Language::Hbs as u16,
+    Language::Heb as u16,
+    Language::Her as u16,
+    Language::Hin as u16,
+    Language::Hmo as u16,
+    Language::Hrv as u16,
+    Language::Hun as u16,
+    Language::Hye as u16,
+    Language::Ibo as u16,
+    Language::Iii as u16,
+    Language::Iku as u16,
+    Language::Ind as u16,
+    Language::Ipk as u16,
+    Language::Isl as u16,
+    Language::Ita as u16,
+    Language::Jav as u16,
+    Language::Jpn as u16,
+    Language::Kal as u16,
+    Language::Kan as u16,
+    Language::Kas as u16,
+    Language::Kat as u16,
+    Language::Kau as u16,
+    Language::Kaz as u16,
+    Language::Khm as u16,
+    Language::Kik as u16,
+    Language::Kin as u16,
+    Language::Kir as u16,
+    Language::Kom as u16,
+    Language::Kon as u16,
+    Language::Kor as u16,
+    Language::Kua as u16,
+    Language::Kur as u16,
+    Language::Lao as u16,
+    Language::Lav as u16,
+    Language::Lim as u16,
+    Language::Lin as u16,
+    Language::Lit as u16,
+    Language::Ltz as u16,
+    Language::Lub as u16,
+    Language::Lug as u16,
+    Language::Mah as u16,
+    Language::Mal as u16,
+    Language::Mar as u16,
+    Language::Mkd as u16,
+    Language::Mlg as u16,
+    Language::Mlt as u16,
+    Language::Mon as u16,
+    Language::Mri as u16,
+    Language::Msa as u16,
+    Language::Mul as u16,
+    Language::Mya as u16,
+    Language::Nau as u16,
+    Language::Nav as u16,
+    Language::Nbl as u16,
+    Language::Nde as u16,
+    Language::Ndo as u16,
+    Language::Nep as u16,
+    Language::Nld as u16,
+    Language::Nno as u16,
+    Language::Nob as u16,
+    Language::Nor as u16,
+    Language::Nya as u16,
+    Language::Oci as u16,
+    Language::Oji as u16,
+    Language::Ori as u16,
+    Language::Orm as u16,
+    Language::Oss as u16,
+    Language::Pan as u16,
+    Language::Pol as u16,
+    Language::Por as u16,
+    Language::Pus as u16,
+    Language::Que as u16,
+    Language::Roh as u16,
+    Language::Ron as u16,
+    Language::Run as u16,
+    Language::Rus as u16,
+    Language::Sag as u16,
+    Language::Sin as u16,
+    Language::Slk as u16,
+    Language::Slv as u16,
+    Language::Sme as u16,
+    Language::Smo as u16,
+    Language::Sna as u16,
+    Language::Snd as u16,
+    Language::Som as u16,
+    Language::Sot as u16,
+    Language::Spa as u16,
+    Language::Sqi as u16,
+    Language::Srd as u16,
+    Language::Srp as u16,
+    Language::Ssw as u16,
+    Language::Sun as u16,
+    Language::Swa as u16,
+    Language::Swe as u16,
+    Language::Tah as u16,
+    Language::Tam as u16,
+    Language::Tat as u16,
+    Language::Tel as u16,
+    Language::Tgk as u16,
+    Language::Tgl as u16,
+    Language::Tha as u16,
+    Language::Tir as u16,
+    Language::Ton as u16,
+    Language::Tsn as u16,
+    Language::Tso as u16,
+    Language::Tuk as u16,
+    Language::Tur as u16,
+    Language::Twi as u16,
+    Language::Uig as u16,
+    Language::Ukr as u16,
+    Language::Und as u16,
+    Language::Urd as u16,
+    Language::Uzb as u16,
+    Language::Ven as u16,
+    Language::Vie as u16,
+    Language::Wln as u16,
+    Language::Wol as u16,
+    Language::Xho as u16,
+    Language::Yid as u16,
+    Language::Yor as u16,
+    Language::Zha as u16,
+    Language::Zho as u16,
+    Language::Zul as u16,
+    Language::Zxx as u16,
+];
+
+#[cfg(feature = "display_names_pt")]
+pub(crate) static DISPLAY_NAMES_POR: &str = concat!(
+    "afar",
+    "abcázio",
+    "africâner",
+    "akan",
+    "amárico",
+    "árabe",
+    "aragonês",
+    "assamês",
+    "avárico",
+    "aimará",
+    "azerbaijano",
+    "bashkir",
+    "bambara",
+    "bielorrusso",
+    "bengali",
+    "bislamá",
+    "tibetano",
+    "bósnio",
+    "bretão",
+    "búlgaro",
+    "catalão",
+    "tcheco",
+    "chamorro",
+    "checheno",
+    "tchuvache",
+    "córnico",
+    "corso",
+    "cree",
+    "galês",
+    "dinamarquês",
+    "alemão",
+    "divehi",
+    "dzonga",
+    "grego",
+    "inglês",
+    "estoniano",
+    "basco",
+    "ewe",
+    "feroês",
+    "persa",
+    "fijiano",
+    "finlandês",
+    "francês",
+    "frísio ocidental",
+    "fula",
+    "gaélico escocês",
+    "irlandês",
+    "galego",
+    "manx",
+    "guarani",
+    "guzerate",
+    "haitiano",
+    "hauçá",
+    "servo-croata",
+    "hebraico",
+    "herero",
+    "híndi",
+    "hiri motu",
+    "croata",
+    "húngaro",
+    "armênio",
+    "igbo",
+    "sichuan yi",
+    "inuktitut",
+    "indonésio",
+    "inupiaque",
+    "islandês",
+    "italiano",
+    "javanês",
+    "japonês",
+    "groenlandês",
+    "canarim",
+    "caxemira",
+    "georgiano",
+    "canúri",
+    "cazaque",
+    "khmer",
+    "quicuio",
+    "quiniaruanda",
+    "quirguiz",
+    "komi",
+    "congolês",
+    "coreano",
+    "cuanhama",
+    "curdo",
+    "laosiano",
+    "letão",
+    "limburguês",
+    "lingala",
+    "lituano",
+    "luxemburguês",
+    "luba-catanga",
+    "luganda",
+    "marshalês",
+    "malaiala",
+    "marati",
+    "macedônio",
+    "malgaxe",
+    "maltês",
+    "mongol",
+    "maori",
+    "malaio",
+    "múltiplos idiomas",
+    "birmanês",
+    "nauruano",
+    "navajo",
+    "ndebele do sul",
+    "ndebele do norte",
+    "dongo",
+    "nepalês",
+    "holandês",
+    "nynorsk norueguês",
+    "bokmål norueguês",
+    "norueguês",
+    "nianja",
+    "occitânico",
+    "ojibwa",
+    "oriá",
+    "oromo",
+    "osseto",
+    "panjabi",
+    "polonês",
+    "português",
+    "pashto",
+    "quíchua",
+    "romanche",
+    "romeno",
+    "rundi",
+    "russo",
+    "sango",
+    "cingalês",
+    "eslovaco",
+    "esloveno",
+    "sami setentrional",
+    "samoano",
+    "xona",
+    "sindi",
+    "somali",
+    "soto do sul",
+    "espanhol",
+    "albanês",
+    "sardo",
+    "sérvio",
+    "suázi",
+    "sundanês",
+    "suaíli",
+    "sueco",
+    "taitiano",
+    "tâmil",
+    "tártaro",
+    "télugo",
+    "tadjique",
+    "tagalo",
+    "tailandês",
+    "tigrínia",
+    "tonganês",
+    "tswana",
+    "tsonga",
+    "turcomeno",
+    "turco",
+    "twi",
+    "uigur",
+    "ucraniano",
+    "idioma desconhecido",
+    "urdu",
+    "uzbeque",
+    "venda",
+    "vietnamita",
+    "valão",
+    "uolofe",
+    "xhosa",
+    "iídiche",
+    "iorubá",
+    "zhuang",
+    "chinês",
+    "zulu",
+    "sem conteúdo linguístico",
+);
+
+#[cfg(feature = "display_names_pt")]
+pub(crate) static DISPLAY_NAMES_POR_OFFSETS: [u16; 178] = [
+    0, 4, 12, 22, 26, 34, 40, 49, 57, 65, 72, 83, 90, 97, 108, 115, 123, 131,
+    138, 145, 153, 161, 167, 175, 183, 192, 200, 205, 209, 215, 227, 234, 240,
+    246, 251, 258, 267, 272, 275, 282, 287, 294, 304, 312, 329, 333, 350, 359,
+    365, 369, 376, 384, 392, 399, 411, 419, 425, 431, 440, 446, 454, 462, 466,
+    476, 485, 495, 504, 513, 521, 529, 537, 549, 556, 564, 573, 580, 587, 592,
+    599, 611, 619, 623, 632, 639, 647, 652, 660, 666, 677, 684, 691, 704, 716,
+    723, 733, 741, 747, 757, 764, 771, 777, 782, 788, 806, 815, 823, 829, 843,
+    859, 864, 872, 881, 899, 917, 927, 933, 944, 950, 955, 960, 966, 973, 981,
+    991, 997, 1005, 1013, 1019, 1024, 1029, 1034, 1043, 1051, 1059, 1076, 1083,
+    1087, 1092, 1098, 1109, 1117, 1125, 1130, 1137, 1143, 1152, 1159, 1164,
+    1172, 1178, 1186, 1193, 1201, 1207, 1217, 1226, 1235, 1241, 1247, 1256,
+    1261, 1264, 1269, 1278, 1297, 1301, 1308, 1313, 1323, 1329, 1335, 1340,
+    1348, 1355, 1361, 1368, 1372, 1398,
+];
+
+#[cfg(feature = "display_names_ru")]
+static DISPLAY_NAMES_RUS_LANGUAGES: [u16; 177] = [
+    Language::Aar as u16,
+    Language::Abk as u16,
+    Language::Afr as u16,
+    Language::Aka as u16,
+    Language::Amh as u16,
+    Language::Ara as u16,
+    Language::Arg as u16,
+    Language::Asm as u16,
+    Language::Ava as u16,
+    Language::Aym as u16,
+    Language::Aze as u16,
+    Language::Bak as u16,
+    Language::Bam as u16,
+    Language::Bel as u16,
+    Language::Ben as u16,
+    Language::Bis as u16,
+    Language::Bod as u16,
+    Language::Bos as u16,
+    Language::Bre as u16,
+    Language::Bul as u16,
+    Language::Cat as u16,
+    Language::Ces as u16,
+    Language::Cha as u16,
+    Language::Che as u16,
+    Language::Chv as u16,
+    Language::Cor as u16,
+    Language::Cos as u16,
+    Language::Cre as u16,
+    Language::Cym as u16,
+    Language::Dan as u16,
+    Language::Deu as u16,
+    Language::Div as u16,
+    Language::Dzo as u16,
+    Language::Ell as u16,
+    Language::Eng as u16,
+    Language::Est as u16,
+    Language::Eus as u16,
+    Language::Ewe as u16,
+    Language::Fao as u16,
+    Language::Fas as u16,
+    Language::Fij as u16,
+    Language::Fin as u16,
+    Language::Fra as u16,
+    Language::Fry as u16,
+    Language::Ful as u16,
+    Language::Gla as u16,
+    Language::Gle as u16,
+    Language::Glg as u16,
+    Language::Glv as u16,
+    Language::Grn as u16,
+    Language::Guj as u16,
+    Language::Hat as u16,
+    Language::Hau as u16,
+    Language::Hbs as u16,
+    Language::Heb as u16,
+    Language::Her as u16,
+    Language::Hin as u16,
+    Language::Hmo as u16,
+    Language::Hrv as u16,
+    Language::Hun as u16,
+    Language::Hye as u16,
+    Language::Ibo as u16,
+    Language::Iii as u16,
+    Language::Iku as u16,
+    Language::Ind as u16,
+    Language::Ipk as u16,
+    Language::Isl as u16,
+    Language::Ita as u16,
+    Language::Jav as u16,
+    Language::Jpn as u16,
+    Language::Kal as u16,
+    Language::Kan as u16,
+    Language::Kas as u16,
+    Language::Kat as u16,
+    Language::Kau as u16,
+    Language::Kaz as u16,
+    Language::Khm as u16,
+    Language::Kik as u16,
+    Language::Kin as u16,
+    Language::Kir as u16,
+    Language::Kom as u16,
+    Language::Kon as u16,
+    Language::Kor as u16,
+    Language::Kua as u16,
+    Language::Kur as u16,
+    Language::Lao as u16,
+    Language::Lav as u16,
+    Language::Lim as u16,
+    Language::Lin as u16,
+    Language::Lit as u16,
+    Language::Ltz as u16,
+    Language::Lub as u16,
+    Language::Lug as u16,
+    Language::Mah as u16,
+    Language::Mal as u16,
+    Language::Mar as u16,
+    Language::Mkd as u16,
+    Language::Mlg as u16,
+    Language::Mlt as u16,
+    Language::Mon as u16,
+    Language::Mri as u16,
+    Language::Msa as u16,
+    Language::Mul as u16,
+    Language::Mya as u16,
+    Language::Nau as u16,
+    Language::Nav as u16,
+    Language::Nbl as u16,
+    Language::Nde as u16,
+    Language::Ndo as u16,
+    Language::Nep as u16,
+    Language::Nld as u16,
+    Language::Nno as u16,
+    Language::Nob as u16,
+    Language::Nor as u16,
+    Language::Nya as u16,
+    Language::Oci as u16,
+    Language::Oji as u16,
+    Language::Ori as u16,
+    Language::Orm as u16,
+    Language::Oss as u16,
+    Language::Pan as u16,
+    Language::Pol as u16,
+    Language::Por as u16,
+    Language::Pus as u16,
+    Language::Que as u16,
+    Language::Roh as u16,
+    Language::Ron as u16,
+    Language::Run as u16,
+    Language::Rus as u16,
+    Language::Sag as u16,
+    Language::Sin as u16,
+    Language::Slk as u16,
+    Language::Slv as u16,
+    Language::Sme as u16,
+    Language::Smo as u16,
+    Language::Sna as u16,
+    Language::Snd as u16,
+    Language::Som as u16,
+    Language::Sot as u16,
+    Language::Spa as u16,
+    Language::Sqi as u16,
+    Language::Srd as u16,
+    Language::Srp as u16,
+    Language::Ssw as u16,
+    Language::Sun as u16,
+    Language::Swa as u16,
+    Language::Swe as u16,
+    Language::Tah as u16,
+    Language::Tam as u16,
+    Language::Tat as u16,
+    Language::Tel as u16,
+    Language::Tgk as u16,
+    Language::Tgl as u16,
+    Language::Tha as u16,
+    Language::Tir as u16,
+    Language::Ton as u16,
+    Language::Tsn as u16,
+    Language::Tso as u16,
+    Language::Tuk as u16,
+    Language::Tur as u16,
+    Language::Twi as u16,
+    Language::Uig as u16,
+    Language::Ukr as u16,
+    Language::Und as u16,
+    Language::Urd as u16,
+    Language::Uzb as u16,
+    Language::Ven as u16,
+    Language::Vie as u16,
+    Language::Wln as u16,
+    Language::Wol as u16,
+    Language::Xho as u16,
+    Language::Yid as u16,
+    Language::Yor as u16,
+    Language::Zha as u16,
+    Language::Zho as u16,
+    Language::Zul as u16,
+    Language::Zxx as u16,
+];
+
+#[cfg(feature = "display_names_ru")]
+pub(crate) static DISPLAY_NAMES_RUS: &str = concat!(
+    "афарский",
+    "абхазский",
+    "африкаанс",
+    "акан",
+    "амхарский",
+    "арабский",
+    "арагонский",
+    "ассамский",
+    "аварский",
+    "аймара",
+    "азербайджанский",
+    "башкирский",
+    "бамбара",
+    "белорусский",
+    "бенгальский",
+    "бислама",
+    "тибетский",
+    "боснийский",
+    "бретонский",
+    "болгарский",
+    "каталанский",
+    "чешский",
+    "чаморро",
+    "чеченский",
+    "чувашский",
+    "корнский",
+    "корсиканский",
+    "кри",
+    "валлийский",
+    "датский",
+    "немецкий",
+    "мальдивский",
+    "дзонг-кэ",
+    "греческий",
+    "английский",
+    "эстонский",
+    "баскский",
+    "эве",
+    "фарерский",
+    "персидский",
+    "фиджи",
+    "финский",
+    "французский",
+    "западнофризский",
+    "фулах",
+    "гэльский",
+    "ирландский",
+    "галисийский",
+    "мэнский",
+    "гуарани",
+    "гуджарати",
+    "гаитянский",
+    "хауса",
+    "сербскохорватский",
+    "иврит",
+    "гереро",
+    "хинди",
+    "хиримоту",
+    "хорватский",
+    "венгерский",
+    "армянский",
+    "игбо",
+    "носу",
+    "инуктитут",
+    "индонезийский",
+    "инупиак",
+    "исландский",
+    "итальянский",
+    "яванский",
+    "японский",
+    "гренландский",
+    "каннада",
+    "кашмири",
+    "грузинский",
+    "канури",
+    "казахский",
+    "кхмерский",
+    "кикуйю",
+    "киньяруанда",
+    "киргизский",
+    "коми",
+    "конго",
+    "корейский",
+    "кунама",
+    "курдский",
+    "лаосский",
+    "латышский",
+    "лимбургский",
+    "лингала",
+    "литовский",
+    "люксембургский",
+    "луба-катанга",
+    "ганда",
+    "маршалльский",
+    "малаялам",
+    "маратхи",
+    "македонский",
+    "малагасийский",
+    "мальтийский",
+    "монгольский",
+    "маори",
+    "малайский",
+    "языки разных семей",
+    "бирманский",
+    "науру",
+    "навахо",
+    "южный ндебеле",
+    "северный ндебеле",
+    "ндонга",
+    "непальский",
+    "нидерландский",
+    "нюнорск",
+    "норвежский букмол",
+    "норвежский",
+    "ньянджа",
+    "окситанский",
+    "оджибва",
+    "ория",
+    "оромо",
+    "осетинский",
+    "панджаби",
+    "польский",
+    "португальский",
+    "пушту",
+    "кечуа",
+    "романшский",
+    "румынский",
+    "рунди",
+    "русский",
+    "санго",
+    "сингальский",
+    "словацкий",
+    "словенский",
+    "северносаамский",
+    "самоанский",
+    "шона",
+    "синдхи",
+    "сомали",
+    "южный сото",
+    "испанский",
+    "албанский",
+    "сардинский",
+    "сербский",
+    "свази",
+    "сунданский",
+    "суахили",
+    "шведский",
+    "таитянский",
+    "тамильский",
+    "татарский",
+    "телугу",
+    "таджикский",
+    "тагалог",
+    "тайский",
+    "тигринья",
+    "тонганский",
+    "тсвана",
+    "тсонга",
+    "туркменский",
+    "турецкий",
+    "тви",
+    "уйгурский",
+    "украинский",
+    "неизвестный язык",
+    "урду",
+    "узбекский",
+    "венда",
+    "вьетнамский",
+    "валлонский",
+    "волоф",
+    "коса",
+    "идиш",
+    "йоруба",
+    "чжуань",
+    "китайский",
+    "зулу",
+    "нет языкового материала",
+);
+
+#[cfg(feature = "display_names_ru")]
+pub(crate) static DISPLAY_NAMES_RUS_OFFSETS: [u16; 178] = [
+    0, 16, 34, 52, 60, 78, 94, 114, 132, 148, 160, 190, 210, 224, 246, 268,
+    282, 300, 320, 340, 360, 382, 396, 410, 428, 446, 462, 486, 492, 512, 526,
+    542, 564, 579, 597, 617, 635, 651, 657, 675, 695, 705, 719, 741, 771, 781,
+    797, 817, 839, 853, 867, 885, 905, 915, 949, 959, 971, 981, 997, 1017,
+    1037, 1055, 1063, 1071, 1089, 1115, 1129, 1149, 1171, 1187, 1203, 1227,
+    1241, 1255, 1275, 1287, 1305, 1323, 1335, 1357, 1377, 1385, 1395, 1413,
+    1425, 1441, 1457, 1475, 1497, 1511, 1529, 1557, 1580, 1590, 1614, 1630,
+    1644, 1666, 1692, 1714, 1736, 1746, 1764, 1798, 1818, 1828, 1840, 1865,
+    1896, 1908, 1928, 1954, 1968, 2001, 2021, 2035, 2057, 2071, 2079, 2089,
+    2109, 2125, 2141, 2167, 2177, 2187, 2207, 2225, 2235, 2249, 2259, 2281,
+    2299, 2319, 2349, 2369, 2377, 2389, 2401, 2420, 2438, 2456, 2476, 2492,
+    2502, 2522, 2536, 2552, 2572, 2592, 2610, 2622, 2642, 2656, 2670, 2686,
+    2706, 2718, 2730, 2752, 2768, 2774, 2792, 2812, 2843, 2851, 2869, 2879,
+    2901, 2921, 2931, 2939, 2947, 2959, 2971, 2989, 2997, 3041,
+];
+
+#[cfg(feature = "display_names_zh")]
+static DISPLAY_NAMES_ZHO_LANGUAGES: [u16; 177] = [
+    Language::Aar as u16,
+    Language::Abk as u16,
+    Language::Afr as u16,
+    Language::Aka as u16,
+    Language::Amh as u16,
+    Language::Ara as u16,
+    Language::Arg as u16,
+    Language::Asm as u16,
+    Language::Ava as u16,
+    Language::Aym as u16,
+    Language::Aze as u16,
+    Language::Bak as u16,
+    Language::Bam as u16,
+    Language::Bel as u16,
+    Language::Ben as u16,
+    Language::Bis as u16,
+    Language::Bod as u16,
+    Language::Bos as u16,
+    Language::Bre as u16,
+    Language::Bul as u16,
+    Language::Cat as u16,
+    Language::Ces as u16,
+    Language::Cha as u16,
+    Language::Che as u16,
+    Language::Chv as u16,
+    Language::Cor as u16,
+    Language::Cos as u16,
+    Language::Cre as u16,
+    Language::Cym as u16,
+    Language::Dan as u16,
+    Language::Deu as u16,
+    Language::Div as u16,
+    Language::Dzo as u16,
+    Language::Ell as u16,
+    Language::Eng as u16,
+    Language::Est as u16,
+    Language::Eus as u16,
+    Language::Ewe as u16,
+    Language::Fao as u16,
+    Language::Fas as u16,
+    Language::Fij as u16,
+    Language::Fin as u16,
+    Language::Fra as u16,
+    Language::Fry as u16,
+    Language::Ful as u16,
+    Language::Gla as u16,
+    Language::Gle as u16,
+    Language::Glg as u16,
+    Language::Glv as u16,
+    Language::Grn as u16,
+    Language::Guj as u16,
+    Language::Hat as u16,
+    Language::Hau as u16,
+    Language::Hbs as u16,
+    Language::Heb as u16,
+    Language::Her as u16,
+    Language::Hin as u16,
+    Language::Hmo as u16,
+    Language::Hrv as u16,
+    Language::Hun as u16,
+    Language::Hye as u16,
+    Language::Ibo as u16,
+    Language::Iii as u16,
+    Language::Iku as u16,
+    Language::Ind as u16,
+    Language::Ipk as u16,
+    Language::Isl as u16,
+    Language::Ita as u16,
+    Language::Jav as u16,
+    Language::Jpn as u16,
+    Language::Kal as u16,
+    Language::Kan as u16,
+    Language::Kas as u16,
+    Language::Kat as u16,
+    Language::Kau as u16,
+    Language::Kaz as u16,
+    Language::Khm as u16,
+    Language::Kik as u16,
+    Language::Kin as u16,
+    Language::Kir as u16,
+    Language::Kom as u16,
+    Language::Kon as u16,
+    Language::Kor as u16,
+    Language::Kua as u16,
+    Language::Kur as u16,
+    Language::Lao as u16,
+    Language::Lav as u16,
+    Language::Lim as u16,
+    Language::Lin as u16,
+    Language::Lit as u16,
+    Language::Ltz as u16,
+    Language::Lub as u16,
+    Language::Lug as u16,
+    Language::Mah as u16,
+    Language::Mal as u16,
+    Language::Mar as u16,
+    Language::Mkd as u16,
+    Language::Mlg as u16,
+    Language::Mlt as u16,
+    Language::Mon as u16,
+    Language::Mri as u16,
+    Language::Msa as u16,
+    Language::Mul as u16,
+    Language::Mya as u16,
+    Language::Nau as u16,
+    Language::Nav as u16,
+    Language::Nbl as u16,
+    Language::Nde as u16,
+    Language::Ndo as u16,
+    Language::Nep as u16,
+    Language::Nld as u16,
+    Language::Nno as u16,
+    Language::Nob as u16,
+    Language::Nor as u16,
+    Language::Nya as u16,
+    Language::Oci as u16,
+    Language::Oji as u16,
+    Language::Ori as u16,
+    Language::Orm as u16,
+    Language::Oss as u16,
+    Language::Pan as u16,
+    Language::Pol as u16,
+    Language::Por as u16,
+    Language::Pus as u16,
+    Language::Que as u16,
+    Language::Roh as u16,
+    Language::Ron as u16,
+    Language::Run as u16,
+    Language::Rus as u16,
+    Language::Sag as u16,
+    Language::Sin as u16,
+    Language::Slk as u16,
+    Language::Slv as u16,
+    Language::Sme as u16,
+    Language::Smo as u16,
+    Language::Sna as u16,
+    Language::Snd as u16,
+    Language::Som as u16,
+    Language::Sot as u16,
+    Language::Spa as u16,
+    Language::Sqi as u16,
+    Language::Srd as u16,
+    Language::Srp as u16,
+    Language::Ssw as u16,
+    Language::Sun as u16,
+    Language::Swa as u16,
+    Language::Swe as u16,
+    Language::Tah as u16,
+    Language::Tam as u16,
+    Language::Tat as u16,
+    Language::Tel as u16,
+    Language::Tgk as u16,
+    Language::Tgl as u16,
+    Language::Tha as u16,
+    Language::Tir as u16,
+    Language::Ton as u16,
+    Language::Tsn as u16,
+    Language::Tso as u16,
+    Language::Tuk as u16,
+    Language::Tur as u16,
+    Language::Twi as u16,
+    Language::Uig as u16,
+    Language::Ukr as u16,
+    Language::Und as u16,
+    Language::Urd as u16,
+    Language::Uzb as u16,
+    Language::Ven as u16,
+    Language::Vie as u16,
+    Language::Wln as u16,
+    Language::Wol as u16,
+    Language::Xho as u16,
+    Language::Yid as u16,
+    Language::Yor as u16,
+    Language::Zha as u16,
+    Language::Zho as u16,
+    Language::Zul as u16,
+    Language::Zxx as u16,
+];
+
+#[cfg(feature = "display_names_zh")]
+pub(crate) static DISPLAY_NAMES_ZHO: &str = concat!(
+    "阿法尔语",
+    "阿布哈西亚语",
+    "南非荷兰语",
+    "阿肯语",
+    "阿姆哈拉语",
+    "阿拉伯语",
+    "阿拉贡语",
+    "阿萨姆语",
+    "阿瓦尔语",
+    "艾马拉语",
+    "阿塞拜疆语",
+    "巴什基尔语",
+    "班巴拉语",
+    "白俄罗斯语",
+    "孟加拉语",
+    "比斯拉马语",
+    "藏语",
+    "波斯尼亚语",
+    "布列塔尼语",
+    "保加利亚语",
+    "加泰罗尼亚语",
+    "捷克语",
+    "查莫罗语",
+    "车臣语",
+    "楚瓦什语",
+    "康沃尔语",
+    "科西嘉语",
+    "克里语",
+    "威尔士语",
+    "丹麦语",
+    "德语",
+    "迪维希语",
+    "宗卡语",
+    "希腊语",
+    "英语",
+    "爱沙尼亚语",
+    "巴斯克语",
+    "埃维语",
+    "法罗语",
+    "波斯语",
+    "斐济语",
+    "芬兰语",
+    "法语",
+    "西弗里西亚语",
+    "富拉语",
+    "苏格兰盖尔语",
+    "爱尔兰语",
+    "加利西亚语",
+    "马恩语",
+    "瓜拉尼语",
+    "古吉拉特语",
+    "海地克里奥尔语",
+    "豪萨语",
+    "塞尔维亚-克罗地亚语",
+    "希伯来语",
+    "赫雷罗语",
+    "印地语",
+    "希里莫图语",
+    "克罗地亚语",
+    "匈牙利语",
+    "亚美尼亚语",
+    "伊博语",
+    "凉山彝语",
+    "因纽特语",
+    "印度尼西亚语",
+    "伊努皮克语",
+    "冰岛语",
+    "意大利语",
+    "爪哇语",
+    "日语",
+    "格陵兰语",
+    "卡纳达语",
+    "克什米尔语",
+    "格鲁吉亚语",
+    "卡努里语",
+    "哈萨克语",
+    "高棉语",
+    "吉库尤语",
+    "卢旺达语",
+    "柯尔克孜语",
+    "科米语",
+    "刚果语",
+    "韩语",
+    "宽亚玛语",
+    "库尔德语",
+    "老挝语",
+    "拉脱维亚语",
+    "林堡语",
+    "林加拉语",
+    "立陶宛语",
+    "卢森堡语",
+    "鲁巴加丹加语",
+    "卢干达语",
+    "马绍尔语",
+    "马拉雅拉姆语",
+    "马拉地语",
+    "马其顿语",
+    "马拉加斯语",
+    "马耳他语",
+    "蒙古语",
+    "毛利语",
+    "马来语",
+    "多语种",
+    "缅甸语",
+    "瑙鲁语",
+    "纳瓦霍语",
+    "南恩德贝勒语",
+    "北恩德贝勒语",
+    "恩东加语",
+    "尼泊尔语",
+    "荷兰语",
+    "挪威尼诺斯克语",
+    "书面挪威语",
+    "挪威语",
+    "齐切瓦语",
+    "奥克语",
+    "奥吉布瓦语",
+    "奥里亚语",
+    "奥罗莫语",
+    "奥塞梯语",
+    "旁遮普语",
+    "波兰语",
+    "葡萄牙语",
+    "普什图语",
+    "克丘亚语",
+    "罗曼什语",
+    "罗马尼亚语",
+    "隆迪语",
+    "俄语",
+    "桑戈语",
+    "僧伽罗语",
+    "斯洛伐克语",
+    "斯洛文尼亚语",
+    "北方萨米语",
+    "萨摩亚语",
+    "绍纳语",
+    "信德语",
+    "索马里语",
+    "南索托语",
+    "西班牙语",
+    "阿尔巴尼亚语",
+    "萨丁语",
+    "塞尔维亚语",
+    "斯瓦蒂语",
+    "巽他语",
+    "斯瓦希里语",
+    "瑞典语",
+    "塔希提语",
+    "泰米尔语",
+    "鞑靼语",
+    "泰卢固语",
+    "塔吉克语",
+    "他加禄语",
+    "泰语",
+    "提格利尼亚语",
+    "汤加语",
+    "茨瓦纳语",
+    "聪加语",
+    "土库曼语",
+    "土耳其语",
+    "契维语",
+    "维吾尔语",
+    "乌克兰语",
+    "未知语言",
+    "乌尔都语",
+    "乌兹别克语",
+    "文达语",
+    "越南语",
+    "瓦隆语",
+    "沃洛夫语",
+    "科萨语",
+    "意第绪语",
+    "约鲁巴语",
+    "壮语",
+    "中文",
+    "祖鲁语",
+    "无语言内容",
+);
+
+#[cfg(feature = "display_names_zh")]
+pub(crate) static DISPLAY_NAMES_ZHO_OFFSETS: [u16; 178] = [
+    0, 12, 30, 45, 54, 69, 81, 93, 105, 117, 129, 144, 159, 171, 186, 198, 213,
+    219, 234, 249, 264, 282, 291, 303, 312, 324, 336, 348, 357, 369, 378, 384,
+    396, 405, 414, 420, 435, 447, 456, 465, 474, 483, 492, 498, 516, 525, 543,
+    555, 570, 579, 591, 606, 627, 636, 664, 676, 688, 697, 712, 727, 739, 754,
+    763, 775, 787, 805, 820, 829, 841, 850, 856, 868, 880, 895, 910, 922, 934,
+    943, 955, 967, 982, 991, 1000, 1006, 1018, 1030, 1039, 1054, 1063, 1075,
+    1087, 1099, 1117, 1129, 1141, 1159, 1171, 1183, 1198, 1210, 1219, 1228,
+    1237, 1246, 1255, 1264, 1276, 1294, 1312, 1324, 1336, 1345, 1366, 1381,
+    1390, 1402, 1411, 1426, 1438, 1450, 1462, 1474, 1483, 1495, 1507, 1519,
+    1531, 1546, 1555, 1561, 1570, 1582, 1597, 1615, 1630, 1642, 1651, 1660,
+    1672, 1684, 1696, 1714, 1723, 1738, 1750, 1759, 1774, 1783, 1795, 1807,
+    1816, 1828, 1840, 1852, 1858, 1876, 1885, 1897, 1906, 1918, 1930, 1939,
+    1951, 1963, 1975, 1987, 2002, 2011, 2020, 2029, 2041, 2050, 2062, 2074,
+    2080, 2086, 2095, 2110,
+];
+
+#[cfg(feature = "display_names")]
+pub(crate) fn display_names(
+    lang: Language,
+) -> (&'static [u16], &'static [u16], &'static str) {
+    match lang {
+        #[cfg(feature = "display_names_de")]
+        Language::Deu => (
+            &DISPLAY_NAMES_DEU_LANGUAGES,
+            &DISPLAY_NAMES_DEU_OFFSETS,
+            DISPLAY_NAMES_DEU,
+        ),
+        #[cfg(feature = "display_names_en")]
+        Language::Eng => (
+            &DISPLAY_NAMES_ENG_LANGUAGES,
+            &DISPLAY_NAMES_ENG_OFFSETS,
+            DISPLAY_NAMES_ENG,
+        ),
+        #[cfg(feature = "display_names_es")]
+        Language::Spa => (
+            &DISPLAY_NAMES_SPA_LANGUAGES,
+            &DISPLAY_NAMES_SPA_OFFSETS,
+            DISPLAY_NAMES_SPA,
+        ),
+        #[cfg(feature = "display_names_fr")]
+        Language::Fra => (
+            &DISPLAY_NAMES_FRA_LANGUAGES,
+            &DISPLAY_NAMES_FRA_OFFSETS,
+            DISPLAY_NAMES_FRA,
+        ),
+        #[cfg(feature = "display_names_it")]
+        Language::Ita => (
+            &DISPLAY_NAMES_ITA_LANGUAGES,
+            &DISPLAY_NAMES_ITA_OFFSETS,
+            DISPLAY_NAMES_ITA,
+        ),
+        #[cfg(feature = "display_names_ja")]
+        Language::Jpn => (
+            &DISPLAY_NAMES_JPN_LANGUAGES,
+            &DISPLAY_NAMES_JPN_OFFSETS,
+            DISPLAY_NAMES_JPN,
+        ),
+        #[cfg(feature = "display_names_pt")]
+        Language::Por => (
+            &DISPLAY_NAMES_POR_LANGUAGES,
+            &DISPLAY_NAMES_POR_OFFSETS,
+            DISPLAY_NAMES_POR,
+        ),
+        #[cfg(feature = "display_names_ru")]
+        Language::Rus => (
+            &DISPLAY_NAMES_RUS_LANGUAGES,
+            &DISPLAY_NAMES_RUS_OFFSETS,
+            DISPLAY_NAMES_RUS,
+        ),
+        #[cfg(feature = "display_names_zh")]
+        Language::Zho => (
+            &DISPLAY_NAMES_ZHO_LANGUAGES,
+            &DISPLAY_NAMES_ZHO_OFFSETS,
+            DISPLAY_NAMES_ZHO,
+        ),
+        _ => (&[], &[0], ""),
+    }
+}
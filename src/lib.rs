@@ -14,6 +14,15 @@
 //! that no additional time is wasted on program startup or on table access for allocating or
 //! filling the map. It is hence suitable for retrieval of codes in constraint environments.
 //!
+//! The crate is `no_std` if the default `std` feature is disabled. All lookups work without an
+//! allocator, while parsing via `FromStr` and the
+//! [`LanguageMap`](map/struct.LanguageMap.html) require the `alloc` feature.
+//!
+//! To reduce the size further, the `isolang_subset` cfg restricts the
+//! [`Language`](enum.Language.html) enum and the tables to the languages with an ISO 639-1 code
+//! (`--cfg isolang_subset="639_1"`), respectively to living languages
+//! (`--cfg isolang_subset="living"`). The special codes like `und` are always included.
+//!
 //! # Examples
 //!
 //! ```
@@ -36,42 +45,160 @@
 //! }
 //! ```
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "clap")]
+mod clap_impl;
+mod code;
+#[cfg(feature = "runtime_db")]
+pub mod db;
+#[cfg(feature = "diesel")]
+mod diesel_impl;
+#[cfg(feature = "async-graphql")]
+mod graphql;
+mod locale;
+#[cfg(feature = "alloc")]
+pub mod map;
+mod matching;
+#[cfg(feature = "postgres-types")]
+mod postgres_impl;
+#[cfg(feature = "prost")]
+mod prost_impl;
+#[cfg(feature = "rusqlite")]
+mod rusqlite_impl;
+#[cfg(feature = "schemars")]
+mod schemars_impl;
+#[cfg(feature = "sea-orm")]
+mod sea_orm_impl;
+#[cfg(feature = "serde")]
+pub mod serde;
 #[cfg(feature = "serde")]
 mod serde_impl;
+pub mod set;
+#[cfg(any(
+    feature = "sqlx",
+    feature = "postgres-types",
+    feature = "diesel",
+    feature = "sea-orm",
+    feature = "rusqlite"
+))]
+mod sql;
+#[cfg(feature = "sqlx")]
+mod sqlx_impl;
+#[cfg(feature = "runtime_db")]
+mod table;
+#[cfg(feature = "utoipa")]
+mod utoipa_impl;
 
 extern crate phf;
 
-use std::{
+#[cfg(feature = "alloc")]
+use alloc::{borrow::ToOwned, string::String};
+#[cfg(feature = "alloc")]
+use core::str::FromStr;
+use core::{
     error::Error,
     fmt::{Debug, Display, Formatter},
-    str::{self, FromStr},
+    str,
 };
 
 /// Language data extracted from `iso-639-3.tab` and `iso639-autonyms.tsv`
 ///
-/// Instances of this are generated in the `generated_code_is_fresh()` integration test,
-/// which generates the code in `src/isotable.rs`.
+/// Instances of this are generated by `cargo xtask regenerate`, which writes the code to
+/// `src/isotable.rs` and to one file per subset of languages (`isolang_subset` cfg).
 struct LanguageData {
     /// The ISO-639-3 3-letter language code (column `Id` in `iso-639-3.tab`)
     code_3: [u8; 3],
     /// The ISO-639-1 2-letter language code, if available (column `Part1` in `iso-639-3.tab`)
     code_1: Option<[u8; 2]>,
-    /// The language's name in English (column `Ref_Name` in `iso-639-3.tab`)
-    ///
-    /// The code generator removes any parenthesized suffix from the name.
-    #[cfg(feature = "english_names")]
-    name_en: &'static str,
-    /// The language's name in its own language (column `autonym` in `iso639-autonyms.tsv`)
-    #[cfg(feature = "local_names")]
-    autonym: Option<&'static str>,
+}
+
+/// The scope of a language (column `Scope` in `iso-639-3.tab`)
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+pub enum Scope {
+    /// An individual language
+    Individual,
+    /// A macrolanguage, i.e. a group of closely related individual languages which are often
+    /// treated as one, like Arabic or Chinese
+    Macrolanguage,
+    /// A special code like `und` (undetermined) or `mul` (multiple languages)
+    Special,
+}
+
+/// Get the name at the given index from a table of concatenated names.
+///
+/// The English names (column `Ref_Name` in `iso-639-3.tab`, without any parenthesized suffix),
+/// the autonyms (column `autonym` in `iso639-autonyms.tsv`) and the display names of each display
+/// language are each stored in one string, since a separate `&str` per language would take up
+/// more space than most names.
+#[cfg(any(
+    feature = "english_names",
+    feature = "local_names",
+    feature = "display_names"
+))]
+const fn name_at(
+    names: &'static str,
+    start: usize,
+    end: usize,
+) -> &'static str {
+    let (_, rest) = names.as_bytes().split_at(start);
+    let (name, _) = rest.split_at(end - start);
+    // SAFETY: The offsets have been generated at the boundaries of the names, which are valid
+    // UTF-8.
+    unsafe { str::from_utf8_unchecked(name) }
 }
 
 #[rustfmt::skip]
+#[cfg_attr(
+    all(isolang_subset = "639_1", not(isolang_subset = "living")),
+    path = "isotable_639_1.rs"
+)]
+#[cfg_attr(
+    all(isolang_subset = "living", not(isolang_subset = "639_1")),
+    path = "isotable_living.rs"
+)]
+#[cfg_attr(
+    all(isolang_subset = "639_1", isolang_subset = "living"),
+    path = "isotable_639_1_living.rs"
+)]
 mod isotable;
+#[cfg(feature = "clap")]
+pub use clap_impl::LanguageValueParser;
+pub use code::{LanguageCode, PrivateUse};
+#[cfg(feature = "async-graphql")]
+pub use graphql::{LanguageInfo, LanguageScalar};
+#[cfg(feature = "display_names")]
+use isotable::display_names;
 pub use isotable::Language;
 use isotable::{
-    iso_639_2b_to_3, iso_639_3_to_2b, OVERVIEW, THREE_TO_THREE, TWO_TO_THREE,
+    iso_639_3_to_2b, language_from_639_1, language_from_639_2b,
+    language_from_639_3, scope, OVERVIEW, THREE_TO_THREE, TWO_TO_THREE,
 };
+#[cfg(feature = "local_names")]
+use isotable::{AUTONYMS, AUTONYMS_OFFSETS};
+#[cfg(feature = "english_names")]
+use isotable::{NAMES_EN, NAMES_EN_OFFSETS};
+pub use locale::Locale;
+#[cfg(feature = "alloc")]
+pub use locale::ParseLocaleError;
+#[cfg(feature = "alloc")]
+pub use map::LanguageMap;
+pub use matching::{LanguageMatch, LanguageMatcher};
+#[cfg(feature = "prost")]
+pub use prost_impl::ProtoLanguage;
+pub use set::LanguageSet;
+#[cfg(any(
+    feature = "sqlx",
+    feature = "postgres-types",
+    feature = "diesel",
+    feature = "sea-orm",
+    feature = "rusqlite"
+))]
+pub use sql::LanguageId;
 
 /// Get an iterator of all languages.
 ///
@@ -97,7 +224,58 @@ use isotable::{
 /// ```
 #[cfg(any(feature = "list_languages", test))]
 pub fn languages() -> impl Iterator<Item = Language> {
-    OVERVIEW.iter().enumerate().filter_map(|(idx, _)| Language::from_usize(idx))
+    all_languages()
+}
+
+#[cfg(any(
+    feature = "list_languages",
+    feature = "english_names",
+    feature = "local_names",
+    feature = "clap",
+    feature = "schemars",
+    feature = "utoipa",
+    test
+))]
+fn all_languages() -> impl Iterator<Item = Language> {
+    (0..isotable::LANGUAGE_COUNT).filter_map(Language::from_usize)
+}
+
+/// Create a [`Language`](enum.Language.html) from a ISO 639-1, 639-3 or 639-2b code literal.
+///
+/// The code is looked up at compile time, so unknown codes are a compile error instead of a
+/// runtime failure. The macro can be used in constant expressions.
+///
+/// # Examples
+///
+/// ```
+/// use isolang::{lang, Language};
+///
+/// assert_eq!(lang!("de"), Language::Deu);
+/// assert_eq!(lang!("deu"), Language::Deu);
+/// assert_eq!(lang!("ger"), Language::Deu);
+///
+/// const SUPPORTED: [Language; 3] = [lang!("en"), lang!("fr"), lang!("nob")];
+/// assert_eq!(SUPPORTED[2].to_639_1(), Some("nb"));
+/// ```
+///
+/// Unknown codes fail to compile:
+///
+/// ```compile_fail
+/// let language = isolang::lang!("xx");
+/// ```
+#[macro_export]
+macro_rules! lang {
+    ($code:literal) => {{
+        const LANGUAGE: $crate::Language =
+            match $crate::Language::__from_code($code) {
+                ::core::option::Option::Some(language) => language,
+                ::core::option::Option::None => ::core::panic!(concat!(
+                    "unknown ISO 639-1, 639-3 or 639-2b code: ",
+                    $code
+                )),
+            };
+        LANGUAGE
+    }};
 }
 
 impl Language {
@@ -112,7 +290,7 @@ impl Language {
     ///
     /// assert_eq!(Language::Deu.to_639_3(), "deu");
     /// ```
-    pub fn to_639_3(&self) -> &'static str {
+    pub const fn to_639_3(&self) -> &'static str {
         // SAFETY: The ISO 639 table has been written to the binary with UTF-8 encoding, hence reading it without checks is safe.
         unsafe { str::from_utf8_unchecked(&OVERVIEW[*self as usize].code_3) }
     }
@@ -128,8 +306,11 @@ impl Language {
     ///
     /// assert_eq!(Language::Deu.to_639_2b(), "ger");
     /// ```
-    pub fn to_639_2b(&self) -> &'static str {
-        iso_639_3_to_2b(self.to_639_3())
+    pub const fn to_639_2b(&self) -> &'static str {
+        match iso_639_3_to_2b(&OVERVIEW[*self as usize].code_3) {
+            Some(code) => code,
+            None => self.to_639_3(),
+        }
     }
 
     /// Create string representation of this Language as a ISO 639-2t code.
@@ -143,7 +324,7 @@ impl Language {
     ///
     /// assert_eq!(Language::Deu.to_639_2t(), "deu");
     /// ```
-    pub fn to_639_2t(&self) -> &'static str {
+    pub const fn to_639_2t(&self) -> &'static str {
         self.to_639_3()
     }
 
@@ -159,14 +340,12 @@ impl Language {
     ///
     /// assert!(Language::Gha.to_639_1().is_none());
     /// ```
-    pub fn to_639_1(&self) -> Option<&'static str> {
-        // SAFETY: The global state is initialised at load time and filled at compile-time. The
-        // access happens read-only.
-        unsafe {
-            OVERVIEW[*self as usize]
-                .code_1
-                .as_ref()
-                .map(|s| str::from_utf8_unchecked(s))
+    pub const fn to_639_1(&self) -> Option<&'static str> {
+        match &OVERVIEW[*self as usize].code_1 {
+            // SAFETY: The ISO 639 table has been written to the binary with UTF-8 encoding, hence
+            // reading it without checks is safe.
+            Some(code) => Some(unsafe { str::from_utf8_unchecked(code) }),
+            None => None,
         }
     }
 
@@ -188,8 +367,13 @@ impl Language {
     /// assert_eq!(Language::Swh.to_name(), "Swahili");
     /// ```
     #[cfg(feature = "english_names")]
-    pub fn to_name(&self) -> &'static str {
-        OVERVIEW[*self as usize].name_en
+    pub const fn to_name(&self) -> &'static str {
+        let idx = *self as usize;
+        name_at(
+            NAMES_EN,
+            NAMES_EN_OFFSETS[idx] as usize,
+            NAMES_EN_OFFSETS[idx + 1] as usize,
+        )
     }
 
     /// Get the ISO code by its English name.
@@ -207,11 +391,7 @@ impl Language {
     /// ```
     #[cfg(feature = "english_names")]
     pub fn from_name(engl_name: &str) -> Option<Self> {
-        OVERVIEW
-            .iter()
-            .enumerate()
-            .find(|(_, it)| it.name_en == engl_name)
-            .and_then(|(idx, _)| Language::from_usize(idx))
+        all_languages().find(|lang| lang.to_name() == engl_name)
     }
 
     /// Get the ISO code by its lowercase English name.
@@ -229,13 +409,14 @@ impl Language {
     /// ```
     #[cfg(all(feature = "english_names", feature = "lowercase_names"))]
     pub fn from_name_lowercase(engl_name: &str) -> Option<Self> {
-        OVERVIEW
-            .iter()
-            .enumerate()
-            .find(|(_, it)| {
-                it.name_en.to_ascii_lowercase().as_str() == engl_name
-            })
-            .and_then(|(idx, _)| Language::from_usize(idx))
+        all_languages().find(|lang| {
+            let name = lang.to_name();
+            name.len() == engl_name.len()
+                && name
+                    .bytes()
+                    .zip(engl_name.bytes())
+                    .all(|(c, lower)| c.to_ascii_lowercase() == lower)
+        })
     }
 
     /// Get all matching ISO codes by a provided English name pattern.
@@ -255,12 +436,7 @@ impl Language {
     where
         F: Fn(&str) -> bool + 'static,
     {
-        OVERVIEW.iter().enumerate().filter_map(move |(idx, it)| {
-            match matcher(it.name_en) {
-                true => Language::from_usize(idx),
-                false => None,
-            }
-        })
+        all_languages().filter(move |lang| matcher(lang.to_name()))
     }
 
     /// Get the autonym of this language
@@ -279,8 +455,13 @@ impl Language {
     /// assert_eq!(Language::Fra.to_autonym(), Some("français"));
     /// ```
     #[cfg(feature = "local_names")]
-    pub fn to_autonym(&self) -> Option<&'static str> {
-        OVERVIEW[*self as usize].autonym
+    pub const fn to_autonym(&self) -> Option<&'static str> {
+        let idx = *self as usize;
+        let (start, end) = (AUTONYMS_OFFSETS[idx], AUTONYMS_OFFSETS[idx + 1]);
+        match start == end {
+            true => None,
+            false => Some(name_at(AUTONYMS, start as usize, end as usize)),
+        }
     }
 
     /// Get the ISO code by its autonym (local language name).
@@ -296,11 +477,7 @@ impl Language {
     /// ```
     #[cfg(feature = "local_names")]
     pub fn from_autonym(autonym: &str) -> Option<Self> {
-        OVERVIEW
-            .iter()
-            .enumerate()
-            .find(|(_, it)| it.autonym == Some(autonym))
-            .and_then(|(idx, _)| Language::from_usize(idx))
+        all_languages().find(|lang| lang.to_autonym() == Some(autonym))
     }
 
     /// Get all matching ISO codes by a provided autonym pattern.
@@ -320,12 +497,35 @@ impl Language {
     where
         F: Fn(&str) -> bool + 'static,
     {
-        OVERVIEW.iter().enumerate().filter_map(move |(idx, it)| {
-            it.autonym.and_then(|autonym| match matcher(autonym) {
-                true => Language::from_usize(idx),
-                false => None,
-            })
-        })
+        all_languages()
+            .filter(move |lang| lang.to_autonym().is_some_and(&matcher))
+    }
+
+    /// Get the name of this language in another language.
+    ///
+    /// This returns the name of the language as used by speakers of `in_language`, as defined in
+    /// the Unicode CLDR. The casing of the CLDR is kept, e.g. French language names are written in
+    /// lower case. Display languages are enabled individually with the `display_names_<code>`
+    /// features, where `<code>` is the ISO 639-1 code of the display language (e.g.
+    /// `display_names_fr`). `None` is returned if the display language is not enabled or if the
+    /// CLDR has no name for this language.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use isolang::Language;
+    ///
+    /// #[cfg(feature = "display_names_fr")]
+    /// assert_eq!(Language::Deu.display_name(Language::Fra), Some("allemand"));
+    /// #[cfg(feature = "display_names_de")]
+    /// assert_eq!(Language::Fra.display_name(Language::Deu), Some("Französisch"));
+    /// assert_eq!(Language::Deu.display_name(Language::Gha), None);
+    /// ```
+    #[cfg(feature = "display_names")]
+    pub fn display_name(&self, in_language: Language) -> Option<&'static str> {
+        let (languages, offsets, names) = display_names(in_language);
+        let idx = languages.binary_search(&(*self as u16)).ok()?;
+        Some(name_at(names, offsets[idx] as usize, offsets[idx + 1] as usize))
     }
 
     /// Create a Language instance rom a ISO 639-1 code.
@@ -380,7 +580,11 @@ impl Language {
     /// assert!(Language::from_639_2b("ger").is_some());
     /// ```
     pub fn from_639_2b(code: &str) -> Option<Language> {
-        Self::from_639_3(iso_639_2b_to_3(code))
+        code.as_bytes()
+            .try_into()
+            .ok()
+            .and_then(language_from_639_2b)
+            .or_else(|| Self::from_639_3(code))
     }
 
     /// Create a Language instance rom a ISO 639-3 code.
@@ -407,6 +611,121 @@ impl Language {
             .and_then(|raw_lang| Language::from_usize(raw_lang as usize))
     }
 
+    /// Create a Language instance from a ISO 639-3 code in a constant context.
+    ///
+    /// This is the `const` counterpart of [`from_639_3`](#method.from_639_3), taking the code as
+    /// a byte string. It allows to build tables of languages at compile time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use isolang::Language;
+    ///
+    /// const SUPPORTED: [Language; 2] = [
+    ///     Language::from_639_3_const(b"deu").unwrap(),
+    ///     Language::from_639_3_const(b"fra").unwrap(),
+    /// ];
+    /// assert_eq!(SUPPORTED, [Language::Deu, Language::Fra]);
+    /// assert!(Language::from_639_3_const(b"xxx").is_none());
+    /// ```
+    pub const fn from_639_3_const(code: &[u8; 3]) -> Option<Language> {
+        language_from_639_3(code)
+    }
+
+    /// Look up a ISO 639-1, 639-3 or 639-2b code in a constant context, used by [`lang!`].
+    #[doc(hidden)]
+    pub const fn __from_code(code: &str) -> Option<Language> {
+        match code.as_bytes() {
+            [a, b] => language_from_639_1(&[*a, *b]),
+            [a, b, c] => match language_from_639_3(&[*a, *b, *c]) {
+                Some(language) => Some(language),
+                None => language_from_639_2b(&[*a, *b, *c]),
+            },
+            _ => None,
+        }
+    }
+
+    /// Get the scope of the language, i.e. whether it is an individual language, a
+    /// macrolanguage or a special code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use isolang::{Language, Scope};
+    ///
+    /// assert_eq!(Language::Deu.scope(), Scope::Individual);
+    /// assert_eq!(Language::Zho.scope(), Scope::Macrolanguage);
+    /// assert_eq!(Language::Und.scope(), Scope::Special);
+    /// ```
+    pub const fn scope(&self) -> Scope {
+        scope(*self)
+    }
+
+    /// Get the index of the language in the tables of this crate.
+    ///
+    /// Indices range from 0 to the number of languages and allow to store languages compactly,
+    /// e.g. as `u16` in columnar formats. They follow the order of the ISO 639-3 table and change
+    /// when languages are added, as well as with the `isolang_subset` cfg, so they should not be
+    /// persisted across releases.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use isolang::Language;
+    ///
+    /// let index = Language::Deu.index();
+    /// assert_eq!(Language::from_usize(index), Some(Language::Deu));
+    /// ```
+    pub const fn index(&self) -> usize {
+        *self as usize
+    }
+
+    /// Get the language with the given [index](#method.index), if it is in range.
+    ///
+    /// This is a bounds check, hence constant time.
+    pub const fn from_usize(index: usize) -> Option<Self> {
+        if index < isotable::LANGUAGE_COUNT {
+            // SAFETY: `Language` is `repr(u16)` and its discriminants are generated as
+            // `0..LANGUAGE_COUNT`, which fits into an `u16`.
+            Some(unsafe { core::mem::transmute::<u16, Language>(index as u16) })
+        } else {
+            None
+        }
+    }
+
+    /// Get a numeric ID of the language which is stable across releases.
+    ///
+    /// Unlike the [index](#method.index), the ID is derived from the ISO 639-3 code alone, by
+    /// reading its letters as a base-26 number (`aaa` is 0, `zzz` is 17575). It is hence suitable
+    /// to be persisted and only changes if ISO 639-3 changes the code of the language.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use isolang::Language;
+    ///
+    /// assert_eq!(Language::Deu.to_stable_id(), 2152);
+    /// assert_eq!(Language::from_stable_id(2152), Some(Language::Deu));
+    /// ```
+    pub const fn to_stable_id(&self) -> u16 {
+        let [a, b, c] = OVERVIEW[*self as usize].code_3;
+        (a - b'a') as u16 * 26 * 26 + (b - b'a') as u16 * 26 + (c - b'a') as u16
+    }
+
+    /// Get the language with the given [stable ID](#method.to_stable_id).
+    ///
+    /// Returns `None` if the ID is out of range or no language has the corresponding code.
+    pub const fn from_stable_id(id: u16) -> Option<Self> {
+        if id >= 26 * 26 * 26 {
+            return None;
+        }
+        language_from_639_3(&[
+            b'a' + (id / 26 / 26) as u8,
+            b'a' + (id / 26 % 26) as u8,
+            b'a' + (id % 26) as u8,
+        ])
+    }
+
     /// Parse language from given locale
     ///
     /// This parses a language from a given locale string, as used by UNIX-alike and other systems.
@@ -425,6 +744,33 @@ impl Language {
         // use first bit of locale (before the _) to detect the language
         locale.split('_').next().and_then(Language::from_639_1)
     }
+
+    /// Get the languages to fall back to if no resources exist for this language.
+    ///
+    /// This returns the languages of the [fallback chain](struct.Locale.html#method.fallback_chain)
+    /// of this language, from the most to the least specific, without the language itself and
+    /// without the root. The chain combines the parent locales of the Unicode CLDR with the
+    /// macrolanguages of individual languages.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use isolang::Language;
+    ///
+    /// assert_eq!(Language::Nob.fallbacks().next(), Some(Language::Nor));
+    /// assert_eq!(Language::Cmn.fallbacks().next(), Some(Language::Zho));
+    /// ```
+    pub fn fallbacks(&self) -> impl Iterator<Item = Language> {
+        let mut last = *self;
+        Locale::new(*self).fallback_chain().filter_map(move |locale| {
+            let language = locale.language();
+            if language == last || language == Language::Und {
+                return None;
+            }
+            last = language;
+            Some(language)
+        })
+    }
 }
 
 #[allow(clippy::derivable_impls)]
@@ -435,14 +781,14 @@ impl Default for Language {
 }
 
 impl Debug for Language {
-    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
         write!(f, "{}", self.to_639_3())
     }
 }
 
 impl Display for Language {
     #[cfg(all(feature = "local_names", feature = "english_names"))]
-    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
         write!(
             f,
             "{} ({})",
@@ -452,48 +798,36 @@ impl Display for Language {
     }
 
     #[cfg(all(feature = "local_names", not(feature = "english_names")))]
-    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
         write!(f, "{}", self.to_autonym().unwrap_or("missing autonym"))
     }
 
     #[cfg(all(not(feature = "local_names"), feature = "english_names"))]
-    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
         write!(f, "{}", self.to_name())
     }
 
     #[cfg(all(not(feature = "local_names"), not(feature = "english_names")))]
-    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
         write!(f, "{}", self.to_639_3())
     }
 }
 
-#[derive(Debug)]
-pub struct ParseLanguageError(String);
-
-impl Display for ParseLanguageError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "'{}' is not a valid ISO 639-1 or 639-3 code.", self.0)
-    }
-}
-
-impl Error for ParseLanguageError {}
-
-impl FromStr for Language {
-    type Err = ParseLanguageError;
-
+#[cfg(any(feature = "alloc", feature = "serde", test))]
+impl Language {
+    /// Parse a language from any of the representations enabled by features.
+    ///
+    /// This backs the [`FromStr`](core::str::FromStr) implementation, without allocating an
+    /// error.
     #[cfg(any(
         not(feature = "english_names"),
         not(feature = "lowercase_names")
     ))]
-    fn from_str(s: &str) -> Result<Self, ParseLanguageError> {
-        match Language::from_639_3(s)
+    pub(crate) fn parse(s: &str) -> Option<Self> {
+        Language::from_639_3(s)
             .or_else(|| Language::from_639_1(s))
             // .or_else(|| Language::from_639_2t(s)) // ISO 639-3 codes are backwards compatible with ISO 639-2t codes, so this is unnecessary
             .or_else(|| Language::from_639_2b(s))
-        {
-            Some(l) => Ok(l),
-            None => Err(ParseLanguageError(s.to_owned())),
-        }
     }
 
     #[cfg(all(
@@ -501,14 +835,10 @@ impl FromStr for Language {
         feature = "lowercase_names",
         not(feature = "local_names")
     ))]
-    fn from_str(s: &str) -> Result<Self, ParseLanguageError> {
-        match Language::from_639_3(s)
+    pub(crate) fn parse(s: &str) -> Option<Self> {
+        Language::from_639_3(s)
             .or_else(|| Language::from_639_1(s))
             .or_else(|| Language::from_name_lowercase(s))
-        {
-            Some(l) => Ok(l),
-            None => Err(ParseLanguageError(s.to_owned())),
-        }
     }
 
     #[cfg(all(
@@ -516,15 +846,61 @@ impl FromStr for Language {
         feature = "lowercase_names",
         feature = "local_names"
     ))]
-    fn from_str(s: &str) -> Result<Self, ParseLanguageError> {
-        match Language::from_639_3(s)
+    pub(crate) fn parse(s: &str) -> Option<Self> {
+        Language::from_639_3(s)
             .or_else(|| Language::from_639_1(s))
             .or_else(|| Language::from_name_lowercase(s))
             .or_else(|| Language::from_autonym(s))
-        {
-            Some(l) => Ok(l),
-            None => Err(ParseLanguageError(s.to_owned())),
-        }
+    }
+}
+
+/// Error returned when parsing a [`Language`] fails. Available with the `alloc` feature.
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct ParseLanguageError(String);
+
+#[cfg(feature = "alloc")]
+impl Display for ParseLanguageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "'{}' is not a valid ISO 639-1 or 639-3 code.", self.0)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Error for ParseLanguageError {}
+
+/// Error returned when converting an out-of-range `u16` into a [`Language`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TryFromIndexError(u16);
+
+impl Display for TryFromIndexError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} is not a valid language index.", self.0)
+    }
+}
+
+impl Error for TryFromIndexError {}
+
+impl TryFrom<u16> for Language {
+    type Error = TryFromIndexError;
+
+    fn try_from(index: u16) -> Result<Self, TryFromIndexError> {
+        Language::from_usize(index as usize).ok_or(TryFromIndexError(index))
+    }
+}
+
+impl From<Language> for u16 {
+    fn from(language: Language) -> Self {
+        language as u16
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromStr for Language {
+    type Err = ParseLanguageError;
+
+    fn from_str(s: &str) -> Result<Self, ParseLanguageError> {
+        Language::parse(s).ok_or_else(|| ParseLanguageError(s.to_owned()))
     }
 }
 
@@ -581,6 +957,191 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(all(feature = "display_names_fr", feature = "display_names_ja"))]
+    fn test_display_names() {
+        assert_eq!(Language::Deu.display_name(Language::Fra), Some("allemand"));
+        assert_eq!(
+            Language::Nob.display_name(Language::Jpn),
+            Some("ノルウェー語(ブークモール)")
+        );
+        // no CLDR name for Ghotuo
+        assert_eq!(Language::Aaa.display_name(Language::Fra), None);
+        // not a display language
+        assert_eq!(Language::Deu.display_name(Language::Gha), None);
+    }
+
+    #[test]
+    fn test_index_conversions() {
+        for language in languages() {
+            let index = u16::from(language);
+            assert_eq!(index as usize, language.index());
+            assert_eq!(Language::try_from(index), Ok(language));
+        }
+        let count = isotable::LANGUAGE_COUNT;
+        #[cfg(not(isolang_subset = "639_1"))]
+        assert_eq!(Language::from_usize(count - 1), Some(Language::Zzj));
+        assert_eq!(Language::from_usize(count), None);
+        assert_eq!(Language::from_usize(usize::MAX), None);
+        assert_eq!(
+            Language::try_from(u16::MAX),
+            Err(TryFromIndexError(u16::MAX))
+        );
+        #[cfg(not(isolang_subset = "639_1"))]
+        {
+            const FIRST: Option<Language> = Language::from_usize(0);
+            assert_eq!(FIRST, Some(Language::Aaa));
+        }
+    }
+
+    #[test]
+    fn test_scopes() {
+        let count = |scope| languages().filter(|l| l.scope() == scope).count();
+        #[cfg(not(any(isolang_subset = "639_1", isolang_subset = "living")))]
+        assert_eq!(count(Scope::Macrolanguage), 63);
+        assert_eq!(count(Scope::Special), 4);
+        // all macrolanguages of the CLDR fallbacks are macrolanguages in ISO 639-3
+        for language in languages() {
+            if let Some(macrolanguage) = isotable::macrolanguage(language) {
+                assert_eq!(macrolanguage.scope(), Scope::Macrolanguage);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(isolang_subset = "639_1")]
+    fn test_subset_639_1() {
+        #[cfg(not(isolang_subset = "living"))]
+        assert_eq!(isotable::LANGUAGE_COUNT, 188);
+        assert_eq!(Language::from_639_3("aaa"), None);
+        assert_eq!(Language::from_639_1("de"), Some(Language::Deu));
+        assert!(languages()
+            .all(|lang| lang.to_639_1().is_some()
+                || lang.scope() == Scope::Special));
+    }
+
+    #[test]
+    #[cfg(isolang_subset = "living")]
+    fn test_subset_living() {
+        #[cfg(not(isolang_subset = "639_1"))]
+        assert_eq!(isotable::LANGUAGE_COUNT, 7087);
+        #[cfg(isolang_subset = "639_1")]
+        assert_eq!(isotable::LANGUAGE_COUNT, 178);
+        // Latin is historical
+        assert_eq!(Language::from_639_3("lat"), None);
+        assert_eq!(Language::from_639_1("la"), None);
+        assert_eq!(Language::from_639_3("deu"), Some(Language::Deu));
+    }
+
+    #[test]
+    #[cfg(any(isolang_subset = "639_1", isolang_subset = "living"))]
+    fn test_subsets() {
+        // special codes are part of every subset
+        for code in ["mis", "mul", "und", "zxx"] {
+            let lang = Language::from_639_3(code).unwrap();
+            assert_eq!(lang.scope(), Scope::Special);
+        }
+        assert_eq!(languages().count(), isotable::LANGUAGE_COUNT);
+        assert_eq!(LanguageSet::all().len(), isotable::LANGUAGE_COUNT);
+        assert!(LanguageSet::all().iter().eq(languages()));
+    }
+
+    #[test]
+    fn test_stable_ids() {
+        #[cfg(not(isolang_subset = "639_1"))]
+        {
+            assert_eq!(Language::Aaa.to_stable_id(), 0);
+            assert_eq!(Language::Zzj.to_stable_id(), 25 * 676 + 25 * 26 + 9);
+        }
+        for language in languages() {
+            let id = language.to_stable_id();
+            assert_eq!(Language::from_stable_id(id), Some(language));
+        }
+        // private-use codes are not assigned to any language
+        assert_eq!(Language::from_stable_id(16 * 676), None);
+        assert_eq!(Language::from_stable_id(26 * 26 * 26), None);
+        assert_eq!(Language::from_stable_id(u16::MAX), None);
+    }
+
+    #[test]
+    fn test_const_conversions() {
+        const GERMAN: Language = Language::from_639_3_const(b"deu").unwrap();
+        const CODES: [&str; 3] =
+            [GERMAN.to_639_3(), GERMAN.to_639_2b(), Language::Ita.to_639_2b()];
+        const CODE_1: Option<&str> = GERMAN.to_639_1();
+        assert_eq!(CODES, ["deu", "ger", "ita"]);
+        assert_eq!(CODE_1, Some("de"));
+        assert_eq!(Language::from_639_3_const(b"DEU"), None);
+        for language in languages() {
+            let code: [u8; 3] =
+                language.to_639_3().as_bytes().try_into().unwrap();
+            assert_eq!(Language::from_639_3_const(&code), Some(language));
+        }
+    }
+
+    #[test]
+    fn test_lang_macro() {
+        const CHINESE: Language = lang!("chi");
+        assert_eq!(CHINESE, Language::Zho);
+        assert_eq!(lang!("zh"), Language::Zho);
+        #[cfg(not(isolang_subset = "639_1"))]
+        assert_eq!(lang!("cmn"), Language::Cmn);
+        for language in languages() {
+            let codes = [
+                Some(language.to_639_3()),
+                Some(language.to_639_2b()),
+                language.to_639_1(),
+            ];
+            for code in codes.into_iter().flatten() {
+                assert_eq!(Language::__from_code(code), Some(language));
+            }
+        }
+    }
+
+    #[test]
+    fn test_table_size() {
+        // the embedded tables make up most of the binary size
+        fn assert_at_most(size: usize, kib: usize) {
+            assert!(size <= kib * 1024, "{} bytes exceed {} KiB", size, kib);
+        }
+        assert_at_most(std::mem::size_of_val(&OVERVIEW), 48);
+        #[cfg(feature = "english_names")]
+        assert_at_most(
+            NAMES_EN.len() + std::mem::size_of_val(&NAMES_EN_OFFSETS),
+            100,
+        );
+        #[cfg(feature = "local_names")]
+        assert_at_most(
+            AUTONYMS.len() + std::mem::size_of_val(&AUTONYMS_OFFSETS),
+            32,
+        );
+        #[cfg(feature = "display_names")]
+        for language in languages() {
+            let (languages, offsets, names) = display_names(language);
+            let size = std::mem::size_of_val(languages)
+                + std::mem::size_of_val(offsets)
+                + names.len();
+            assert_at_most(size, 16);
+        }
+    }
+
+    #[test]
+    #[cfg(not(isolang_subset = "639_1"))]
+    fn test_names_at_table_bounds() {
+        #[cfg(feature = "english_names")]
+        {
+            assert_eq!(Language::Aaa.to_name(), "Ghotuo");
+            assert_eq!(Language::Zzj.to_name(), "Zuojiang Zhuang");
+            assert!(languages().all(|lang| !lang.to_name().is_empty()));
+        }
+        #[cfg(feature = "local_names")]
+        {
+            assert_eq!(Language::Aaa.to_autonym(), None);
+            assert_eq!(Language::Zzj.to_autonym(), None);
+            assert_eq!(Language::Zul.to_autonym(), Some("isiZulu"));
+        }
+    }
+
     #[test]
     fn test_default() {
         assert_eq!(Language::default(), Language::Und);
@@ -631,7 +1192,55 @@ mod tests {
         );
         assert_eq!(
             serde_json::from_str::<Language>("123").map_err(|e| e.to_string()),
-            Err("invalid type: integer `123`, expected borrowed str or bytes at line 1 column 3".to_string())
+            Err("invalid type: integer `123`, expected an ISO 639 language code at line 1 column 3".to_string())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_collections() {
+        use std::collections::{BTreeMap, HashMap};
+
+        use ::serde::de::{value, Deserialize, IntoDeserializer};
+
+        let languages: Option<Vec<Language>> =
+            serde_json::from_str(r#"["de","fra"]"#).unwrap();
+        assert_eq!(languages, Some(vec![Language::Deu, Language::Fra]));
+        assert_eq!(
+            serde_json::from_str::<Option<Language>>("null").unwrap(),
+            None
+        );
+
+        let map: HashMap<Language, u8> =
+            serde_json::from_str(r#"{"de":1,"fra":2}"#).unwrap();
+        assert_eq!(map[&Language::Deu], 1);
+        assert_eq!(map[&Language::Fra], 2);
+        let map = BTreeMap::from([(Language::Deu, 1), (Language::Fra, 2)]);
+        let json = serde_json::to_string(&map).unwrap();
+        assert_eq!(json, r#"{"deu":1,"fra":2}"#);
+        assert_eq!(
+            serde_json::from_slice::<BTreeMap<Language, u8>>(json.as_bytes())
+                .unwrap(),
+            map
+        );
+
+        // integers are read as stable IDs
+        let deserialize = |id: u64| {
+            Language::deserialize(id.into_deserializer())
+                .map_err(|e: value::Error| e.to_string())
+        };
+        assert_eq!(deserialize(2152), Ok(Language::Deu));
+        assert_eq!(
+            deserialize(70000),
+            Err(
+                "invalid value: integer `70000`, expected a stable language ID"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            Language::deserialize(b"de".as_slice().into_deserializer())
+                .map_err(|e: value::Error| e.to_string()),
+            Ok(Language::Deu)
         );
     }
 
@@ -665,6 +1274,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "alloc")]
     fn test_from_str() {
         assert_eq!(Language::from_str("deu").unwrap(), Language::Deu);
         assert_eq!(Language::from_str("fr").unwrap(), Language::Fra);
@@ -672,7 +1282,7 @@ mod tests {
     }
 
     #[test]
-    #[cfg(feature = "english_names")]
+    #[cfg(all(feature = "alloc", feature = "english_names"))]
     fn test_from_str_full_features() {
         assert_eq!(Language::from_str("es").unwrap().to_name(), "Spanish");
         assert_eq!(Language::from_str("spa").unwrap().to_name(), "Spanish");
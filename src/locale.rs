@@ -0,0 +1,346 @@
+#[cfg(feature = "alloc")]
+use alloc::{borrow::ToOwned, string::String};
+#[cfg(feature = "alloc")]
+use core::{error::Error, str::FromStr};
+use core::{
+    fmt::{self, Debug, Display, Formatter, Write},
+    iter, str,
+};
+
+use crate::isotable::{macrolanguage, PARENT_LOCALES};
+use crate::Language;
+
+/// A language with an optional script and region.
+///
+/// This covers the parts of a BCP 47 language tag which are relevant for picking translations,
+/// e.g. `pt-BR` or `sr-Latn-RS`. Variants and extensions are not supported. A locale is parsed
+/// from its BCP 47 form or from a POSIX locale, such as `de_DE.UTF-8`, and displayed in its BCP 47
+/// form, using the ISO 639-1 code of the language if there is one.
+///
+/// # Examples
+///
+/// ```
+/// use isolang::{Language, Locale};
+///
+/// # #[cfg(feature = "alloc")] {
+/// let locale: Locale = "sr_Latn_RS.UTF-8".parse().unwrap();
+/// assert_eq!(locale.language(), Language::Srp);
+/// assert_eq!(locale.script(), Some("Latn"));
+/// assert_eq!(locale.region(), Some("RS"));
+/// assert_eq!(locale.to_string(), "sr-Latn-RS");
+/// # }
+/// ```
+#[derive(Clone, Copy, Default, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Locale {
+    language: Language,
+    script: Option<[u8; 4]>,
+    /// Two letters, padded with a zero byte, or three digits
+    region: Option<[u8; 3]>,
+}
+
+impl Locale {
+    /// Create a locale consisting of the given language only.
+    pub fn new(language: Language) -> Self {
+        Locale { language, script: None, region: None }
+    }
+
+    /// The root locale `und`, at which every fallback chain ends.
+    pub fn root() -> Self {
+        Locale::new(Language::Und)
+    }
+
+    /// Get the language of this locale.
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// Get the ISO 15924 script code of this locale, if any, e.g. `Latn`.
+    pub fn script(&self) -> Option<&str> {
+        self.script.as_ref().and_then(|s| str::from_utf8(s).ok())
+    }
+
+    /// Get the ISO 3166-1 or UN M.49 region code of this locale, if any, e.g. `BR` or `419`.
+    pub fn region(&self) -> Option<&str> {
+        self.region.as_ref().and_then(|r| {
+            let len = if r[2] == 0 { 2 } else { 3 };
+            str::from_utf8(&r[..len]).ok()
+        })
+    }
+
+    /// Get the locale to fall back to if no resources exist for this locale.
+    ///
+    /// The parent is determined as follows:
+    ///
+    /// 1. the parent locale as defined in the Unicode CLDR, e.g. `nb` → `no` or `es-MX` → `es-419`
+    /// 2. the locale without its region, e.g. `de-AT` → `de`
+    /// 3. the locale without its script, e.g. `sr-Latn` → `sr`
+    /// 4. the macrolanguage of the language, e.g. `cmn` → `zh`
+    /// 5. the [root locale](#method.root)
+    ///
+    /// The root locale itself has no parent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolang::Locale;
+    ///
+    /// # #[cfg(feature = "alloc")] {
+    /// let locale: Locale = "es-MX".parse().unwrap();
+    /// assert_eq!(locale.parent().unwrap().to_string(), "es-419");
+    /// # }
+    /// assert_eq!(Locale::root().parent(), None);
+    /// ```
+    pub fn parent(&self) -> Option<Locale> {
+        let mut tag = TagBuffer::default();
+        write!(tag, "{}", self).expect("locale exceeds maximum tag length");
+        if let Some(parent) = PARENT_LOCALES.get(tag.as_str()) {
+            return Locale::parse(parent);
+        }
+
+        if self.region.is_some() {
+            Some(Locale { region: None, ..*self })
+        } else if self.script.is_some() {
+            Some(Locale { script: None, ..*self })
+        } else if let Some(macrolanguage) = macrolanguage(self.language) {
+            Some(Locale::new(macrolanguage))
+        } else if self.language != Language::Und {
+            Some(Locale::root())
+        } else {
+            None
+        }
+    }
+
+    /// Get the chain of locales to look up resources for, from the most to the least specific.
+    ///
+    /// The chain starts with this locale, followed by its [parents](#method.parent), and ends with
+    /// the [root locale](#method.root).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolang::Locale;
+    ///
+    /// # #[cfg(feature = "alloc")] {
+    /// let locale: Locale = "nb-NO".parse().unwrap();
+    /// let chain: Vec<String> =
+    ///     locale.fallback_chain().map(|l| l.to_string()).collect();
+    /// assert_eq!(chain, ["nb-NO", "nb", "no", "und"]);
+    /// # }
+    /// ```
+    pub fn fallback_chain(&self) -> impl Iterator<Item = Locale> {
+        iter::successors(Some(*self), Locale::parent)
+    }
+}
+
+impl From<Language> for Locale {
+    fn from(language: Language) -> Self {
+        Locale::new(language)
+    }
+}
+
+impl Display for Locale {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(
+            self.language
+                .to_639_1()
+                .unwrap_or_else(|| self.language.to_639_3()),
+        )?;
+        if let Some(script) = self.script() {
+            write!(f, "-{}", script)?;
+        }
+        if let Some(region) = self.region() {
+            write!(f, "-{}", region)?;
+        }
+        Ok(())
+    }
+}
+
+impl Debug for Locale {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+/// Stack buffer to format a locale into, long enough for `lll-Ssss-RRR`.
+#[derive(Default)]
+struct TagBuffer {
+    buf: [u8; 12],
+    len: usize,
+}
+
+impl TagBuffer {
+    fn as_str(&self) -> &str {
+        // only ASCII is ever written into the buffer
+        str::from_utf8(&self.buf[..self.len]).unwrap_or_default()
+    }
+}
+
+impl Write for TagBuffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.buf.len() {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Error returned when parsing a [`Locale`] fails. Available with the `alloc` feature.
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct ParseLocaleError(String);
+
+#[cfg(feature = "alloc")]
+impl Display for ParseLocaleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}' is not a valid locale.", self.0)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Error for ParseLocaleError {}
+
+#[cfg(feature = "alloc")]
+impl FromStr for Locale {
+    type Err = ParseLocaleError;
+
+    fn from_str(s: &str) -> Result<Self, ParseLocaleError> {
+        Locale::parse(s).ok_or_else(|| ParseLocaleError(s.to_owned()))
+    }
+}
+
+impl Locale {
+    /// Parse a BCP 47 language tag or a POSIX locale, without allocating an error.
+    pub(crate) fn parse(s: &str) -> Option<Self> {
+        // strip encoding and modifier of POSIX locales, e.g. `de_DE.UTF-8@euro`
+        let tag = s.split(['.', '@']).next().unwrap_or_default();
+        let mut subtags = tag.split(['-', '_']).peekable();
+
+        let language = subtags.next().and_then(parse_language)?;
+        let mut locale = Locale::new(language);
+        if let Some(script) = subtags.next_if(|s| is_script(s)) {
+            let mut bytes = [0; 4];
+            for (byte, c) in bytes.iter_mut().zip(script.bytes()) {
+                *byte = c.to_ascii_lowercase();
+            }
+            bytes[0] = bytes[0].to_ascii_uppercase();
+            locale.script = Some(bytes);
+        }
+        if let Some(region) = subtags.next_if(|s| is_region(s)) {
+            let mut bytes = [0; 3];
+            for (byte, c) in bytes.iter_mut().zip(region.bytes()) {
+                *byte = c.to_ascii_uppercase();
+            }
+            locale.region = Some(bytes);
+        }
+
+        match subtags.next() {
+            Some(_) => None,
+            None => Some(locale),
+        }
+    }
+}
+
+fn parse_language(code: &str) -> Option<Language> {
+    let mut bytes = [0; 3];
+    if !(2..=3).contains(&code.len()) {
+        return None;
+    }
+    for (byte, c) in bytes.iter_mut().zip(code.bytes()) {
+        *byte = c.to_ascii_lowercase();
+    }
+    let code = str::from_utf8(&bytes[..code.len()]).ok()?;
+    Language::from_639_1(code)
+        .or_else(|| Language::from_639_3(code))
+        .or_else(|| Language::from_639_2b(code))
+}
+
+fn is_script(subtag: &str) -> bool {
+    subtag.len() == 4 && subtag.bytes().all(|c| c.is_ascii_alphabetic())
+}
+
+fn is_region(subtag: &str) -> bool {
+    (subtag.len() == 2 && subtag.bytes().all(|c| c.is_ascii_alphabetic()))
+        || (subtag.len() == 3 && subtag.bytes().all(|c| c.is_ascii_digit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain(locale: &str) -> Vec<String> {
+        Locale::parse(locale)
+            .unwrap()
+            .fallback_chain()
+            .map(|l| l.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let locale = Locale::parse("ZH_hant_hk").unwrap();
+        assert_eq!(locale.language(), Language::Zho);
+        assert_eq!(locale.script(), Some("Hant"));
+        assert_eq!(locale.region(), Some("HK"));
+        assert_eq!(locale.to_string(), "zh-Hant-HK");
+        assert_eq!(
+            Locale::parse("de_DE.UTF-8@euro").unwrap().to_string(),
+            "de-DE"
+        );
+        assert_eq!(Locale::parse("es-419").unwrap().region(), Some("419"));
+        assert_eq!(Locale::parse("ger").unwrap().to_string(), "de");
+        #[cfg(not(isolang_subset = "639_1"))]
+        assert_eq!(Locale::parse("cmn").unwrap().to_string(), "cmn");
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(Locale::parse("").is_none());
+        assert!(Locale::parse("xx-DE").is_none());
+        assert!(Locale::parse("de-DE-1996").is_none());
+        assert!(Locale::parse("de-Latn-DE-DE").is_none());
+        assert!(Locale::parse("deutsch").is_none());
+    }
+
+    #[test]
+    fn test_fallback_chains() {
+        assert_eq!(chain("nb"), ["nb", "no", "und"]);
+        #[cfg(not(isolang_subset = "639_1"))]
+        assert_eq!(chain("cmn-CN"), ["cmn-CN", "cmn", "zh", "und"]);
+        assert_eq!(chain("sr-Latn-RS"), ["sr-Latn-RS", "sr-Latn", "sr", "und"]);
+        assert_eq!(chain("en-GB"), ["en-GB", "en-001", "en", "und"]);
+        assert_eq!(
+            chain("zh-Hant-MO"),
+            ["zh-Hant-MO", "zh-Hant-HK", "zh-Hant", "zh", "und"]
+        );
+        assert_eq!(chain("und"), ["und"]);
+    }
+
+    #[test]
+    fn test_parent_locales_are_valid() {
+        for (locale, parent) in PARENT_LOCALES.entries() {
+            let locale = Locale::parse(locale).unwrap();
+            assert_eq!(locale.parent().unwrap().to_string(), *parent);
+        }
+    }
+
+    #[test]
+    fn test_language_fallbacks() {
+        assert_eq!(
+            Language::Nob.fallbacks().collect::<Vec<_>>(),
+            [Language::Nor]
+        );
+        #[cfg(not(isolang_subset = "639_1"))]
+        assert_eq!(
+            Language::Cmn.fallbacks().collect::<Vec<_>>(),
+            [Language::Zho]
+        );
+        assert_eq!(
+            Language::Hat.fallbacks().collect::<Vec<_>>(),
+            [Language::Fra]
+        );
+        assert_eq!(Language::Deu.fallbacks().count(), 0);
+    }
+}
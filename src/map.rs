@@ -0,0 +1,461 @@
+//! A map keyed by languages
+
+use alloc::vec::{self, Vec};
+use core::{
+    fmt::{self, Debug, Formatter},
+    iter::FusedIterator,
+    ops::{Index, IndexMut},
+};
+
+use crate::isotable::LANGUAGE_COUNT;
+use crate::{set, Language, LanguageSet};
+
+/// A map from languages to values, indexed by the discriminant of the language.
+///
+/// Since the variants of [`Language`](enum.Language.html) are dense, the values are stored in a
+/// plain vector with one slot per language, so that no hashing is required. Iteration happens in
+/// the order of the `Language` enum and only visits the languages with an entry.
+///
+/// The vector is allocated on the first insertion and has a slot for each of the 7927 languages
+/// (fewer with a subset), regardless of the number of entries. For maps with a handful of
+/// entries, a `BTreeMap<Language, V>` uses less memory.
+///
+/// # Examples
+///
+/// ```
+/// use isolang::{Language, LanguageMap};
+///
+/// let mut documents = LanguageMap::new();
+/// for language in [Language::Fra, Language::Deu, Language::Fra] {
+///     *documents.entry(language).or_insert(0) += 1;
+/// }
+/// assert_eq!(documents.get(Language::Fra), Some(&2));
+/// assert_eq!(
+///     documents.iter().collect::<Vec<_>>(),
+///     [(Language::Deu, &1), (Language::Fra, &2)]
+/// );
+/// ```
+#[derive(Clone)]
+pub struct LanguageMap<V> {
+    keys: LanguageSet,
+    len: usize,
+    slots: Vec<Option<V>>,
+}
+
+impl<V> LanguageMap<V> {
+    /// Create an empty map without allocating.
+    pub const fn new() -> Self {
+        LanguageMap {
+            keys: LanguageSet::new(),
+            len: 0,
+            slots: Vec::new(),
+        }
+    }
+
+    /// Get the number of entries in this map.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check whether this map contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the set of languages with an entry in this map.
+    pub fn languages(&self) -> &LanguageSet {
+        &self.keys
+    }
+
+    /// Check whether this map contains an entry for the given language.
+    pub fn contains_key(&self, language: Language) -> bool {
+        self.keys.contains(language)
+    }
+
+    /// Get a reference to the value of the given language.
+    pub fn get(&self, language: Language) -> Option<&V> {
+        self.slots.get(language as usize).and_then(Option::as_ref)
+    }
+
+    /// Get a mutable reference to the value of the given language.
+    pub fn get_mut(&mut self, language: Language) -> Option<&mut V> {
+        self.slots.get_mut(language as usize).and_then(Option::as_mut)
+    }
+
+    /// Insert a value for the given language.
+    ///
+    /// Returns the previous value of the language, if any.
+    pub fn insert(&mut self, language: Language, value: V) -> Option<V> {
+        if self.keys.insert(language) {
+            self.len += 1;
+        }
+        self.slot(language).replace(value)
+    }
+
+    /// Remove the entry of the given language.
+    ///
+    /// Returns the removed value, if any.
+    pub fn remove(&mut self, language: Language) -> Option<V> {
+        if self.keys.remove(language) {
+            self.len -= 1;
+        }
+        self.slots.get_mut(language as usize).and_then(Option::take)
+    }
+
+    /// Get the entry of the given language for in-place manipulation.
+    pub fn entry(&mut self, language: Language) -> Entry<'_, V> {
+        Entry { map: self, language }
+    }
+
+    /// Remove all entries, keeping the allocated memory.
+    pub fn clear(&mut self) {
+        for language in self.keys {
+            self.slots[language as usize] = None;
+        }
+        self.keys.clear();
+        self.len = 0;
+    }
+
+    /// Keep only the entries for which the predicate returns `true`.
+    pub fn retain<F>(&mut self, mut predicate: F)
+    where
+        F: FnMut(Language, &mut V) -> bool,
+    {
+        for language in self.keys {
+            if let Some(value) = self.get_mut(language) {
+                if !predicate(language, value) {
+                    self.remove(language);
+                }
+            }
+        }
+    }
+
+    /// Iterate over the languages and their values in the order of the `Language` enum.
+    pub fn iter(&self) -> Iter<'_, V> {
+        Iter { keys: self.keys.iter(), slots: &self.slots }
+    }
+
+    /// Iterate over the languages and mutable references to their values in the order of the
+    /// `Language` enum.
+    pub fn iter_mut(&mut self) -> IterMut<'_, V> {
+        IterMut {
+            keys: self.keys.iter(),
+            slots: self.slots.iter_mut(),
+            next: 0,
+        }
+    }
+
+    /// Iterate over the languages of this map in the order of the `Language` enum.
+    pub fn keys(&self) -> set::Iter {
+        self.keys.iter()
+    }
+
+    /// Iterate over the values of this map in the order of the `Language` enum.
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    /// Iterate over mutable references to the values of this map in the order of the `Language`
+    /// enum.
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.iter_mut().map(|(_, value)| value)
+    }
+
+    fn slot(&mut self, language: Language) -> &mut Option<V> {
+        if self.slots.is_empty() {
+            self.slots.resize_with(LANGUAGE_COUNT, || None);
+        }
+        &mut self.slots[language as usize]
+    }
+}
+
+impl<V> Default for LanguageMap<V> {
+    fn default() -> Self {
+        LanguageMap::new()
+    }
+}
+
+impl<V: PartialEq> PartialEq for LanguageMap<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.keys == other.keys && self.values().eq(other.values())
+    }
+}
+
+impl<V: Eq> Eq for LanguageMap<V> {}
+
+impl<V: Debug> Debug for LanguageMap<V> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<V> Index<Language> for LanguageMap<V> {
+    type Output = V;
+
+    /// Get the value of the given language.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the language has no entry in the map.
+    fn index(&self, language: Language) -> &V {
+        self.get(language).expect("no entry found for language")
+    }
+}
+
+impl<V> IndexMut<Language> for LanguageMap<V> {
+    fn index_mut(&mut self, language: Language) -> &mut V {
+        self.get_mut(language).expect("no entry found for language")
+    }
+}
+
+impl<V> FromIterator<(Language, V)> for LanguageMap<V> {
+    fn from_iter<I: IntoIterator<Item = (Language, V)>>(iter: I) -> Self {
+        let mut map = LanguageMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<V> Extend<(Language, V)> for LanguageMap<V> {
+    fn extend<I: IntoIterator<Item = (Language, V)>>(&mut self, iter: I) {
+        for (language, value) in iter {
+            self.insert(language, value);
+        }
+    }
+}
+
+impl<V, const N: usize> From<[(Language, V); N]> for LanguageMap<V> {
+    fn from(entries: [(Language, V); N]) -> Self {
+        entries.into_iter().collect()
+    }
+}
+
+impl<'a, V> IntoIterator for &'a LanguageMap<V> {
+    type Item = (Language, &'a V);
+    type IntoIter = Iter<'a, V>;
+
+    fn into_iter(self) -> Iter<'a, V> {
+        self.iter()
+    }
+}
+
+impl<'a, V> IntoIterator for &'a mut LanguageMap<V> {
+    type Item = (Language, &'a mut V);
+    type IntoIter = IterMut<'a, V>;
+
+    fn into_iter(self) -> IterMut<'a, V> {
+        self.iter_mut()
+    }
+}
+
+impl<V> IntoIterator for LanguageMap<V> {
+    type Item = (Language, V);
+    type IntoIter = IntoIter<V>;
+
+    fn into_iter(self) -> IntoIter<V> {
+        IntoIter {
+            keys: self.keys.iter(),
+            slots: self.slots.into_iter(),
+            next: 0,
+        }
+    }
+}
+
+/// A view into a single entry of a [`LanguageMap`], which may either be vacant or occupied.
+pub struct Entry<'a, V> {
+    map: &'a mut LanguageMap<V>,
+    language: Language,
+}
+
+impl<'a, V> Entry<'a, V> {
+    /// Get the language of this entry.
+    pub fn key(&self) -> Language {
+        self.language
+    }
+
+    /// Insert the given value if the entry is vacant and return a mutable reference to the
+    /// value.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    /// Insert the result of the given function if the entry is vacant and return a mutable
+    /// reference to the value.
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        if self.map.keys.insert(self.language) {
+            self.map.len += 1;
+        }
+        self.map.slot(self.language).get_or_insert_with(default)
+    }
+
+    /// Modify the value in place if the entry is occupied.
+    pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Self {
+        if let Some(value) = self.map.get_mut(self.language) {
+            f(value);
+        }
+        self
+    }
+}
+
+impl<'a, V: Default> Entry<'a, V> {
+    /// Insert the default value if the entry is vacant and return a mutable reference to the
+    /// value.
+    pub fn or_default(self) -> &'a mut V {
+        self.or_insert_with(V::default)
+    }
+}
+
+/// Iterator over the entries of a [`LanguageMap`].
+#[derive(Clone)]
+pub struct Iter<'a, V> {
+    keys: set::Iter,
+    slots: &'a [Option<V>],
+}
+
+impl<'a, V> Iterator for Iter<'a, V> {
+    type Item = (Language, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let language = self.keys.next()?;
+        self.slots[language as usize].as_ref().map(|value| (language, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.keys.size_hint()
+    }
+}
+
+impl<V> ExactSizeIterator for Iter<'_, V> {}
+
+impl<V> FusedIterator for Iter<'_, V> {}
+
+/// Iterator over the entries of a [`LanguageMap`] with mutable references to the values.
+pub struct IterMut<'a, V> {
+    keys: set::Iter,
+    slots: core::slice::IterMut<'a, Option<V>>,
+    /// Index of the language of the next slot in `slots`
+    next: usize,
+}
+
+impl<'a, V> Iterator for IterMut<'a, V> {
+    type Item = (Language, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let language = self.keys.next()?;
+        let value = self.slots.nth(language as usize - self.next)?;
+        self.next = language as usize + 1;
+        value.as_mut().map(|value| (language, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.keys.size_hint()
+    }
+}
+
+impl<V> ExactSizeIterator for IterMut<'_, V> {}
+
+impl<V> FusedIterator for IterMut<'_, V> {}
+
+/// Owning iterator over the entries of a [`LanguageMap`].
+pub struct IntoIter<V> {
+    keys: set::Iter,
+    slots: vec::IntoIter<Option<V>>,
+    /// Index of the language of the next slot in `slots`
+    next: usize,
+}
+
+impl<V> Iterator for IntoIter<V> {
+    type Item = (Language, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let language = self.keys.next()?;
+        let value = self.slots.nth(language as usize - self.next)?;
+        self.next = language as usize + 1;
+        value.map(|value| (language, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.keys.size_hint()
+    }
+}
+
+impl<V> ExactSizeIterator for IntoIter<V> {}
+
+impl<V> FusedIterator for IntoIter<V> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_get_remove() {
+        let mut map = LanguageMap::new();
+        assert!(map.is_empty());
+        assert_eq!(map.get(Language::Deu), None);
+        assert_eq!(map.insert(Language::Deu, "de"), None);
+        assert_eq!(map.insert(Language::Deu, "deu"), Some("de"));
+        map.insert(Language::Zul, "zul");
+        assert_eq!(map.len(), 2);
+        assert_eq!(map[Language::Deu], "deu");
+        assert!(map.contains_key(Language::Zul));
+        assert_eq!(map.remove(Language::Zul), Some("zul"));
+        assert_eq!(map.remove(Language::Zul), None);
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.iter_mut().len(), 1);
+        map.clear();
+        assert!(map.is_empty());
+        assert_eq!(map.get(Language::Deu), None);
+        assert_eq!(map, LanguageMap::new());
+    }
+
+    #[test]
+    fn test_entry() {
+        let mut map: LanguageMap<Vec<u8>> = LanguageMap::new();
+        map.entry(Language::Fra).or_default().push(1);
+        map.entry(Language::Fra).or_default().push(2);
+        map.entry(Language::Eng)
+            .and_modify(|v| v.push(3))
+            .or_insert_with(|| vec![0]);
+        assert_eq!(map[Language::Fra], [1, 2]);
+        assert_eq!(map[Language::Eng], [0]);
+        assert_eq!(map.entry(Language::Eng).key(), Language::Eng);
+    }
+
+    #[test]
+    fn test_iteration_order() {
+        let mut map = LanguageMap::from([
+            (Language::Zul, 3),
+            (Language::Afr, 1),
+            (Language::Deu, 2),
+        ]);
+        let expected = [Language::Afr, Language::Deu, Language::Zul];
+        assert!(map.keys().eq(expected));
+        assert_eq!(map.values().copied().collect::<Vec<_>>(), [1, 2, 3]);
+        for value in map.values_mut() {
+            *value *= 10;
+        }
+        map.retain(|_, value| *value > 10);
+        assert_eq!(
+            map.into_iter().collect::<Vec<_>>(),
+            [(Language::Deu, 20), (Language::Zul, 30)]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let map = LanguageMap::from([(Language::Fra, 1), (Language::Deu, 2)]);
+        let json = serde_json::to_string(&map).unwrap();
+        assert_eq!(json, r#"{"deu":2,"fra":1}"#);
+        assert_eq!(
+            serde_json::from_str::<LanguageMap<u8>>(&json).unwrap(),
+            map
+        );
+    }
+
+    #[test]
+    fn test_debug() {
+        let map = LanguageMap::from([(Language::Fra, 1), (Language::Deu, 2)]);
+        assert_eq!(format!("{:?}", map), "{deu: 2, fra: 1}");
+    }
+}
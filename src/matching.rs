@@ -0,0 +1,248 @@
+use crate::isotable::{macrolanguage, LANGUAGE_MATCHES};
+use crate::{Language, Locale};
+
+/// Distance between unrelated languages
+const LANGUAGE_DISTANCE: u16 = 80;
+/// Distance between different scripts of the same language
+const SCRIPT_DISTANCE: u16 = 50;
+/// Distance between different regions of the same language and script
+const REGION_DISTANCE: u16 = 4;
+/// Distance added for each desired locale after the first one
+const DEMOTION_PER_DESIRED: u16 = 5;
+
+/// A supported language or locale picked by a [`LanguageMatcher`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LanguageMatch<T> {
+    /// The best matching supported language or locale
+    pub supported: T,
+    /// How well the supported locale matches the desired one, from 100 (exact match) down to
+    /// 100 minus the threshold of the matcher
+    pub confidence: u8,
+}
+
+/// Language matching based on the distances of the Unicode CLDR.
+///
+/// The distance of a desired and a supported locale is the sum of the distance of their
+/// languages, scripts and regions. Languages are equal if they are the same or if one is the
+/// macrolanguage of the other, e.g. `cmn` and `zh`. Closely related languages, such as Danish and
+/// Norwegian Bokmål, have a small distance, while other languages have a distance of 80. Scripts
+/// and regions are only compared if both locales have them. Likely subtags are not added, so `en`
+/// matches `en-GB` exactly.
+///
+/// Supported locales with a distance equal to or above the threshold of the matcher (50 by
+/// default) are never picked.
+///
+/// # Examples
+///
+/// ```
+/// use isolang::{Language, LanguageMatcher};
+///
+/// let matcher = LanguageMatcher::new();
+/// let supported = [Language::Eng, Language::Dan, Language::Por];
+///
+/// let found = matcher.best_match([Language::Nob], &supported).unwrap();
+/// assert_eq!(found.supported, Language::Dan);
+/// assert_eq!(found.confidence, 92);
+///
+/// // Spanish is no substitute for Portuguese
+/// assert!(matcher.best_match([Language::Spa], &supported).is_none());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct LanguageMatcher {
+    threshold: u16,
+}
+
+impl Default for LanguageMatcher {
+    fn default() -> Self {
+        LanguageMatcher { threshold: 50 }
+    }
+}
+
+impl LanguageMatcher {
+    /// Create a matcher with the default threshold of 50.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the distance at which supported locales are no longer considered a match.
+    ///
+    /// The threshold is capped at 100.
+    pub fn with_threshold(self, threshold: u8) -> Self {
+        LanguageMatcher { threshold: u16::from(threshold.min(100)) }
+    }
+
+    /// Get the distance between a desired and a supported locale.
+    ///
+    /// The distance is 0 for equal locales. It is not symmetric, e.g. a Swiss German speaker
+    /// understands German, but not the other way around.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolang::{Language, LanguageMatcher, Locale};
+    ///
+    /// let matcher = LanguageMatcher::new();
+    /// assert_eq!(matcher.distance(Language::Gsw, Language::Deu), 4);
+    /// assert_eq!(matcher.distance(Language::Deu, Language::Gsw), 80);
+    /// # #[cfg(feature = "alloc")] {
+    /// let sr_latn: Locale = "sr-Latn".parse().unwrap();
+    /// let sr_cyrl: Locale = "sr-Cyrl".parse().unwrap();
+    /// assert_eq!(matcher.distance(sr_latn, sr_cyrl), 50);
+    /// # }
+    /// ```
+    pub fn distance(
+        &self,
+        desired: impl Into<Locale>,
+        supported: impl Into<Locale>,
+    ) -> u16 {
+        let (desired, supported) = (desired.into(), supported.into());
+        let language =
+            language_distance(desired.language(), supported.language());
+        let script = match (desired.script(), supported.script()) {
+            (Some(d), Some(s)) if d != s => SCRIPT_DISTANCE,
+            _ => 0,
+        };
+        let region = match (desired.region(), supported.region()) {
+            (Some(d), Some(s)) if d != s => REGION_DISTANCE,
+            _ => 0,
+        };
+        language + script + region
+    }
+
+    /// Pick the supported language or locale best matching the desired ones.
+    ///
+    /// The desired locales are ordered by preference, every locale after the first one adds a
+    /// distance of 5. Among supported locales with the same distance, the first one is picked.
+    /// `None` is returned if no supported locale is below the threshold.
+    pub fn best_match<D, T>(
+        &self,
+        desired: impl IntoIterator<Item = D>,
+        supported: &[T],
+    ) -> Option<LanguageMatch<T>>
+    where
+        D: Into<Locale>,
+        T: Copy + Into<Locale>,
+    {
+        let mut best: Option<(T, u16)> = None;
+        for (idx, desired) in desired.into_iter().enumerate() {
+            let desired = desired.into();
+            let demotion = DEMOTION_PER_DESIRED.saturating_mul(idx as u16);
+            if demotion >= self.threshold {
+                break;
+            }
+            for candidate in supported {
+                let distance = self.distance(desired, *candidate) + demotion;
+                if distance < self.threshold
+                    && best.is_none_or(|(_, best)| distance < best)
+                {
+                    best = Some((*candidate, distance));
+                }
+            }
+        }
+        best.map(|(supported, distance)| LanguageMatch {
+            supported,
+            confidence: 100 - distance as u8,
+        })
+    }
+}
+
+/// Get the distance of two languages from the `languageMatching` data (`written_new`) in
+/// `languageInfo.xml` of the Unicode CLDR.
+///
+/// The generated entries are `(desired, supported, distance, oneway)`. Unless an entry is one-way,
+/// it applies in both directions. Entries with languages missing from the `isolang_subset` cfg are
+/// left out.
+fn language_distance(desired: Language, supported: Language) -> u16 {
+    let canonical = |lang| macrolanguage(lang).unwrap_or(lang);
+    let (desired, supported) = (canonical(desired), canonical(supported));
+    if desired == supported {
+        return 0;
+    }
+    LANGUAGE_MATCHES
+        .iter()
+        .find(|(d, s, _, oneway)| {
+            (*d == desired && *s == supported)
+                || (!oneway && *d == supported && *s == desired)
+        })
+        .map_or(LANGUAGE_DISTANCE, |(_, _, distance, _)| *distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn locale(tag: &str) -> Locale {
+        Locale::parse(tag).unwrap()
+    }
+
+    #[test]
+    fn test_distances() {
+        let matcher = LanguageMatcher::new();
+        assert_eq!(matcher.distance(Language::Deu, Language::Deu), 0);
+        #[cfg(not(isolang_subset = "639_1"))]
+        assert_eq!(matcher.distance(Language::Cmn, Language::Zho), 0);
+        assert_eq!(matcher.distance(Language::Nor, Language::Nob), 1);
+        assert_eq!(matcher.distance(Language::Dan, Language::Nob), 8);
+        assert_eq!(matcher.distance(Language::Nob, Language::Dan), 8);
+        assert_eq!(matcher.distance(Language::Spa, Language::Por), 80);
+        assert_eq!(matcher.distance(Language::Afr, Language::Nld), 20);
+        assert_eq!(matcher.distance(Language::Nld, Language::Afr), 80);
+        assert_eq!(matcher.distance(Language::Hbs, Language::Srp), 4);
+        assert_eq!(matcher.distance(locale("pt-BR"), locale("pt-PT")), 4);
+        assert_eq!(matcher.distance(locale("pt-BR"), Language::Por), 0);
+        assert_eq!(matcher.distance(locale("zh-Hant"), locale("zh-Hans")), 50);
+    }
+
+    #[test]
+    fn test_language_matches() {
+        for (desired, supported, distance, _) in LANGUAGE_MATCHES {
+            // languages are compared by their macrolanguage
+            assert_eq!(macrolanguage(*desired), None);
+            assert_eq!(macrolanguage(*supported), None);
+            assert!(*distance < LANGUAGE_DISTANCE);
+        }
+    }
+
+    #[test]
+    fn test_best_match() {
+        let matcher = LanguageMatcher::new();
+        let supported = [locale("en"), locale("pt-PT"), locale("pt-BR")];
+        let found = matcher.best_match([locale("pt-BR")], &supported);
+        assert_eq!(
+            found,
+            Some(LanguageMatch { supported: locale("pt-BR"), confidence: 100 })
+        );
+        // later desired locales are demoted
+        let found = matcher
+            .best_match(
+                [Language::Nno, Language::Eng],
+                &[Language::Eng, Language::Nob],
+            )
+            .unwrap();
+        assert_eq!(found.supported, Language::Eng);
+        assert_eq!(found.confidence, 95);
+        let found = matcher
+            .best_match(
+                [Language::Nno, Language::Eng, Language::Deu],
+                &[Language::Deu, Language::Nob],
+            )
+            .unwrap();
+        assert_eq!(found.supported, Language::Nob);
+        assert_eq!(found.confidence, 90);
+        let found = matcher
+            .best_match(
+                [Language::Spa, Language::Eng],
+                &[Language::Por, Language::Eng],
+            )
+            .unwrap();
+        assert_eq!(found.supported, Language::Eng);
+        assert_eq!(found.confidence, 95);
+        assert!(matcher
+            .best_match([Language::Spa], &[Language::Por])
+            .is_none());
+        assert!(matcher
+            .with_threshold(100)
+            .best_match([Language::Spa], &[Language::Por])
+            .is_some());
+    }
+}
@@ -0,0 +1,106 @@
+use std::error::Error;
+
+use bytes::BytesMut;
+use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use crate::sql::{language_from_id, language_from_text, LanguageId};
+use crate::Language;
+
+impl ToSql for Language {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        self.to_639_3().to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <&str as ToSql>::accepts(ty)
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Language {
+    fn from_sql(
+        ty: &Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let text = <&str as FromSql>::from_sql(ty, raw)?;
+        Ok(language_from_text(text)?)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <&str as FromSql>::accepts(ty)
+    }
+}
+
+impl ToSql for LanguageId {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        // stable IDs are below 26³, hence fit into an `i16`
+        (self.0.to_stable_id() as i16).to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <i16 as ToSql>::accepts(ty)
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for LanguageId {
+    fn from_sql(
+        ty: &Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let id = i16::from_sql(ty, raw)?;
+        Ok(language_from_id(id.into())?)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <i16 as FromSql>::accepts(ty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip<T>(value: T, ty: &Type) -> T
+    where
+        T: ToSql + for<'a> FromSql<'a>,
+    {
+        let mut buf = BytesMut::new();
+        assert!(matches!(value.to_sql_checked(ty, &mut buf), Ok(IsNull::No)));
+        T::from_sql(ty, &buf).unwrap()
+    }
+
+    #[test]
+    fn test_text() {
+        for ty in [Type::BPCHAR, Type::VARCHAR, Type::TEXT] {
+            assert_eq!(roundtrip(Language::Deu, &ty), Language::Deu);
+        }
+        assert!(!<Language as ToSql>::accepts(&Type::INT2));
+        assert_eq!(
+            Language::from_sql(&Type::BPCHAR, b"xxx").unwrap_err().to_string(),
+            "'xxx' is not a valid ISO 639-3 code"
+        );
+    }
+
+    #[test]
+    fn test_smallint() {
+        let id = LanguageId(Language::Deu);
+        assert_eq!(roundtrip(id, &Type::INT2), id);
+        assert!(!<LanguageId as ToSql>::accepts(&Type::TEXT));
+        assert!(
+            LanguageId::from_sql(&Type::INT2, &(-1i16).to_be_bytes()).is_err()
+        );
+        let mut buf = BytesMut::new();
+        assert!(id.to_sql_checked(&Type::TEXT, &mut buf).is_err());
+    }
+}
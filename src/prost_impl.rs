@@ -0,0 +1,103 @@
+use prost::UnknownEnumValue;
+
+use crate::Language;
+
+/// A [`Language`] as value of the `isolang.Language` enum of `proto/isolang.proto`, for the `i32`
+/// fields of messages generated by prost.
+///
+/// The value of a language is its [stable ID](enum.Language.html#method.to_stable_id) plus one,
+/// since 0 is `LANGUAGE_UNSPECIFIED`. Like enums generated by prost, it converts to `i32` with
+/// `From` and from `i32` with `TryFrom`. Available with the `prost` feature.
+///
+/// # Example
+///
+/// ```
+/// use isolang::{Language, ProtoLanguage};
+///
+/// let value = i32::from(ProtoLanguage(Language::Deu));
+/// assert_eq!(value, 2153);
+/// assert_eq!(ProtoLanguage::try_from(value), Ok(ProtoLanguage(Language::Deu)));
+/// assert!(ProtoLanguage::try_from(0).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub struct ProtoLanguage(pub Language);
+
+impl From<Language> for ProtoLanguage {
+    fn from(language: Language) -> Self {
+        ProtoLanguage(language)
+    }
+}
+
+impl From<ProtoLanguage> for Language {
+    fn from(language: ProtoLanguage) -> Self {
+        language.0
+    }
+}
+
+impl From<ProtoLanguage> for i32 {
+    fn from(language: ProtoLanguage) -> Self {
+        i32::from(language.0.to_stable_id()) + 1
+    }
+}
+
+/// `LANGUAGE_UNSPECIFIED` (0) is an error, as well as languages excluded by the `isolang_subset`
+/// cfg.
+impl TryFrom<i32> for ProtoLanguage {
+    type Error = UnknownEnumValue;
+
+    fn try_from(value: i32) -> Result<Self, UnknownEnumValue> {
+        value
+            .checked_sub(1)
+            .and_then(|id| u16::try_from(id).ok())
+            .and_then(Language::from_stable_id)
+            .map(ProtoLanguage)
+            .ok_or(UnknownEnumValue(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(i32::from(ProtoLanguage(Language::Aaa)), 1);
+        assert_eq!(i32::from(ProtoLanguage(Language::Deu)), 2153);
+        assert_eq!(
+            ProtoLanguage::try_from(2153),
+            Ok(ProtoLanguage(Language::Deu))
+        );
+        for value in [0, -1, i32::MIN, 17577, i32::MAX] {
+            assert_eq!(
+                ProtoLanguage::try_from(value),
+                Err(UnknownEnumValue(value))
+            );
+        }
+    }
+
+    #[test]
+    fn test_proto_file() {
+        let proto = include_str!("../proto/isolang.proto");
+        let mut count = 0;
+        for line in proto.lines() {
+            let Some(value) = line.trim().strip_prefix("LANGUAGE_") else {
+                continue;
+            };
+            let (name, number) =
+                value.trim_end_matches(';').split_once(" = ").unwrap();
+            let number: i32 = number.parse().unwrap();
+            if name == "UNSPECIFIED" {
+                assert_eq!(number, 0);
+                continue;
+            }
+            let language = Language::from_639_3(&name.to_lowercase()).unwrap();
+            assert_eq!(i32::from(ProtoLanguage(language)), number);
+            assert_eq!(
+                ProtoLanguage::try_from(number),
+                Ok(ProtoLanguage(language))
+            );
+            count += 1;
+        }
+        assert_eq!(count, crate::languages().count());
+    }
+}
@@ -0,0 +1,85 @@
+use rusqlite::types::{
+    FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef,
+};
+
+use crate::sql::{language_from_id, language_from_text, LanguageId};
+use crate::Language;
+
+impl ToSql for Language {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.to_639_3()))
+    }
+}
+
+impl FromSql for Language {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        language_from_text(value.as_str()?)
+            .map_err(|e| FromSqlError::Other(Box::new(e)))
+    }
+}
+
+impl ToSql for LanguageId {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(i64::from(self.0.to_stable_id())))
+    }
+}
+
+impl FromSql for LanguageId {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        language_from_id(value.as_i64()?)
+            .map_err(|e| FromSqlError::Other(Box::new(e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rusqlite::{params, Connection};
+
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute(
+            "CREATE TABLE documents (language TEXT, language_id INTEGER)",
+            [],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO documents VALUES (?1, ?2)",
+            params![Language::Deu, LanguageId(Language::Fra)],
+        )
+        .unwrap();
+        let row: (Language, LanguageId, String, i64) = conn
+            .query_row(
+                "SELECT language, language_id, language, language_id FROM documents",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+            )
+            .unwrap();
+        assert_eq!(
+            row,
+            (
+                Language::Deu,
+                LanguageId(Language::Fra),
+                "deu".to_owned(),
+                Language::Fra.to_stable_id().into()
+            )
+        );
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        let conn = Connection::open_in_memory().unwrap();
+        let error = conn
+            .query_row("SELECT 'xxx'", [], |row| row.get::<_, Language>(0))
+            .unwrap_err();
+        assert!(error.to_string().contains("'xxx' is not a valid ISO 639-3"));
+        assert!(conn
+            .query_row("SELECT 1", [], |row| row.get::<_, Language>(0))
+            .is_err());
+        assert!(conn
+            .query_row("SELECT 20000", [], |row| row.get::<_, LanguageId>(0))
+            .is_err());
+    }
+}
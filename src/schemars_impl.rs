@@ -0,0 +1,173 @@
+use std::borrow::Cow;
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::Language;
+
+/// Languages are described as string of all ISO 639-3 codes, which is how they are serialized with
+/// the `serde` feature. With `english_names`, every code is a `const` alternative with the English
+/// name as description, like schemars derives it for documented unit variants. The modules of
+/// [`isolang::serde`](crate::serde) provide the schemas of the other representations.
+impl JsonSchema for Language {
+    fn schema_name() -> Cow<'static, str> {
+        "Language".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "isolang::Language".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        string_schema("ISO 639-3 language code", |language| {
+            Some(language.to_639_3())
+        })
+    }
+}
+
+/// Describe the languages as the strings returned by `value`, leaving out languages without one.
+#[cfg(feature = "english_names")]
+pub(crate) fn string_schema(
+    description: &str,
+    value: impl Fn(Language) -> Option<&'static str>,
+) -> Schema {
+    let values: Vec<Schema> = crate::all_languages()
+        .filter_map(|language| {
+            let value = value(language)?;
+            Some(json_schema!({
+                "const": value,
+                "description": language.to_name(),
+            }))
+        })
+        .collect();
+    json_schema!({
+        "type": "string",
+        "description": description,
+        "oneOf": values,
+    })
+}
+
+/// Describe the languages as the strings returned by `value`, leaving out languages without one.
+#[cfg(not(feature = "english_names"))]
+pub(crate) fn string_schema(
+    description: &str,
+    value: impl Fn(Language) -> Option<&'static str>,
+) -> Schema {
+    let values: Vec<&str> = crate::all_languages().filter_map(value).collect();
+    json_schema!({
+        "type": "string",
+        "description": description,
+        "enum": values,
+    })
+}
+
+/// Describe the languages as their stable IDs.
+#[cfg(all(feature = "serde", feature = "english_names"))]
+pub(crate) fn integer_schema() -> Schema {
+    let values: Vec<Schema> = crate::all_languages()
+        .map(|language| {
+            json_schema!({
+                "const": language.to_stable_id(),
+                "description": language.to_name(),
+            })
+        })
+        .collect();
+    json_schema!({
+        "type": "integer",
+        "description": "stable language ID",
+        "oneOf": values,
+    })
+}
+
+/// Describe the languages as their stable IDs.
+#[cfg(all(feature = "serde", not(feature = "english_names")))]
+pub(crate) fn integer_schema() -> Schema {
+    let values: Vec<u16> = crate::all_languages()
+        .map(|language| language.to_stable_id())
+        .collect();
+    json_schema!({
+        "type": "integer",
+        "description": "stable language ID",
+        "enum": values,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use schemars::schema_for;
+
+    use super::*;
+
+    #[test]
+    fn test_schema() {
+        let schema = schema_for!(Language);
+        assert_eq!(schema.get("title").unwrap(), "Language");
+        assert_eq!(schema.get("type").unwrap(), "string");
+
+        #[cfg(feature = "english_names")]
+        let codes = schema.get("oneOf").unwrap().as_array().unwrap();
+        #[cfg(feature = "english_names")]
+        assert!(codes.iter().any(|code| code.get("const").unwrap() == "deu"
+            && code.get("description").unwrap() == "German"));
+        #[cfg(not(feature = "english_names"))]
+        let codes = schema.get("enum").unwrap().as_array().unwrap();
+        #[cfg(not(feature = "english_names"))]
+        assert!(codes.iter().any(|code| code == "deu"));
+        assert_eq!(codes.len(), crate::languages().count());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_schemas() {
+        use serde_json::{value::Serializer, Value};
+
+        use crate::serde::*;
+
+        type Serialize = fn(&Language, Serializer) -> serde_json::Result<Value>;
+
+        // the schema lists exactly the serialized values
+        fn check(
+            json_schema: fn(&mut SchemaGenerator) -> Schema,
+            instance_type: &str,
+            serialize: Serialize,
+        ) {
+            let schema = json_schema(&mut SchemaGenerator::default());
+            assert_eq!(schema.get("type").unwrap(), instance_type);
+            #[cfg(feature = "english_names")]
+            let values: Vec<Value> = schema
+                .get("oneOf")
+                .unwrap()
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|value| value.get("const").unwrap().clone())
+                .collect();
+            #[cfg(not(feature = "english_names"))]
+            let values =
+                schema.get("enum").unwrap().as_array().unwrap().clone();
+            let serialized: Vec<Value> = crate::languages()
+                .filter_map(|language| serialize(&language, Serializer).ok())
+                .collect();
+            assert_eq!(values, serialized);
+        }
+
+        check(iso639_1::json_schema, "string", iso639_1::serialize);
+        check(iso639_2b::json_schema, "string", iso639_2b::serialize);
+        check(iso639_3::json_schema, "string", iso639_3::serialize);
+        check(
+            iso639_1_strict::json_schema,
+            "string",
+            iso639_1_strict::serialize,
+        );
+        check(
+            iso639_3_strict::json_schema,
+            "string",
+            iso639_3_strict::serialize,
+        );
+        #[cfg(feature = "english_names")]
+        check(name::json_schema, "string", name::serialize);
+        check(integer::json_schema, "integer", integer::serialize);
+        check(Language::json_schema, "string", |language, s| {
+            ::serde::Serialize::serialize(language, s)
+        });
+    }
+}
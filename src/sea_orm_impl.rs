@@ -0,0 +1,167 @@
+use sea_orm::sea_query::{
+    ArrayType, ColumnType, Nullable, Value, ValueType, ValueTypeErr,
+};
+use sea_orm::{ColIdx, DbErr, QueryResult, TryGetError, TryGetable};
+
+use crate::sql::{language_from_id, language_from_text, LanguageId};
+use crate::Language;
+
+impl From<Language> for Value {
+    fn from(language: Language) -> Self {
+        Value::String(Some(Box::new(language.to_639_3().to_owned())))
+    }
+}
+
+impl Nullable for Language {
+    fn null() -> Value {
+        Value::String(None)
+    }
+}
+
+impl ValueType for Language {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::String(Some(text)) => {
+                language_from_text(&text).map_err(|_| ValueTypeErr)
+            }
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        "Language".to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::String
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::Char(Some(3))
+    }
+}
+
+impl TryGetable for Language {
+    fn try_get_by<I: ColIdx>(
+        res: &QueryResult,
+        index: I,
+    ) -> Result<Self, TryGetError> {
+        let text = String::try_get_by(res, index)?;
+        language_from_text(&text)
+            .map_err(|e| TryGetError::DbErr(DbErr::Type(e.to_string())))
+    }
+}
+
+impl From<LanguageId> for Value {
+    fn from(id: LanguageId) -> Self {
+        // stable IDs are below 26³, hence fit into an `i16`
+        Value::SmallInt(Some(id.0.to_stable_id() as i16))
+    }
+}
+
+impl Nullable for LanguageId {
+    fn null() -> Value {
+        Value::SmallInt(None)
+    }
+}
+
+impl ValueType for LanguageId {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::SmallInt(Some(id)) => {
+                language_from_id(id.into()).map_err(|_| ValueTypeErr)
+            }
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        "LanguageId".to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::SmallInt
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::SmallInteger
+    }
+}
+
+impl TryGetable for LanguageId {
+    fn try_get_by<I: ColIdx>(
+        res: &QueryResult,
+        index: I,
+    ) -> Result<Self, TryGetError> {
+        let id = i16::try_get_by(res, index)?;
+        language_from_id(id.into())
+            .map_err(|e| TryGetError::DbErr(DbErr::Type(e.to_string())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value() {
+        let value = Value::from(Language::Deu);
+        assert_eq!(value, Value::String(Some(Box::new("deu".to_owned()))));
+        assert_eq!(
+            <Language as ValueType>::try_from(value).unwrap(),
+            Language::Deu
+        );
+        assert_eq!(Value::from(None::<Language>), Value::String(None));
+        assert!(<Language as ValueType>::try_from(Value::String(None)).is_err());
+        assert!(<Language as ValueType>::try_from("xxx".into()).is_err());
+        assert!(<Language as ValueType>::try_from(1i16.into()).is_err());
+        assert_eq!(Language::column_type(), ColumnType::Char(Some(3)));
+
+        // `char(n)` columns are padded with spaces
+        assert_eq!(
+            <Language as ValueType>::try_from("deu  ".into()).unwrap(),
+            Language::Deu
+        );
+    }
+
+    #[test]
+    fn test_language_id() {
+        let value = Value::from(LanguageId(Language::Deu));
+        assert_eq!(value, Value::SmallInt(Some(2152)));
+        assert_eq!(
+            <LanguageId as ValueType>::try_from(value).unwrap(),
+            LanguageId(Language::Deu)
+        );
+        assert!(<LanguageId as ValueType>::try_from((-1i16).into()).is_err());
+        assert!(<LanguageId as ValueType>::try_from("deu".into()).is_err());
+        assert_eq!(LanguageId::column_type(), ColumnType::SmallInteger);
+    }
+
+    #[test]
+    fn test_try_get() {
+        use std::collections::BTreeMap;
+
+        use sea_orm::{ConnectionTrait, DbBackend, MockDatabase, Statement};
+
+        let row =
+            |code: &str| BTreeMap::from([("language", Value::from(code))]);
+        let connection = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[row("deu  "), row("xxx")]])
+            .into_connection();
+        let rows = futures_lite::future::block_on(connection.query_all(
+            Statement::from_string(DbBackend::Postgres, "SELECT language"),
+        ))
+        .unwrap();
+        assert_eq!(
+            rows[0].try_get_by::<Language, _>("language").unwrap(),
+            Language::Deu
+        );
+        assert_eq!(
+            rows[1]
+                .try_get_by::<Language, _>("language")
+                .unwrap_err()
+                .to_string(),
+            "Type Error: 'xxx' is not a valid ISO 639-3 code"
+        );
+    }
+}
@@ -0,0 +1,435 @@
+//! Alternative serde representations of [`Language`], for use with `#[serde(with = "...")]`
+//!
+//! By default, a [`Language`] is serialized as its ISO 639-3 code. The modules of this module
+//! serialize it as another code, as its English name or as its stable ID. Deserialization accepts
+//! everything that the default representation accepts, besides the respective representation.
+//! Available with the `serde` feature.
+//!
+//! Depending on the enabled features, the default representation also accepts English names and
+//! autonyms. For input validation, the `*_strict` modules only accept codes of one standard,
+//! regardless of the features.
+//!
+//! # Examples
+//!
+//! ```
+//! use isolang::Language;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Document {
+//!     #[serde(with = "isolang::serde::iso639_1")]
+//!     language: Language,
+//! }
+//!
+//! let document = Document { language: Language::Deu };
+//! assert_eq!(serde_json::to_string(&document).unwrap(), r#"{"language":"de"}"#);
+//! ```
+
+use ::serde::de::{self, Deserialize, Deserializer, Unexpected, Visitor};
+use ::serde::ser::{self, Serializer};
+use core::fmt::{self, Formatter};
+#[cfg(feature = "schemars")]
+use schemars::SchemaGenerator;
+#[cfg(feature = "utoipa")]
+use utoipa::openapi::{schema::Schema as OpenApiSchema, RefOr};
+
+use crate::serde_impl::LanguageVisitor;
+use crate::Language;
+
+/// Visitor of strings, looking up the language with the given function.
+struct LookupVisitor {
+    lookup: fn(&str) -> Option<Language>,
+    expecting: &'static str,
+}
+
+impl Visitor<'_> for LookupVisitor {
+    type Value = Language;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Language, E> {
+        (self.lookup)(v)
+            .ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Language, E> {
+        match core::str::from_utf8(v) {
+            Ok(s) => self.visit_str(s),
+            Err(_) => Err(E::invalid_value(Unexpected::Bytes(v), &self)),
+        }
+    }
+}
+
+/// Define the schemas of a representation as string, given its description and a function
+/// returning the string of a language, or `None` if the language cannot be serialized.
+macro_rules! string_schemas {
+    ($description:expr, $value:expr) => {
+        /// Describe this representation in a JSON schema, for `#[schemars(schema_with = "...")]`.
+        /// Available with the `schemars` feature.
+        #[cfg(feature = "schemars")]
+        pub fn json_schema(_: &mut SchemaGenerator) -> schemars::Schema {
+            crate::schemars_impl::string_schema($description, $value)
+        }
+
+        /// Describe this representation in an OpenAPI schema, for `#[schema(schema_with = ...)]`.
+        /// Available with the `utoipa` feature.
+        #[cfg(feature = "utoipa")]
+        pub fn schema() -> RefOr<OpenApiSchema> {
+            crate::utoipa_impl::string_schema($description, $value)
+        }
+    };
+}
+
+/// The ISO 639-1 code, or the ISO 639-3 code for languages without one.
+pub mod iso639_1 {
+    use super::*;
+
+    /// Serialize the ISO 639-1 code, falling back to the ISO 639-3 code.
+    pub fn serialize<S: Serializer>(
+        language: &Language,
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        s.serialize_str(language.to_639_1().unwrap_or(language.to_639_3()))
+    }
+
+    /// Deserialize a language like the default representation.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Language, D::Error> {
+        Language::deserialize(d)
+    }
+
+    string_schemas!(
+        "ISO 639-1 language code, or ISO 639-3 code for languages without one",
+        |language: Language| {
+            Some(language.to_639_1().unwrap_or(language.to_639_3()))
+        }
+    );
+}
+
+/// The ISO 639-2b code, which is the ISO 639-3 code for languages without one.
+pub mod iso639_2b {
+    use super::*;
+
+    /// Serialize the ISO 639-2b code.
+    pub fn serialize<S: Serializer>(
+        language: &Language,
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        s.serialize_str(language.to_639_2b())
+    }
+
+    /// Deserialize a language from its ISO 639-2b code, or like the default representation.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Language, D::Error> {
+        d.deserialize_str(LookupVisitor {
+            lookup: |s| Language::from_639_2b(s).or_else(|| Language::parse(s)),
+            expecting: "an ISO 639-2b code or a language code",
+        })
+    }
+
+    string_schemas!("ISO 639-2b language code", |language: Language| {
+        Some(language.to_639_2b())
+    });
+}
+
+/// The ISO 639-3 code, as in the default representation.
+pub mod iso639_3 {
+    use super::*;
+
+    /// Serialize the ISO 639-3 code.
+    pub fn serialize<S: Serializer>(
+        language: &Language,
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        s.serialize_str(language.to_639_3())
+    }
+
+    /// Deserialize a language like the default representation.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Language, D::Error> {
+        Language::deserialize(d)
+    }
+
+    string_schemas!("ISO 639-3 language code", |language: Language| {
+        Some(language.to_639_3())
+    });
+}
+
+/// Only ISO 639-1 codes. Languages without one fail to serialize.
+pub mod iso639_1_strict {
+    use super::*;
+
+    /// Serialize the ISO 639-1 code, failing for languages without one.
+    pub fn serialize<S: Serializer>(
+        language: &Language,
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        match language.to_639_1() {
+            Some(code) => s.serialize_str(code),
+            None => Err(ser::Error::custom(format_args!(
+                "{} has no ISO 639-1 code",
+                language.to_639_3()
+            ))),
+        }
+    }
+
+    /// Deserialize a language from its ISO 639-1 code only.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Language, D::Error> {
+        d.deserialize_str(LookupVisitor {
+            lookup: Language::from_639_1,
+            expecting: "an ISO 639-1 code",
+        })
+    }
+
+    string_schemas!("ISO 639-1 language code", |language: Language| {
+        language.to_639_1()
+    });
+}
+
+/// Only ISO 639-3 codes.
+pub mod iso639_3_strict {
+    use super::*;
+
+    /// Serialize the ISO 639-3 code.
+    pub fn serialize<S: Serializer>(
+        language: &Language,
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        s.serialize_str(language.to_639_3())
+    }
+
+    /// Deserialize a language from its ISO 639-3 code only.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Language, D::Error> {
+        d.deserialize_str(LookupVisitor {
+            lookup: Language::from_639_3,
+            expecting: "an ISO 639-3 code",
+        })
+    }
+
+    string_schemas!("ISO 639-3 language code", |language: Language| {
+        Some(language.to_639_3())
+    });
+}
+
+/// The English name. Available with the `english_names` feature.
+///
+/// English names are not unique, e.g. "Swahili" is the name of both `swa` and `swh`. A shared
+/// name is read as the first of these languages in the ISO 639-3 table, so the others are
+/// serialized as ISO 639-3 code instead, which keeps the representation lossless.
+#[cfg(feature = "english_names")]
+pub mod name {
+    use super::*;
+
+    /// Serialize the English name, or the ISO 639-3 code if the name is shared with a preceding
+    /// language.
+    pub fn serialize<S: Serializer>(
+        language: &Language,
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        if crate::isotable::has_shared_name(*language) {
+            s.serialize_str(language.to_639_3())
+        } else {
+            s.serialize_str(language.to_name())
+        }
+    }
+
+    /// Deserialize a language from its English name, or like the default representation.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Language, D::Error> {
+        d.deserialize_str(LookupVisitor {
+            lookup: |s| Language::from_name(s).or_else(|| Language::parse(s)),
+            expecting: "an English language name or a language code",
+        })
+    }
+
+    string_schemas!("English language name", |language: Language| {
+        if crate::isotable::has_shared_name(language) {
+            Some(language.to_639_3())
+        } else {
+            Some(language.to_name())
+        }
+    });
+}
+
+/// The [stable ID](../enum.Language.html#method.to_stable_id) as `u16`, a compact form for
+/// binary formats.
+pub mod integer {
+    use super::*;
+
+    /// Serialize the stable ID.
+    pub fn serialize<S: Serializer>(
+        language: &Language,
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        s.serialize_u16(language.to_stable_id())
+    }
+
+    /// Deserialize a language from its stable ID.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Language, D::Error> {
+        d.deserialize_u16(LanguageVisitor)
+    }
+
+    /// Describe this representation in a JSON schema, for `#[schemars(schema_with = "...")]`.
+    /// Available with the `schemars` feature.
+    #[cfg(feature = "schemars")]
+    pub fn json_schema(_: &mut SchemaGenerator) -> schemars::Schema {
+        crate::schemars_impl::integer_schema()
+    }
+
+    /// Describe this representation in an OpenAPI schema, for `#[schema(schema_with = ...)]`.
+    /// Available with the `utoipa` feature.
+    #[cfg(feature = "utoipa")]
+    pub fn schema() -> RefOr<OpenApiSchema> {
+        crate::utoipa_impl::integer_schema()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::serde::{Deserialize, Serialize};
+
+    use crate::Language;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Codes {
+        #[serde(with = "crate::serde::iso639_1")]
+        iso639_1: Language,
+        #[serde(with = "crate::serde::iso639_2b")]
+        iso639_2b: Language,
+        #[serde(with = "crate::serde::iso639_3")]
+        iso639_3: Language,
+        #[serde(with = "crate::serde::integer")]
+        integer: Language,
+    }
+
+    #[test]
+    fn test_representations() {
+        let codes = Codes {
+            iso639_1: Language::Deu,
+            iso639_2b: Language::Deu,
+            iso639_3: Language::Deu,
+            integer: Language::Deu,
+        };
+        let json = serde_json::to_string(&codes).unwrap();
+        assert_eq!(
+            json,
+            r#"{"iso639_1":"de","iso639_2b":"ger","iso639_3":"deu","integer":2152}"#
+        );
+        assert_eq!(serde_json::from_str::<Codes>(&json).unwrap(), codes);
+    }
+
+    #[test]
+    fn test_fallbacks() {
+        let codes = Codes {
+            iso639_1: Language::Gsw,
+            iso639_2b: Language::Gsw,
+            iso639_3: Language::Gsw,
+            integer: Language::Gsw,
+        };
+        let json = serde_json::to_string(&codes).unwrap();
+        assert!(json.starts_with(r#"{"iso639_1":"gsw","iso639_2b":"gsw""#));
+        assert_eq!(serde_json::from_str::<Codes>(&json).unwrap(), codes);
+    }
+
+    #[test]
+    fn test_signed_integer() {
+        use ::serde::de::{value, IntoDeserializer};
+
+        let deserialize = |id: i64| {
+            crate::serde::integer::deserialize(id.into_deserializer())
+                .map_err(|e: value::Error| e.to_string())
+        };
+        assert_eq!(deserialize(2152), Ok(Language::Deu));
+        assert!(deserialize(-1).is_err());
+        assert!(deserialize(i64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_invalid_integer() {
+        let json = r#"{"iso639_1":"de","iso639_2b":"ger","iso639_3":"deu","integer":11232}"#;
+        let error = serde_json::from_str::<Codes>(json).unwrap_err();
+        assert!(error.to_string().contains("a stable language ID"));
+    }
+
+    #[test]
+    fn test_strict() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Strict {
+            #[serde(with = "crate::serde::iso639_1_strict")]
+            iso639_1: Language,
+            #[serde(with = "crate::serde::iso639_3_strict")]
+            iso639_3: Language,
+        }
+
+        let strict =
+            Strict { iso639_1: Language::Spa, iso639_3: Language::Spa };
+        let json = serde_json::to_string(&strict).unwrap();
+        assert_eq!(json, r#"{"iso639_1":"es","iso639_3":"spa"}"#);
+        assert_eq!(serde_json::from_str::<Strict>(&json).unwrap(), strict);
+
+        let error = |json| serde_json::from_str::<Strict>(json).unwrap_err();
+        assert_eq!(
+            error(r#"{"iso639_1":"spa","iso639_3":"spa"}"#).to_string(),
+            r#"invalid value: string "spa", expected an ISO 639-1 code at line 1 column 17"#
+        );
+        assert_eq!(
+            error(r#"{"iso639_1":"es","iso639_3":"spanish"}"#).to_string(),
+            r#"invalid value: string "spanish", expected an ISO 639-3 code at line 1 column 37"#
+        );
+        assert!(serde_json::from_str::<Strict>(
+            r#"{"iso639_1":"es","iso639_3":"es"}"#
+        )
+        .is_err());
+
+        let strict =
+            Strict { iso639_1: Language::Gsw, iso639_3: Language::Gsw };
+        assert_eq!(
+            serde_json::to_string(&strict).unwrap_err().to_string(),
+            "gsw has no ISO 639-1 code"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "english_names")]
+    fn test_name() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Named(#[serde(with = "crate::serde::name")] Language);
+
+        let json = serde_json::to_string(&Named(Language::Nld)).unwrap();
+        assert_eq!(json, r#""Dutch""#);
+        assert_eq!(
+            serde_json::from_str::<Named>(&json).unwrap().0,
+            Language::Nld
+        );
+        assert_eq!(
+            serde_json::from_str::<Named>(r#""nl""#).unwrap().0,
+            Language::Nld
+        );
+        assert!(serde_json::from_str::<Named>(r#""Klingonish""#).is_err());
+
+        // shared names are only used for the first language
+        let json = serde_json::to_string(&Named(Language::Swa)).unwrap();
+        assert_eq!(json, r#""Swahili""#);
+        let json = serde_json::to_string(&Named(Language::Swh)).unwrap();
+        assert_eq!(json, r#""swh""#);
+        for language in crate::languages() {
+            let json = serde_json::to_string(&Named(language)).unwrap();
+            assert_eq!(
+                serde_json::from_str::<Named>(&json).unwrap().0,
+                language
+            );
+        }
+    }
+}
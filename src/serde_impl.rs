@@ -1,6 +1,8 @@
-use std::str::FromStr;
+use core::str;
 
-use crate::*;
+#[cfg(feature = "alloc")]
+use crate::LanguageMap;
+use crate::{Language, LanguageCode, LanguageSet};
 
 impl serde::ser::Serialize for Language {
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
@@ -11,23 +13,24 @@ impl serde::ser::Serialize for Language {
     }
 }
 
-struct LanguageVisitor;
+/// Visitor of languages as codes or, for the integer form, as stable IDs.
+pub(crate) struct LanguageVisitor;
 
 impl<'a> serde::de::Visitor<'a> for LanguageVisitor {
     type Value = Language;
 
     fn expecting(
         &self,
-        formatter: &mut std::fmt::Formatter,
-    ) -> std::fmt::Result {
-        formatter.write_str("borrowed str or bytes")
+        formatter: &mut core::fmt::Formatter,
+    ) -> core::fmt::Result {
+        formatter.write_str("an ISO 639 language code")
     }
 
     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
     where
         E: serde::de::Error,
     {
-        Language::from_str(v).map_err(|_| {
+        Language::parse(v).ok_or_else(|| {
             serde::de::Error::unknown_variant(
                 v,
                 &["any valid ISO 639-1 or 639-3 code"],
@@ -40,13 +43,52 @@ impl<'a> serde::de::Visitor<'a> for LanguageVisitor {
         E: serde::de::Error,
     {
         match str::from_utf8(v) {
-            Ok(s) => self.visit_borrowed_str(s),
+            Ok(s) => self.visit_str(s),
             Err(_) => Err(serde::de::Error::invalid_value(
                 serde::de::Unexpected::Bytes(v),
                 &self,
             )),
         }
     }
+
+    fn visit_u16<E>(self, v: u16) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Language::from_stable_id(v).ok_or_else(|| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Unsigned(v.into()),
+                &"a stable language ID",
+            )
+        })
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match u16::try_from(v) {
+            Ok(id) => self.visit_u16(id),
+            Err(_) => Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Unsigned(v),
+                &"a stable language ID",
+            )),
+        }
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        // some formats, e.g. TOML, only have signed integers
+        match u16::try_from(v) {
+            Ok(id) => self.visit_u16(id),
+            Err(_) => Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Signed(v),
+                &"a stable language ID",
+            )),
+        }
+    }
 }
 
 impl<'de> serde::de::Deserialize<'de> for Language {
@@ -56,3 +98,151 @@ impl<'de> serde::de::Deserialize<'de> for Language {
         deserializer.deserialize_str(LanguageVisitor)
     }
 }
+
+impl serde::ser::Serialize for LanguageCode {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        s.serialize_str(self.to_639_3())
+    }
+}
+
+struct LanguageCodeVisitor;
+
+impl<'a> serde::de::Visitor<'a> for LanguageCodeVisitor {
+    type Value = LanguageCode;
+
+    fn expecting(
+        &self,
+        formatter: &mut core::fmt::Formatter,
+    ) -> core::fmt::Result {
+        formatter.write_str("a language or private-use code")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        LanguageCode::parse(v).ok_or_else(|| {
+            serde::de::Error::unknown_variant(
+                v,
+                &["any valid ISO 639-1 or 639-3 code, or qaa-qtz"],
+            )
+        })
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match str::from_utf8(v) {
+            Ok(s) => self.visit_str(s),
+            Err(_) => Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Bytes(v),
+                &self,
+            )),
+        }
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for LanguageCode {
+    fn deserialize<D: serde::de::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(LanguageCodeVisitor)
+    }
+}
+
+impl serde::ser::Serialize for LanguageSet {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        s.collect_seq(self)
+    }
+}
+
+struct LanguageSetVisitor;
+
+impl<'de> serde::de::Visitor<'de> for LanguageSetVisitor {
+    type Value = LanguageSet;
+
+    fn expecting(
+        &self,
+        formatter: &mut core::fmt::Formatter,
+    ) -> core::fmt::Result {
+        formatter.write_str("a sequence of languages")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut set = LanguageSet::new();
+        while let Some(language) = seq.next_element::<Language>()? {
+            set.insert(language);
+        }
+        Ok(set)
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for LanguageSet {
+    fn deserialize<D: serde::de::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(LanguageSetVisitor)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<V: serde::ser::Serialize> serde::ser::Serialize for LanguageMap<V> {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        s.collect_map(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+struct LanguageMapVisitor<V>(core::marker::PhantomData<V>);
+
+#[cfg(feature = "alloc")]
+impl<'de, V> serde::de::Visitor<'de> for LanguageMapVisitor<V>
+where
+    V: serde::de::Deserialize<'de>,
+{
+    type Value = LanguageMap<V>;
+
+    fn expecting(
+        &self,
+        formatter: &mut core::fmt::Formatter,
+    ) -> core::fmt::Result {
+        formatter.write_str("a map keyed by languages")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let mut languages = LanguageMap::new();
+        while let Some((language, value)) = map.next_entry()? {
+            languages.insert(language, value);
+        }
+        Ok(languages)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, V> serde::de::Deserialize<'de> for LanguageMap<V>
+where
+    V: serde::de::Deserialize<'de>,
+{
+    fn deserialize<D: serde::de::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_map(LanguageMapVisitor(core::marker::PhantomData))
+    }
+}
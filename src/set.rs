@@ -0,0 +1,385 @@
+//! A compact set of languages
+
+use core::{
+    fmt::{self, Debug, Display, Formatter},
+    iter::FusedIterator,
+    ops::{
+        BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Sub,
+        SubAssign,
+    },
+};
+
+use crate::isotable::LANGUAGE_COUNT;
+use crate::Language;
+
+const WORDS: usize = LANGUAGE_COUNT.div_ceil(64);
+
+/// Combine the words of two sets in a constant context, where closures can't be called.
+macro_rules! combine {
+    ($lhs:expr, $rhs:expr, |$a:ident, $b:ident| $op:expr) => {{
+        let mut words = [0; WORDS];
+        let mut idx = 0;
+        while idx < WORDS {
+            let ($a, $b) = ($lhs.words[idx], $rhs.words[idx]);
+            words[idx] = $op;
+            idx += 1;
+        }
+        LanguageSet { words }
+    }};
+}
+
+/// A set of languages, stored as a bitset with one bit per language.
+///
+/// Since the variants of [`Language`](enum.Language.html) are dense, the set has a fixed size of
+/// about 1 KiB, no matter how many languages it contains, and never allocates. Sets can be
+/// constructed in constant expressions and are iterated in the order of the `Language` enum.
+///
+/// # Examples
+///
+/// ```
+/// use isolang::{Language, LanguageSet};
+///
+/// const SPOKEN: LanguageSet =
+///     LanguageSet::from_languages([Language::Deu, Language::Eng]);
+/// let available: LanguageSet =
+///     [Language::Eng, Language::Fra].into_iter().collect();
+///
+/// let common = SPOKEN & available;
+/// assert_eq!(common.iter().collect::<Vec<_>>(), [Language::Eng]);
+/// assert_eq!((SPOKEN | available).len(), 3);
+/// ```
+#[derive(Clone, Copy, Hash, Eq, PartialEq)]
+pub struct LanguageSet {
+    words: [u64; WORDS],
+}
+
+impl LanguageSet {
+    /// Create an empty set.
+    pub const fn new() -> Self {
+        LanguageSet { words: [0; WORDS] }
+    }
+
+    /// Create a set containing all languages.
+    pub const fn all() -> Self {
+        let mut words = [u64::MAX; WORDS];
+        words[WORDS - 1] = u64::MAX >> (WORDS * 64 - LANGUAGE_COUNT);
+        LanguageSet { words }
+    }
+
+    /// Create a set from the given languages.
+    pub const fn from_languages<const N: usize>(
+        languages: [Language; N],
+    ) -> Self {
+        let mut set = LanguageSet::new();
+        let mut idx = 0;
+        while idx < N {
+            set = set.with(languages[idx]);
+            idx += 1;
+        }
+        set
+    }
+
+    /// Return a copy of this set with the given language added.
+    pub const fn with(mut self, language: Language) -> Self {
+        let (word, bit) = position(language);
+        self.words[word] |= bit;
+        self
+    }
+
+    /// Return a copy of this set with the given language removed.
+    pub const fn without(mut self, language: Language) -> Self {
+        let (word, bit) = position(language);
+        self.words[word] &= !bit;
+        self
+    }
+
+    /// Check whether the given language is contained in this set.
+    pub const fn contains(&self, language: Language) -> bool {
+        let (word, bit) = position(language);
+        self.words[word] & bit != 0
+    }
+
+    /// Add a language to this set.
+    ///
+    /// Returns whether the language was newly inserted.
+    pub fn insert(&mut self, language: Language) -> bool {
+        let inserted = !self.contains(language);
+        *self = self.with(language);
+        inserted
+    }
+
+    /// Remove a language from this set.
+    ///
+    /// Returns whether the language was contained in the set.
+    pub fn remove(&mut self, language: Language) -> bool {
+        let removed = self.contains(language);
+        *self = self.without(language);
+        removed
+    }
+
+    /// Remove all languages from this set.
+    pub fn clear(&mut self) {
+        *self = LanguageSet::new();
+    }
+
+    /// Get the number of languages in this set.
+    pub const fn len(&self) -> usize {
+        let mut len = 0;
+        let mut idx = 0;
+        while idx < WORDS {
+            len += self.words[idx].count_ones() as usize;
+            idx += 1;
+        }
+        len
+    }
+
+    /// Check whether this set contains no languages.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the languages contained in this or the other set.
+    pub const fn union(&self, other: &LanguageSet) -> Self {
+        combine!(self, other, |a, b| a | b)
+    }
+
+    /// Get the languages contained in both this and the other set.
+    pub const fn intersection(&self, other: &LanguageSet) -> Self {
+        combine!(self, other, |a, b| a & b)
+    }
+
+    /// Get the languages contained in this set, but not in the other one.
+    pub const fn difference(&self, other: &LanguageSet) -> Self {
+        combine!(self, other, |a, b| a & !b)
+    }
+
+    /// Get the languages contained in either this or the other set, but not in both.
+    pub const fn symmetric_difference(&self, other: &LanguageSet) -> Self {
+        combine!(self, other, |a, b| a ^ b)
+    }
+
+    /// Check whether all languages of this set are contained in the other one.
+    pub const fn is_subset(&self, other: &LanguageSet) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    /// Check whether all languages of the other set are contained in this one.
+    pub const fn is_superset(&self, other: &LanguageSet) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Check whether this and the other set have no languages in common.
+    pub const fn is_disjoint(&self, other: &LanguageSet) -> bool {
+        self.intersection(other).is_empty()
+    }
+
+    /// Iterate over the languages of this set in the order of the `Language` enum.
+    pub fn iter(&self) -> Iter {
+        Iter { words: self.words, word: 0 }
+    }
+}
+
+const fn position(language: Language) -> (usize, u64) {
+    let idx = language as usize;
+    (idx / 64, 1 << (idx % 64))
+}
+
+impl Default for LanguageSet {
+    fn default() -> Self {
+        LanguageSet::new()
+    }
+}
+
+/// Iterator over the languages of a [`LanguageSet`].
+#[derive(Clone)]
+pub struct Iter {
+    words: [u64; WORDS],
+    word: usize,
+}
+
+impl Iterator for Iter {
+    type Item = Language;
+
+    fn next(&mut self) -> Option<Language> {
+        while self.word < WORDS {
+            let bits = self.words[self.word];
+            if bits != 0 {
+                // clear the lowest set bit
+                self.words[self.word] = bits & (bits - 1);
+                let idx = self.word * 64 + bits.trailing_zeros() as usize;
+                return Language::from_usize(idx);
+            }
+            self.word += 1;
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.words[self.word.min(WORDS)..]
+            .iter()
+            .map(|w| w.count_ones() as usize)
+            .sum();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Iter {}
+
+impl FusedIterator for Iter {}
+
+impl IntoIterator for LanguageSet {
+    type Item = Language;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Iter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &LanguageSet {
+    type Item = Language;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Iter {
+        self.iter()
+    }
+}
+
+impl FromIterator<Language> for LanguageSet {
+    fn from_iter<I: IntoIterator<Item = Language>>(iter: I) -> Self {
+        let mut set = LanguageSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Language> for LanguageSet {
+    fn extend<I: IntoIterator<Item = Language>>(&mut self, iter: I) {
+        for language in iter {
+            self.insert(language);
+        }
+    }
+}
+
+impl<const N: usize> From<[Language; N]> for LanguageSet {
+    fn from(languages: [Language; N]) -> Self {
+        LanguageSet::from_languages(languages)
+    }
+}
+
+macro_rules! impl_set_op {
+    ($op:ident, $fn:ident, $assign_op:ident, $assign_fn:ident, $method:ident) => {
+        impl $op for LanguageSet {
+            type Output = LanguageSet;
+
+            fn $fn(self, rhs: LanguageSet) -> LanguageSet {
+                self.$method(&rhs)
+            }
+        }
+
+        impl $assign_op for LanguageSet {
+            fn $assign_fn(&mut self, rhs: LanguageSet) {
+                *self = self.$method(&rhs);
+            }
+        }
+    };
+}
+
+impl_set_op!(BitOr, bitor, BitOrAssign, bitor_assign, union);
+impl_set_op!(BitAnd, bitand, BitAndAssign, bitand_assign, intersection);
+impl_set_op!(Sub, sub, SubAssign, sub_assign, difference);
+impl_set_op!(BitXor, bitxor, BitXorAssign, bitxor_assign, symmetric_difference);
+
+impl Debug for LanguageSet {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Formats the ISO 639-3 codes of the languages, separated by commas, e.g. `deu, fra`.
+impl Display for LanguageSet {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for (idx, language) in self.iter().enumerate() {
+            if idx > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(language.to_639_3())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_remove() {
+        let mut set = LanguageSet::new();
+        assert!(set.is_empty());
+        assert!(set.insert(Language::Zul));
+        assert!(!set.insert(Language::Zul));
+        assert!(set.insert(Language::Afr));
+        assert!(set.contains(Language::Zul));
+        assert_eq!(set.len(), 2);
+        assert!(set.remove(Language::Zul));
+        assert!(!set.remove(Language::Zul));
+        assert_eq!(set.iter().collect::<Vec<_>>(), [Language::Afr]);
+        set.clear();
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        const A: LanguageSet = LanguageSet::from_languages([
+            Language::Deu,
+            Language::Eng,
+            Language::Fra,
+        ]);
+        const B: LanguageSet =
+            LanguageSet::new().with(Language::Fra).with(Language::Spa);
+        assert_eq!(
+            (A | B).iter().collect::<Vec<_>>(),
+            [Language::Deu, Language::Eng, Language::Fra, Language::Spa]
+        );
+        assert_eq!((A & B).iter().collect::<Vec<_>>(), [Language::Fra]);
+        assert_eq!(
+            (A - B).iter().collect::<Vec<_>>(),
+            [Language::Deu, Language::Eng]
+        );
+        assert_eq!((A ^ B).len(), 3);
+        assert!((A & B).is_subset(&A));
+        assert!(A.is_superset(&(A & B)));
+        assert!(A.is_disjoint(&LanguageSet::from([Language::Spa])));
+    }
+
+    #[test]
+    fn test_all() {
+        let all = LanguageSet::all();
+        assert_eq!(all.len(), LANGUAGE_COUNT);
+        assert_eq!(all.iter().len(), LANGUAGE_COUNT);
+        assert!(all.iter().eq(crate::languages()));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let set = LanguageSet::from([Language::Fra, Language::Deu]);
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(json, r#"["deu","fra"]"#);
+        assert_eq!(serde_json::from_str::<LanguageSet>(&json).unwrap(), set);
+        assert_eq!(
+            serde_json::from_str::<LanguageSet>(r#"["fr","deu","fra"]"#)
+                .unwrap(),
+            set
+        );
+    }
+
+    #[test]
+    fn test_fmt() {
+        let set = LanguageSet::from([Language::Fra, Language::Deu]);
+        assert_eq!(set.to_string(), "deu, fra");
+        assert_eq!(format!("{:?}", set), "{deu, fra}");
+        assert_eq!(LanguageSet::new().to_string(), "");
+    }
+}
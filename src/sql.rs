@@ -0,0 +1,92 @@
+//! Conversions shared by the database integrations
+//!
+//! Languages are stored as ISO 639-3 code in text columns, e.g. `char(3)`, and wrapped in
+//! [`LanguageId`] as stable ID in integer columns, e.g. `smallint`.
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use crate::Language;
+
+/// A [`Language`] stored as its [stable ID](enum.Language.html#method.to_stable_id) in integer
+/// columns of databases.
+///
+/// [`Language`] itself is stored as ISO 639-3 code in text columns. Available with the database
+/// features, e.g. `sqlx`, `postgres-types`, `diesel` or `rusqlite`.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::SmallInt)
+)]
+pub struct LanguageId(pub Language);
+
+impl From<Language> for LanguageId {
+    fn from(language: Language) -> Self {
+        LanguageId(language)
+    }
+}
+
+impl From<LanguageId> for Language {
+    fn from(id: LanguageId) -> Self {
+        id.0
+    }
+}
+
+/// Error of decoding a language from a database value
+#[derive(Debug)]
+pub(crate) enum DecodeError {
+    /// The text is not an ISO 639-3 code
+    UnknownCode(String),
+    /// The integer is not a stable ID of a language
+    UnknownId(i64),
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            DecodeError::UnknownCode(code) => {
+                write!(f, "'{}' is not a valid ISO 639-3 code", code)
+            }
+            DecodeError::UnknownId(id) => {
+                write!(f, "{} is not a valid stable language ID", id)
+            }
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+/// Decode a language from its ISO 639-3 code, ignoring the padding of `char(n)` columns.
+pub(crate) fn language_from_text(text: &str) -> Result<Language, DecodeError> {
+    let code = text.trim_end_matches(' ');
+    Language::from_639_3(code)
+        .ok_or_else(|| DecodeError::UnknownCode(code.to_owned()))
+}
+
+/// Decode a language from its stable ID.
+pub(crate) fn language_from_id(id: i64) -> Result<LanguageId, DecodeError> {
+    u16::try_from(id)
+        .ok()
+        .and_then(Language::from_stable_id)
+        .map(LanguageId)
+        .ok_or(DecodeError::UnknownId(id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(language_from_text("deu").unwrap(), Language::Deu);
+        assert_eq!(language_from_text("deu  ").unwrap(), Language::Deu);
+        assert_eq!(
+            language_from_text("de").unwrap_err().to_string(),
+            "'de' is not a valid ISO 639-3 code"
+        );
+        assert_eq!(language_from_id(2152).unwrap(), LanguageId(Language::Deu));
+        assert!(language_from_id(-1).is_err());
+        assert!(language_from_id(17576).is_err());
+    }
+}
@@ -0,0 +1,109 @@
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::postgres::{
+    PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueRef, Postgres,
+};
+use sqlx::{Decode, Encode, Type};
+
+use crate::sql::{language_from_id, language_from_text, LanguageId};
+use crate::Language;
+
+impl Type<Postgres> for Language {
+    fn type_info() -> PgTypeInfo {
+        <&str as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <&str as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl PgHasArrayType for Language {
+    fn array_type_info() -> PgTypeInfo {
+        <&str as PgHasArrayType>::array_type_info()
+    }
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        <&str as PgHasArrayType>::array_compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for Language {
+    fn encode_by_ref(
+        &self,
+        buf: &mut PgArgumentBuffer,
+    ) -> Result<IsNull, BoxDynError> {
+        <&str as Encode<Postgres>>::encode(self.to_639_3(), buf)
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for Language {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        let text = <&str as Decode<Postgres>>::decode(value)?;
+        Ok(language_from_text(text)?)
+    }
+}
+
+impl Type<Postgres> for LanguageId {
+    fn type_info() -> PgTypeInfo {
+        <i16 as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <i16 as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl PgHasArrayType for LanguageId {
+    fn array_type_info() -> PgTypeInfo {
+        <i16 as PgHasArrayType>::array_type_info()
+    }
+}
+
+impl Encode<'_, Postgres> for LanguageId {
+    fn encode_by_ref(
+        &self,
+        buf: &mut PgArgumentBuffer,
+    ) -> Result<IsNull, BoxDynError> {
+        // stable IDs are below 26³, hence fit into an `i16`
+        <i16 as Encode<Postgres>>::encode(self.0.to_stable_id() as i16, buf)
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for LanguageId {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        let id = <i16 as Decode<Postgres>>::decode(value)?;
+        Ok(language_from_id(id.into())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode<T: for<'q> Encode<'q, Postgres>>(value: T) -> Vec<u8> {
+        let mut buf = PgArgumentBuffer::default();
+        assert!(matches!(value.encode_by_ref(&mut buf), Ok(IsNull::No)));
+        buf.to_vec()
+    }
+
+    #[test]
+    fn test_types() {
+        // `char(n)` columns have the type `bpchar`, the type `"char"` is a single byte
+        assert!(Language::compatible(&PgTypeInfo::with_name("bpchar")));
+        assert!(Language::compatible(&PgTypeInfo::with_name("varchar")));
+        assert!(!Language::compatible(&PgTypeInfo::with_name("char")));
+        assert!(!Language::compatible(&PgTypeInfo::with_name("int2")));
+        assert!(LanguageId::compatible(&PgTypeInfo::with_name("int2")));
+        assert!(!LanguageId::compatible(&PgTypeInfo::with_name("char")));
+        assert!(!LanguageId::compatible(&PgTypeInfo::with_name("bpchar")));
+        assert!(!LanguageId::compatible(&PgTypeInfo::with_name("text")));
+    }
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(encode(Language::Deu), b"deu");
+        assert_eq!(encode(LanguageId(Language::Deu)), 2152i16.to_be_bytes());
+        assert_eq!(encode(LanguageId(Language::Zzj)), 17559i16.to_be_bytes());
+    }
+}
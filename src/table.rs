@@ -0,0 +1,72 @@
+//! Parsing of the `iso-639-3.tab` and `iso639-autonyms.tsv` tables
+//!
+//! This module is shared by the runtime [`LanguageDb`](crate::db::LanguageDb) and the code
+//! generator in `xtask/src/main.rs`, which includes it by path.
+
+use std::collections::HashMap;
+
+/// Language data as extracted from `iso-639-3.tab` and `iso639-autonyms.tsv`.
+pub(crate) struct LangCode<'a> {
+    pub(crate) code_3: &'a str,
+    pub(crate) code_2b: Option<&'a str>,
+    pub(crate) code_1: Option<&'a str>,
+    pub(crate) name_en: &'a str,
+    pub(crate) autonym: Option<&'a str>,
+}
+
+/// A line of a table which lacks columns, with its 1-based line number.
+#[derive(Debug)]
+pub(crate) struct MalformedLine(pub(crate) usize);
+
+/// Parse the autonyms table into a map from 639-3 codes to autonyms.
+pub(crate) fn read_autonyms_table(
+    table: &str,
+) -> Result<HashMap<&str, Option<&str>>, MalformedLine> {
+    table
+        .lines()
+        .enumerate()
+        .skip(1)
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(idx, line)| {
+            let mut cols = line.split('\t');
+            let three_letter = cols.next().ok_or(MalformedLine(idx + 1))?;
+            Ok((three_letter, cols.nth(2).filter(|s| !s.is_empty())))
+        })
+        .collect()
+}
+
+/// Parse ISO 639-3 table, together with the autonyms table.
+pub(crate) fn read_iso_table<'a>(
+    iso_table: &'a str,
+    autonyms_table: &'a str,
+) -> Result<Vec<LangCode<'a>>, MalformedLine> {
+    let autonyms_table = read_autonyms_table(autonyms_table)?;
+    iso_table
+        .lines()
+        .enumerate()
+        .skip(1)
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(idx, line)| {
+            let malformed = || MalformedLine(idx + 1);
+            let mut cols = line.split('\t');
+            let code_3 =
+                cols.next().filter(|s| s.len() == 3).ok_or_else(malformed)?;
+            let code_2b = match cols.next().ok_or_else(malformed)? {
+                "" => None,
+                s => Some(s),
+            };
+            // ISO 639-2t codes are equal to the 639-3 codes
+            let _code_2t = cols.next().ok_or_else(malformed)?;
+            let code_1 = cols.next().filter(|s| s.len() == 2);
+            let autonym = match autonyms_table.get(code_3) {
+                Some(Some(t)) => Some(*t),
+                _ => None,
+            };
+
+            // split language string into name and comment, if required
+            let name = cols.nth(2).ok_or_else(malformed)?;
+            let name_en = name.split('(').next().unwrap_or_default().trim_end();
+            Ok(LangCode { code_3, code_2b, code_1, name_en, autonym })
+        })
+        .collect()
+}
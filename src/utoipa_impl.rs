@@ -0,0 +1,163 @@
+#[cfg(feature = "english_names")]
+use utoipa::openapi::schema::OneOfBuilder;
+use utoipa::openapi::schema::{ObjectBuilder, Schema, Type};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+use crate::Language;
+
+/// Languages are described as string of all ISO 639-3 codes, which is how they are serialized with
+/// the `serde` feature. With `english_names`, every code is a single-value alternative with the
+/// English name as description, like utoipa derives it for documented unit variants. The modules
+/// of [`isolang::serde`](crate::serde) provide the schemas of the other representations.
+impl PartialSchema for Language {
+    fn schema() -> RefOr<Schema> {
+        string_schema("ISO 639-3 language code", |language| {
+            Some(language.to_639_3())
+        })
+    }
+}
+
+impl ToSchema for Language {}
+
+/// Describe the languages as the strings returned by `value`, leaving out languages without one.
+#[cfg(feature = "english_names")]
+pub(crate) fn string_schema(
+    description: &str,
+    value: impl Fn(Language) -> Option<&'static str>,
+) -> RefOr<Schema> {
+    crate::all_languages()
+        .filter_map(|language| Some((language, value(language)?)))
+        .fold(
+            OneOfBuilder::new().description(Some(description)),
+            |values, (language, value)| {
+                values.item(
+                    ObjectBuilder::new()
+                        .schema_type(Type::String)
+                        .enum_values(Some([value]))
+                        .description(Some(language.to_name())),
+                )
+            },
+        )
+        .into()
+}
+
+/// Describe the languages as the strings returned by `value`, leaving out languages without one.
+#[cfg(not(feature = "english_names"))]
+pub(crate) fn string_schema(
+    description: &str,
+    value: impl Fn(Language) -> Option<&'static str>,
+) -> RefOr<Schema> {
+    ObjectBuilder::new()
+        .schema_type(Type::String)
+        .description(Some(description))
+        .enum_values(Some(crate::all_languages().filter_map(value)))
+        .into()
+}
+
+/// Describe the languages as their stable IDs.
+#[cfg(all(feature = "serde", feature = "english_names"))]
+pub(crate) fn integer_schema() -> RefOr<Schema> {
+    crate::all_languages()
+        .fold(
+            OneOfBuilder::new().description(Some("stable language ID")),
+            |values, language| {
+                values.item(
+                    ObjectBuilder::new()
+                        .schema_type(Type::Integer)
+                        .enum_values(Some([language.to_stable_id()]))
+                        .description(Some(language.to_name())),
+                )
+            },
+        )
+        .into()
+}
+
+/// Describe the languages as their stable IDs.
+#[cfg(all(feature = "serde", not(feature = "english_names")))]
+pub(crate) fn integer_schema() -> RefOr<Schema> {
+    ObjectBuilder::new()
+        .schema_type(Type::Integer)
+        .description(Some("stable language ID"))
+        .enum_values(Some(
+            crate::all_languages().map(|language| language.to_stable_id()),
+        ))
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema() {
+        assert_eq!(Language::name(), "Language");
+        let schema = serde_json::to_value(Language::schema()).unwrap();
+
+        #[cfg(feature = "english_names")]
+        let codes = schema["oneOf"].as_array().unwrap();
+        #[cfg(feature = "english_names")]
+        assert!(codes.iter().any(|code| code["type"] == "string"
+            && code["enum"] == serde_json::json!(["deu"])
+            && code["description"] == "German"));
+        #[cfg(not(feature = "english_names"))]
+        let codes = schema["enum"].as_array().unwrap();
+        #[cfg(not(feature = "english_names"))]
+        assert!(codes.iter().any(|code| code == "deu"));
+        assert_eq!(codes.len(), crate::languages().count());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_schemas() {
+        use serde_json::{value::Serializer, Value};
+
+        use crate::serde::*;
+
+        type Serialize = fn(&Language, Serializer) -> serde_json::Result<Value>;
+
+        // the schema lists exactly the serialized values
+        fn check(
+            schema: fn() -> RefOr<Schema>,
+            instance_type: &str,
+            serialize: Serialize,
+        ) {
+            let schema = serde_json::to_value(schema()).unwrap();
+            #[cfg(feature = "english_names")]
+            let values: Vec<Value> = schema["oneOf"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|value| {
+                    assert_eq!(value["type"], instance_type);
+                    let [value] = value["enum"].as_array().unwrap().as_slice()
+                    else {
+                        panic!("no single value in {}", value);
+                    };
+                    value.clone()
+                })
+                .collect();
+            #[cfg(not(feature = "english_names"))]
+            let values = {
+                assert_eq!(schema["type"], instance_type);
+                schema["enum"].as_array().unwrap().clone()
+            };
+            let serialized: Vec<Value> = crate::languages()
+                .filter_map(|language| serialize(&language, Serializer).ok())
+                .collect();
+            assert_eq!(values, serialized);
+        }
+
+        check(iso639_1::schema, "string", iso639_1::serialize);
+        check(iso639_2b::schema, "string", iso639_2b::serialize);
+        check(iso639_3::schema, "string", iso639_3::serialize);
+        check(iso639_1_strict::schema, "string", iso639_1_strict::serialize);
+        check(iso639_3_strict::schema, "string", iso639_3_strict::serialize);
+        #[cfg(feature = "english_names")]
+        check(name::schema, "string", name::serialize);
+        check(integer::schema, "integer", integer::serialize);
+        check(Language::schema, "string", |language, s| {
+            ::serde::Serialize::serialize(language, s)
+        });
+    }
+}
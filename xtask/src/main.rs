@@ -0,0 +1,864 @@
+//! Generator of `src/isotable*.rs` and `proto/isolang.proto` from the data files in the crate root
+//!
+//! Run `cargo xtask regenerate` after updating the data files. With `--check`, the generated
+//! code is only compared to the existing files, e.g. on CI. `--data-dir` and `--output-dir` allow
+//! to generate the tables from other data files, e.g. for a fork with additional languages.
+//!
+//! Besides the full table, one file is generated for each subset of languages, see [`SUBSETS`].
+//! The Protocol Buffers definition always contains all languages.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+use std::{env, fs};
+
+// the table parser is shared with the runtime `LanguageDb`
+#[allow(dead_code)]
+#[path = "../../src/table.rs"]
+mod table;
+use table::{read_iso_table, LangCode};
+
+// Taken from http://www-01.sil.org/iso639-3/download.asp
+static ISO_TABLE_PATH: &str = "iso-639-3.tab";
+
+// Local names of languages from https://github.com/bbqsrc/iso639-autonyms
+static AUTONYMS_TABLE_PATH: &str = "iso639-autonyms.tsv";
+
+// Names of languages in other languages, extracted from `languages.json` of the
+// Unicode CLDR (https://github.com/unicode-org/cldr-json)
+static DISPLAY_NAMES_TABLE_PATH: &str = "cldr-display-names.tsv";
+
+// Individual languages with their macrolanguage, extracted from the language
+// aliases of the Unicode CLDR which have been replaced by their macrolanguage
+static MACROLANGUAGES_TABLE_PATH: &str = "cldr-macrolanguages.tsv";
+
+// Parent locales from `parentLocales.json` of the Unicode CLDR
+static PARENT_LOCALES_TABLE_PATH: &str = "cldr-parent-locales.tsv";
+
+// Distances of closely related languages, extracted from the `languageMatching`
+// data (`written_new`) in `languageInfo.xml` of the Unicode CLDR, without the
+// entries for scripts, regions and wildcards
+static LANGUAGE_MATCHING_TABLE_PATH: &str = "cldr-language-matching.tsv";
+
+const USAGE: &str = "\
+Usage: cargo xtask regenerate [--check] [--data-dir <dir>] [--output-dir <dir>]
+                               [--proto-dir <dir>]
+
+Options:
+    --check              Fail if the generated code differs from the output files,
+                         instead of writing them
+    --data-dir <dir>     Directory with the data files [default: crate root]
+    --output-dir <dir>   Directory of the generated files [default: src]
+    --proto-dir <dir>    Directory of the generated `.proto` file [default: proto]";
+
+// Name of the generated Protocol Buffers definition
+static PROTO_PATH: &str = "isolang.proto";
+
+/// A subset of the languages, selected at compile time by the `isolang_subset` cfg.
+struct Subset {
+    /// Name of the generated file
+    file: &'static str,
+    /// Only languages with a 639-1 code
+    only_639_1: bool,
+    /// Only living languages
+    only_living: bool,
+}
+
+/// All generated subsets, including the full table. Special codes like `und`
+/// are part of every subset, since the crate relies on them.
+const SUBSETS: [Subset; 4] = [
+    Subset {
+        file: "isotable.rs",
+        only_639_1: false,
+        only_living: false,
+    },
+    Subset {
+        file: "isotable_639_1.rs",
+        only_639_1: true,
+        only_living: false,
+    },
+    Subset {
+        file: "isotable_living.rs",
+        only_639_1: false,
+        only_living: true,
+    },
+    Subset {
+        file: "isotable_639_1_living.rs",
+        only_639_1: true,
+        only_living: true,
+    },
+];
+
+fn format_code(code: &str) -> String {
+    // run from the crate, so that its `.rustfmt.toml` is picked up
+    let child = Command::new("rustfmt")
+        .arg("--edition=2021")
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Unable to format code, install rustfmt");
+    {
+        let mut childstdin = child.stdin.as_ref().unwrap();
+        let mut writer = BufWriter::new(&mut childstdin);
+        writer.write_all(code.as_bytes()).unwrap();
+    }
+    let output = child.wait_with_output().unwrap();
+    if !output.status.success() {
+        panic!("Unable to execute rustfmt");
+    }
+
+    String::from_utf8(output.stdout)
+        .expect("Could not parse the generated source as UTF-8")
+}
+
+struct Title<'a>(&'a str);
+
+impl<'a> std::fmt::Display for Title<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut iter = self.0.chars();
+        if let Some(c) = iter.next() {
+            f.write_fmt(format_args!("{}", c.to_uppercase()))?;
+        }
+        f.write_str(iter.as_str())
+    }
+}
+
+/// Write static array with (639-3, 639-1) entries, followed by the names.
+fn write_overview_table(out: &mut String, codes: &[LangCode]) {
+    writeln!(
+        out,
+        "/// Number of languages, i.e. variants of the `Language` enum\npub(crate) const LANGUAGE_COUNT: usize = {};\n",
+        codes.len()
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "#[allow(clippy::type_complexity)]\npub(crate) static OVERVIEW: [LanguageData; LANGUAGE_COUNT] = ["
+    )
+    .unwrap();
+
+    for language in codes {
+        writeln!(
+            out,
+            r#"    LanguageData {{
+        code_3: {:?},
+        code_1: {:?},
+    }},"#,
+            language.code_3.as_bytes(),
+            language.code_1.as_ref().map(|s| s.as_bytes()),
+        )
+        .unwrap();
+    }
+
+    writeln!(out, "];\n").unwrap();
+
+    write_names_blob(
+        out,
+        "english_names",
+        "NAMES_EN",
+        "u32",
+        "LANGUAGE_COUNT + 1",
+        codes.iter().map(|lang| lang.name_en),
+    );
+    write_names_blob(
+        out,
+        "local_names",
+        "AUTONYMS",
+        "u16",
+        "LANGUAGE_COUNT + 1",
+        codes.iter().map(|lang| lang.autonym.unwrap_or_default()),
+    );
+}
+
+/// Write names as a single concatenated string, together with the offsets of
+/// each name in it. This avoids a `&str` (two words) per language, plus the
+/// padding of the overview table. The name of language `i` ranges from
+/// `OFFSETS[i]` to `OFFSETS[i + 1]`, an empty name means there is none.
+/// `offset_count` is the length of the offsets array, one more than the number
+/// of names.
+fn write_names_blob<'a>(
+    out: &mut String,
+    feature: &str,
+    name: &str,
+    offset_type: &str,
+    offset_count: &str,
+    names: impl Iterator<Item = &'a str>,
+) {
+    let mut offsets = vec![0];
+    writeln!(
+        out,
+        "#[cfg(feature = \"{feature}\")]\npub(crate) static {name}: &str = concat!("
+    )
+    .unwrap();
+    for value in names {
+        if !value.is_empty() {
+            writeln!(out, "    {:?},", value).unwrap();
+        }
+        offsets.push(offsets.last().unwrap() + value.len());
+    }
+    writeln!(out, ");\n").unwrap();
+
+    let max = match offset_type {
+        "u16" => u16::MAX as usize,
+        _ => u32::MAX as usize,
+    };
+    assert!(
+        *offsets.last().unwrap() <= max,
+        "{name} exceeds the range of {offset_type} offsets"
+    );
+    writeln!(
+        out,
+        "#[cfg(feature = \"{feature}\")]\npub(crate) static {name}_OFFSETS: [{offset_type}; {offset_count}] = {offsets:?};\n"
+    )
+    .unwrap();
+}
+
+/// Parse the CLDR display names table into (display language, 639-3 code,
+/// name) triples, grouped by display language.
+fn read_display_names_table(table: &str) -> Vec<(&str, Vec<(&str, &str)>)> {
+    let mut locales: Vec<(&str, Vec<(&str, &str)>)> = Vec::new();
+    for line in table.lines().skip(1) {
+        let mut cols = line.split('\t');
+        let locale = cols.next().unwrap();
+        let code_3 = cols.next().unwrap();
+        let name = cols.next().unwrap();
+        match locales.last_mut() {
+            Some((last, names)) if *last == locale => {
+                names.push((code_3, name))
+            }
+            _ => locales.push((locale, vec![(code_3, name)])),
+        }
+    }
+    locales
+}
+
+/// Write the display names of each display language as the sorted languages
+/// with a name and a blob of their names, gated behind its own
+/// `display_names_<639-1 code>` feature.
+fn write_display_names_tables(
+    out: &mut String,
+    codes: &[LangCode],
+    display_names: &[(&str, Vec<(&str, &str)>)],
+) {
+    let index_of = |code_3: &str| {
+        codes.iter().position(|lang| lang.code_3 == code_3).unwrap_or_else(
+            || panic!("unknown language {code_3} in display names table"),
+        )
+    };
+    let code_3_of = |code_1: &str| {
+        codes
+            .iter()
+            .find(|lang| lang.code_1 == Some(code_1))
+            .unwrap_or_else(|| panic!("unknown display language {code_1}"))
+            .code_3
+    };
+
+    for (locale, names) in display_names {
+        let mut names = names.clone();
+        // sorted by enum discriminant to allow binary searches
+        names.sort_by_key(|(code_3, _)| index_of(code_3));
+        let feature = format!("display_names_{locale}");
+        let name =
+            format!("DISPLAY_NAMES_{}", code_3_of(locale).to_uppercase());
+        writeln!(
+            out,
+            "#[cfg(feature = \"{feature}\")]\nstatic {name}_LANGUAGES: [u16; {}] = [",
+            names.len()
+        )
+        .unwrap();
+        for (code_3, _) in &names {
+            writeln!(out, "    Language::{} as u16,", Title(code_3)).unwrap();
+        }
+        writeln!(out, "];\n").unwrap();
+        write_names_blob(
+            out,
+            &feature,
+            &name,
+            "u16",
+            &(names.len() + 1).to_string(),
+            names.iter().map(|(_, name)| *name),
+        );
+    }
+
+    writeln!(
+        out,
+        "#[cfg(feature = \"display_names\")]\npub(crate) fn display_names(lang: Language) -> (&'static [u16], &'static [u16], &'static str) {{"
+    )
+    .unwrap();
+    writeln!(out, "    match lang {{").unwrap();
+    for (locale, _) in display_names {
+        let code_3 = code_3_of(locale);
+        writeln!(
+            out,
+            "        #[cfg(feature = \"display_names_{}\")]\n        Language::{} => (&DISPLAY_NAMES_{2}_LANGUAGES, &DISPLAY_NAMES_{2}_OFFSETS, DISPLAY_NAMES_{2}),",
+            locale,
+            Title(code_3),
+            code_3.to_uppercase()
+        )
+        .unwrap();
+    }
+    writeln!(out, "        _ => (&[], &[0], \"\"),").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+}
+
+/// Parse a table with two columns and a header line into pairs.
+fn read_pairs_table(table: &str) -> Vec<(&str, &str)> {
+    table
+        .lines()
+        .skip(1)
+        .map(|line| {
+            let mut cols = line.split('\t');
+            (cols.next().unwrap(), cols.next().unwrap())
+        })
+        .collect()
+}
+
+/// Write a function mapping languages to their scope (column `Scope`), with
+/// individual languages as the default.
+fn write_scopes(
+    out: &mut String,
+    codes: &[LangCode],
+    scopes: &HashMap<&str, &str>,
+) {
+    writeln!(out, "pub(crate) const fn scope(lang: Language) -> Scope {{")
+        .unwrap();
+    writeln!(out, "    match lang {{").unwrap();
+    for lang in codes {
+        let scope = match scopes[lang.code_3] {
+            "I" => continue,
+            "M" => "Macrolanguage",
+            "S" => "Special",
+            other => panic!("unknown scope {other} of {}", lang.code_3),
+        };
+        writeln!(
+            out,
+            "        Language::{} => Scope::{},",
+            Title(lang.code_3),
+            scope
+        )
+        .unwrap();
+    }
+    writeln!(out, "        _ => Scope::Individual,").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}\n").unwrap();
+}
+
+/// Write a function telling whether the English name of a language is shared
+/// with a preceding language, which is found instead when looking up the name.
+fn write_shared_names(out: &mut String, codes: &[LangCode]) {
+    let mut names = HashSet::new();
+    let shared: Vec<_> = codes
+        .iter()
+        .filter(|lang| !names.insert(lang.name_en))
+        .map(|lang| format!("Language::{}", Title(lang.code_3)))
+        .collect();
+    writeln!(
+        out,
+        "#[cfg(all(feature = \"english_names\", feature = \"serde\"))]\npub(crate) const fn has_shared_name(lang: Language) -> bool {{"
+    )
+    .unwrap();
+    if shared.is_empty() {
+        writeln!(out, "    let _ = lang;\n    false").unwrap();
+    } else {
+        writeln!(out, "    matches!(lang, {})", shared.join(" | ")).unwrap();
+    }
+    writeln!(out, "}}\n").unwrap();
+}
+
+/// Write a function mapping individual languages to their macrolanguage.
+fn write_macrolanguages(out: &mut String, macrolanguages: &[(&str, &str)]) {
+    writeln!(
+        out,
+        "pub(crate) fn macrolanguage(lang: Language) -> Option<Language> {{"
+    )
+    .unwrap();
+    writeln!(out, "    match lang {{").unwrap();
+    for (code_3, macro_code_3) in macrolanguages {
+        writeln!(
+            out,
+            "        Language::{} => Some(Language::{}),",
+            Title(code_3),
+            Title(macro_code_3)
+        )
+        .unwrap();
+    }
+    writeln!(out, "        _ => None,").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}\n").unwrap();
+}
+
+/// A row of the language matching table: desired and supported language (639-1 or 639-3 code),
+/// distance and whether it only applies in one direction
+type LanguageMatch<'a> = (&'a str, &'a str, u16, bool);
+
+/// Parse the language matching table.
+fn read_language_matching_table(table: &str) -> Vec<LanguageMatch<'_>> {
+    table
+        .lines()
+        .skip(1)
+        .map(|line| {
+            let mut cols = line.split('\t');
+            let mut col = || cols.next().unwrap();
+            let (desired, supported) = (col(), col());
+            let distance = col().parse().unwrap_or_else(|e| {
+                panic!("invalid distance of {desired} and {supported}: {e}")
+            });
+            (desired, supported, distance, col() == "true")
+        })
+        .collect()
+}
+
+/// Write the distances of closely related languages; the codes have already
+/// been resolved to 639-3 codes.
+fn write_language_matches(out: &mut String, matches: &[LanguageMatch]) {
+    writeln!(
+        out,
+        "pub(crate) static LANGUAGE_MATCHES: &[(Language, Language, u16, bool)] = &["
+    )
+    .unwrap();
+    for (desired, supported, distance, oneway) in matches {
+        writeln!(
+            out,
+            "    (Language::{}, Language::{}, {}, {}),",
+            Title(desired),
+            Title(supported),
+            distance,
+            oneway
+        )
+        .unwrap();
+    }
+    writeln!(out, "];\n").unwrap();
+}
+
+/// Write a mapping of locales to their parent locale.
+fn write_parent_locales(out: &mut String, parent_locales: &[(&str, &str)]) {
+    write!(out, "pub(crate) const PARENT_LOCALES: phf::Map<&str, &str> = ")
+        .unwrap();
+    let mut map = phf_codegen::Map::new();
+    for (locale, parent) in parent_locales {
+        map.entry(*locale, format!("{:?}", parent));
+    }
+    writeln!(out, "{};\n", map.build()).unwrap();
+}
+
+/// Write a mapping of codes from 639-1 -> Language::`639-3`.
+fn write_two_letter_to_enum(out: &mut String, codes: &[LangCode]) {
+    write!(out, "pub(crate) const TWO_TO_THREE: phf::Map<&str, u16> = ")
+        .unwrap();
+    let mut map = phf_codegen::Map::new();
+    for lang in codes.iter() {
+        if let Some(ref two_letter) = lang.code_1 {
+            let formatted_lang =
+                format!("Language::{} as u16", Title(lang.code_3));
+            map.entry(two_letter, formatted_lang);
+        }
+    }
+    writeln!(out, "{};\n", map.build()).unwrap();
+}
+
+/// Write a mapping of codes from 639-3 -> Language::`639-3`.
+fn write_three_letter_to_enum(out: &mut String, codes: &[LangCode]) {
+    write!(out, "pub(crate) const THREE_TO_THREE: phf::Map<&str, u16> = ")
+        .unwrap();
+    let mut map = phf_codegen::Map::new();
+    for lang in codes.iter() {
+        map.entry(
+            lang.code_3,
+            format!("Language::{} as u16", Title(lang.code_3)),
+        );
+    }
+    writeln!(out, "{};", map.build()).unwrap();
+}
+
+/// Write constant lookups of the Language by its 639-1 and 639-3 code.
+fn write_const_lookups(out: &mut String, codes: &[LangCode]) {
+    writeln!(
+        out,
+        "pub(crate) const fn language_from_639_1(code: &[u8; 2]) -> Option<Language> {{"
+    )
+    .unwrap();
+    writeln!(out, "    match code {{").unwrap();
+    for lang in codes.iter() {
+        if let Some(code_1) = lang.code_1 {
+            writeln!(
+                out,
+                "        b\"{}\" => Some(Language::{}),",
+                code_1,
+                Title(lang.code_3)
+            )
+            .unwrap();
+        }
+    }
+    writeln!(out, "        _ => None,").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+
+    writeln!(
+        out,
+        "pub(crate) const fn language_from_639_3(code: &[u8; 3]) -> Option<Language> {{"
+    )
+    .unwrap();
+    writeln!(out, "    match code {{").unwrap();
+    for lang in codes.iter() {
+        writeln!(
+            out,
+            "        b\"{}\" => Some(Language::{}),",
+            lang.code_3,
+            Title(lang.code_3)
+        )
+        .unwrap();
+    }
+    writeln!(out, "        _ => None,").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+}
+
+fn write_iso_639_3_to_2b_conversions(out: &mut String, codes: &[LangCode]) {
+    // 3 -> 2b, only for codes which differ
+    writeln!(
+        out,
+        "pub(crate) const fn iso_639_3_to_2b(code: &[u8; 3]) -> Option<&'static str> {{"
+    )
+    .unwrap();
+    writeln!(out, "    match code {{").unwrap();
+    for lang in codes.iter() {
+        if let Some(code_2b) = lang.code_2b {
+            if code_2b != lang.code_3 {
+                writeln!(
+                    out,
+                    "        b\"{}\" => Some(\"{}\"),",
+                    lang.code_3, code_2b
+                )
+                .unwrap();
+            }
+        }
+    }
+    writeln!(out, "        _ => None,").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+
+    // 2b -> Language, only for codes which differ from 639-3
+    writeln!(
+        out,
+        "pub(crate) const fn language_from_639_2b(code: &[u8; 3]) -> Option<Language> {{"
+    )
+    .unwrap();
+    writeln!(out, "    match code {{").unwrap();
+    for lang in codes.iter() {
+        if let Some(code_2b) = lang.code_2b {
+            if code_2b != lang.code_3 {
+                writeln!(
+                    out,
+                    "        b\"{}\" => Some(Language::{}),",
+                    code_2b,
+                    Title(lang.code_3)
+                )
+                .unwrap();
+            }
+        }
+    }
+    writeln!(out, "        _ => None,").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+}
+
+/// Parse a column of the ISO 639-3 table into a map from 639-3 codes to values,
+/// e.g. the `Scope` (4) or the `Language_Type` (5).
+fn read_iso_column(iso_table: &str, column: usize) -> HashMap<&str, &str> {
+    iso_table
+        .lines()
+        .skip(1)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let mut cols = line.split('\t');
+            (cols.next().unwrap(), cols.nth(column - 1).unwrap())
+        })
+        .collect()
+}
+
+/// Get the language subtag of a locale as 639-3 code, if the language exists.
+fn language_of_locale<'a>(
+    codes: &[LangCode<'a>],
+    locale: &str,
+) -> Option<&'a str> {
+    let language = locale.split('-').next().unwrap();
+    codes
+        .iter()
+        .find(|lang| lang.code_3 == language || lang.code_1 == Some(language))
+        .map(|lang| lang.code_3)
+}
+
+/// Read a data file from the data directory.
+fn read_data_file(data_dir: &Path, name: &str) -> String {
+    let path = data_dir.join(name);
+    fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Couldn't read {}: {}", path.display(), e))
+}
+
+/// Generate the formatted code of a subset from the data files.
+fn generate(data_dir: &Path, subset: &Subset) -> String {
+    let iso_table = read_data_file(data_dir, ISO_TABLE_PATH);
+    let autonyms_table = read_data_file(data_dir, AUTONYMS_TABLE_PATH);
+    let display_names_table =
+        read_data_file(data_dir, DISPLAY_NAMES_TABLE_PATH);
+    let macrolanguages_table =
+        read_data_file(data_dir, MACROLANGUAGES_TABLE_PATH);
+    let parent_locales_table =
+        read_data_file(data_dir, PARENT_LOCALES_TABLE_PATH);
+    let language_matching_table =
+        read_data_file(data_dir, LANGUAGE_MATCHING_TABLE_PATH);
+
+    let scopes = read_iso_column(&iso_table, 4);
+    // `L` for living and `S` for special codes
+    let language_types = read_iso_column(&iso_table, 5);
+    let codes: Vec<_> = read_iso_table(&iso_table, &autonyms_table)
+        .expect("malformed ISO 639-3 or autonyms table")
+        .into_iter()
+        .filter(|lang| {
+            let language_type = language_types[lang.code_3];
+            language_type == "S"
+                || ((!subset.only_639_1 || lang.code_1.is_some())
+                    && (!subset.only_living || language_type == "L"))
+        })
+        .collect();
+    let contains =
+        |code_3: &str| codes.iter().any(|lang| lang.code_3 == code_3);
+
+    let display_names: Vec<_> = read_display_names_table(&display_names_table)
+        .into_iter()
+        .filter(|(locale, _)| language_of_locale(&codes, locale).is_some())
+        .map(|(locale, names)| {
+            let names = names
+                .into_iter()
+                .filter(|(code_3, _)| contains(code_3))
+                .collect();
+            (locale, names)
+        })
+        .collect();
+    let macrolanguages: Vec<_> = read_pairs_table(&macrolanguages_table)
+        .into_iter()
+        .filter(|(code_3, macro_code_3)| {
+            contains(code_3) && contains(macro_code_3)
+        })
+        .collect();
+    let parent_locales: Vec<_> = read_pairs_table(&parent_locales_table)
+        .into_iter()
+        .filter(|(locale, parent)| {
+            language_of_locale(&codes, locale).is_some()
+                && language_of_locale(&codes, parent).is_some()
+        })
+        .collect();
+    let language_matches: Vec<_> =
+        read_language_matching_table(&language_matching_table)
+            .into_iter()
+            .filter_map(|(desired, supported, distance, oneway)| {
+                Some((
+                    language_of_locale(&codes, desired)?,
+                    language_of_locale(&codes, supported)?,
+                    distance,
+                    oneway,
+                ))
+            })
+            .collect();
+    let mut new_code = String::with_capacity(1024 * 1024 + 1024 * 256); // Current size at 118k
+    new_code.push_str(
+        "/// This file is generated and should not be edited directly.\nuse super::{LanguageData, Scope};\n\n",
+    );
+
+    // write overview table with all data
+    write_overview_table(&mut new_code, &codes);
+
+    // write enum with 639-3 codes (num is the index into the overview table);
+    // `Language::from_usize` relies on the discriminants being `0..LANGUAGE_COUNT`
+    assert!(codes.len() <= u16::MAX as usize, "too many languages for u16");
+    writeln!(
+        &mut new_code,
+        "#[derive(Clone, Copy, Hash, Eq, PartialEq, PartialOrd, Ord)]\n#[repr(u16)]"
+    )
+    .unwrap();
+
+    writeln!(
+        &mut new_code,
+r###"#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]"###).unwrap();
+    writeln!(
+        &mut new_code,
+r###"#[cfg_attr(feature = "diesel", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow), diesel(sql_type = diesel::sql_types::Text))]"###).unwrap();
+    writeln!(&mut new_code, "pub enum Language {{").unwrap();
+    for (num, lang) in codes.iter().enumerate() {
+        writeln!(&mut new_code, "    /// {}", lang.name_en).unwrap();
+        writeln!(&mut new_code, "    {} = {},", Title(lang.code_3), num)
+            .unwrap();
+    }
+    writeln!(&mut new_code, "}}\n").unwrap();
+
+    // write map 639-1 -> enum mapping
+    write_two_letter_to_enum(&mut new_code, &codes);
+
+    // write map 639-3 -> enum mapping
+    write_three_letter_to_enum(&mut new_code, &codes);
+
+    // write constant lookups of 639-1 and 639-3 codes
+    write_const_lookups(&mut new_code, &codes);
+
+    // write conversion function from 639-3 to 639-2t/b
+    write_iso_639_3_to_2b_conversions(&mut new_code, &codes);
+
+    // write scopes, only for languages which are not individual ones
+    write_scopes(&mut new_code, &codes, &scopes);
+
+    // write languages which can't be looked up by their English name
+    write_shared_names(&mut new_code, &codes);
+
+    // write macrolanguage relationships and parent locales for fallback chains
+    write_macrolanguages(&mut new_code, &macrolanguages);
+    write_parent_locales(&mut new_code, &parent_locales);
+
+    // write distances of closely related languages for language matching
+    write_language_matches(&mut new_code, &language_matches);
+
+    // write tables with names of languages in other languages
+    write_display_names_tables(&mut new_code, &codes, &display_names);
+
+    format_code(&new_code)
+}
+
+/// Compute the stable ID of a 639-3 code, like `Language::to_stable_id()`.
+fn stable_id(code_3: &str) -> u16 {
+    code_3.bytes().fold(0, |id, letter| id * 26 + u16::from(letter - b'a'))
+}
+
+/// Generate the Protocol Buffers definition of all languages from the data files.
+///
+/// The enum values are the stable IDs plus one, since proto3 reserves 0 for the default value.
+fn generate_proto(data_dir: &Path) -> String {
+    let iso_table = read_data_file(data_dir, ISO_TABLE_PATH);
+    let autonyms_table = read_data_file(data_dir, AUTONYMS_TABLE_PATH);
+    let codes = read_iso_table(&iso_table, &autonyms_table)
+        .expect("malformed ISO 639-3 or autonyms table");
+
+    let mut proto = String::with_capacity(256 * 1024);
+    proto.push_str(
+        "// This file is generated and should not be edited directly.\n\
+         syntax = \"proto3\";\n\n\
+         package isolang;\n\n\
+         // A language of ISO 639-3. The values are the stable IDs of the isolang crate plus one,\n\
+         // i.e. the ISO 639-3 code read as base-26 number, starting at 1 for `aaa`.\n\
+         enum Language {\n  LANGUAGE_UNSPECIFIED = 0;\n",
+    );
+    for lang in &codes {
+        writeln!(
+            proto,
+            "  // {}\n  LANGUAGE_{} = {};",
+            lang.name_en,
+            lang.code_3.to_uppercase(),
+            u32::from(stable_id(lang.code_3)) + 1
+        )
+        .unwrap();
+    }
+    proto.push_str("}\n");
+    proto
+}
+
+/// Write a generated file if it changed, or only report it with `check`. Returns whether the file
+/// is outdated.
+fn update(output: &Path, new_code: &str, check: bool) -> bool {
+    let old_code = fs::read_to_string(output).unwrap_or_default();
+    if old_code == new_code {
+        println!("{} is up to date", output.display());
+        false
+    } else if check {
+        eprintln!(
+            "{} is outdated, run `cargo xtask regenerate`",
+            output.display()
+        );
+        true
+    } else {
+        fs::write(output, new_code).unwrap_or_else(|e| {
+            panic!("Couldn't write {}: {}", output.display(), e)
+        });
+        println!("{} has been regenerated", output.display());
+        false
+    }
+}
+
+fn crate_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
+
+fn main() {
+    let crate_root = crate_root();
+    let mut args = env::args().skip(1);
+    let mut check = false;
+    let mut data_dir = crate_root.to_owned();
+    let mut output_dir = crate_root.join("src");
+    let mut proto_dir = crate_root.join("proto");
+    if args.next().as_deref() != Some("regenerate") {
+        usage();
+    }
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--check" => check = true,
+            "--data-dir" => {
+                data_dir =
+                    args.next().map(PathBuf::from).unwrap_or_else(|| usage())
+            }
+            "--output-dir" => {
+                output_dir =
+                    args.next().map(PathBuf::from).unwrap_or_else(|| usage())
+            }
+            "--proto-dir" => {
+                proto_dir =
+                    args.next().map(PathBuf::from).unwrap_or_else(|| usage())
+            }
+            _ => usage(),
+        }
+    }
+
+    let mut outdated = false;
+    for subset in &SUBSETS {
+        let output = output_dir.join(subset.file);
+        outdated |= update(&output, &generate(&data_dir, subset), check);
+    }
+    if !check {
+        fs::create_dir_all(&proto_dir).unwrap_or_else(|e| {
+            panic!("Couldn't create {}: {}", proto_dir.display(), e)
+        });
+    }
+    let output = proto_dir.join(PROTO_PATH);
+    outdated |= update(&output, &generate_proto(&data_dir), check);
+    if outdated {
+        process::exit(1);
+    }
+}
+
+/// Check that the generated files in the repository are up to date.
+///
+/// Avoid running on Windows: the generated code will use `\r\n` instead of `\n`.
+#[cfg(unix)]
+#[test]
+fn generated_code_is_up_to_date() {
+    for subset in &SUBSETS {
+        let path = crate_root().join("src").join(subset.file);
+        let old_code = fs::read_to_string(&path).unwrap();
+        assert!(
+            generate(crate_root(), subset) == old_code,
+            "src/{} is outdated, run `cargo xtask regenerate`",
+            subset.file
+        );
+    }
+    let path = crate_root().join("proto").join(PROTO_PATH);
+    assert!(
+        generate_proto(crate_root()) == fs::read_to_string(&path).unwrap(),
+        "proto/{} is outdated, run `cargo xtask regenerate`",
+        PROTO_PATH
+    );
+}